@@ -0,0 +1,732 @@
+// Thoughts about this code
+
+// GenerationConstraints shape the dummy data of a single column, they don't change the column in the DBMS.
+// They are checked before any value is generated.
+// A constraint that can't be fulfilled fails the generation with a message instead of being ignored.
+
+// The constraints deciding the values themselves are used in this order:
+//     1. values, a fixed list to pick from
+//     2. weights, the distribution of the values of an enum
+//     3. pattern, a regex the text has to match
+//     4. min and max for numbers, earliest and latest for dates
+// A column with one of them doesn't get a generator from the name heuristics.
+// Setting one of them together with an explicit generator is an error, because one of them would be ignored.
+// null_probability and unique only change how often NULL is used and whether values may repeat, so they combine with everything.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDateTime};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{datetime_value, default_datetime_range, integer_of_type};
+use crate::db_mocker::error::{Error, Result};
+use crate::db_mocker::{Column, ColumnType, Value};
+
+// Constraints for the dummy data of a column.
+// Every constraint is optional, a column without constraints gets random values of its column type.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GenerationConstraints {
+    // Smallest and biggest value of number, bit and year columns
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    // Earliest and latest value of date, datetime and timestamp columns in UTC
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub earliest: Option<NaiveDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest: Option<NaiveDateTime>,
+    // Share of the datasets in which the column gets NULL, NOT NULL and primary key columns never get NULL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null_probability: Option<f64>,
+    // Regex the values of char, varchar and text columns match, e.g. "[A-Z]{2}-[0-9]{4}"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    // Probability of values of an enum column, e.g. 0.9 for "active"
+    // Values without a weight share the remaining probability equally
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub weights: BTreeMap<String, f64>,
+    // Values the column gets instead of generated ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<Value>,
+    // No two datasets get the same value, NULL doesn't count as a value
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub unique: bool,
+}
+
+// Repetitions of unbounded regex quantifiers like '*' and '+' are limited to this number
+const MAX_PATTERN_REPEAT: u32 = 16;
+// Text generated from a pattern is discarded and generated again if it is longer than the column
+const MAX_PATTERN_ATTEMPTS: usize = 100;
+
+impl GenerationConstraints {
+    // Returns if one of the constraints decides the values themselves instead of only how often NULL is used or if values may repeat.
+    pub fn shapes_values(&self) -> bool {
+        !self.values.is_empty()
+            || !self.weights.is_empty()
+            || self.pattern.is_some()
+            || self.min.is_some()
+            || self.max.is_some()
+            || self.earliest.is_some()
+            || self.latest.is_some()
+    }
+
+    // Checks that the constraints fit the column and can be fulfilled.
+    pub fn check(&self, column: &Column) -> Result<()> {
+        let column_type = &column.column_type;
+
+        if column.generator.is_some() && self.shapes_values() {
+            return Err(invalid(
+                column,
+                "A generator can't be combined with values, weights, a pattern or a range",
+            ));
+        }
+
+        if let Some(probability) = self.null_probability {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(invalid(
+                    column,
+                    "null_probability has to be between 0 and 1",
+                ));
+            }
+        }
+
+        if self.min.is_some() || self.max.is_some() {
+            if [self.min, self.max]
+                .iter()
+                .flatten()
+                .any(|bound| !bound.is_finite())
+            {
+                return Err(invalid(column, "min and max have to be finite numbers"));
+            }
+            if self.min > self.max && self.max.is_some() {
+                return Err(invalid(column, "min is greater than max"));
+            }
+
+            let in_range = match column_type {
+                // The random number generator can't sample a range wider than the biggest f64
+                ColumnType::Float | ColumnType::Double => {
+                    let (min, max) = float_range(self.min, self.max);
+                    let fits_float = *column_type == ColumnType::Double
+                        || (min >= f32::MIN as f64 && max <= f32::MAX as f64);
+                    (max - min).is_finite() && fits_float
+                }
+                ColumnType::Decimal(precision, scale) => {
+                    self.decimal_range(*precision, *scale).is_some()
+                }
+                _ => match integer_bounds(column_type) {
+                    Some(bounds) => self.integer_range(bounds).is_some(),
+                    None => {
+                        return Err(invalid(
+                            column,
+                            "min and max only apply to number, bit and year columns",
+                        ))
+                    }
+                },
+            };
+            if !in_range {
+                return Err(invalid(
+                    column,
+                    "min and max leave no value that fits the column type",
+                ));
+            }
+        }
+
+        if self.earliest.is_some() || self.latest.is_some() {
+            if !matches!(
+                column_type,
+                ColumnType::Date | ColumnType::DateTime(_) | ColumnType::Timestamp
+            ) {
+                return Err(invalid(
+                    column,
+                    "earliest and latest only apply to date, datetime and timestamp columns",
+                ));
+            }
+            if self.earliest > self.latest && self.latest.is_some() {
+                return Err(invalid(column, "earliest is after latest"));
+            }
+        }
+
+        if let Some(pattern) = &self.pattern {
+            if !is_text(column_type) {
+                return Err(invalid(
+                    column,
+                    "A pattern only applies to char, varchar and text columns",
+                ));
+            }
+            compile_pattern(pattern).map_err(|message| invalid(column, &message))?;
+        }
+
+        if !self.weights.is_empty() {
+            let ColumnType::Enum(values) = column_type else {
+                return Err(invalid(column, "Weights only apply to enum columns"));
+            };
+            if let Some(value) = self.weights.keys().find(|value| !values.contains(value)) {
+                return Err(invalid(
+                    column,
+                    &format!("Weight for '{}' which isn't a value of the enum", value),
+                ));
+            }
+            if self
+                .weights
+                .values()
+                .any(|weight| !(0.0..=1.0).contains(weight))
+            {
+                return Err(invalid(column, "Weights have to be between 0 and 1"));
+            }
+
+            let total: f64 = self.weights.values().sum();
+            let every_value_weighted = values.iter().all(|value| self.weights.contains_key(value));
+            if every_value_weighted && total <= 0.0 {
+                return Err(invalid(column, "Weights add up to 0"));
+            }
+            // Rounding errors of weights like 0.7 + 0.2 + 0.1 are allowed
+            if !every_value_weighted && total > 1.0 + 1e-9 {
+                return Err(invalid(column, "Weights add up to more than 1"));
+            }
+        }
+
+        for value in self.values.iter() {
+            if !value.fits(column_type) || (column.not_null && *value == Value::Null) {
+                return Err(invalid(
+                    column,
+                    &format!("Value {:?} doesn't fit the column", value),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Range of the integers between min and max that fit into the bounds of the column type.
+    // Returns None if there is no such integer.
+    pub(crate) fn integer_range(&self, (lowest, highest): (i128, i128)) -> Option<(i128, i128)> {
+        // Casts from f64 saturate, so huge bounds end up at the bounds of the column type
+        let min = self
+            .min
+            .map_or(lowest, |min| (min.ceil() as i128).max(lowest));
+        let max = self
+            .max
+            .map_or(highest, |max| (max.floor() as i128).min(highest));
+
+        (min <= max).then_some((min, max))
+    }
+
+    // Range of a decimal column in units of its last digit, e.g. 12.34 is 1234 for a scale of 2.
+    // Without min or max the range is the same as for decimals without constraints.
+    pub(crate) fn decimal_range(&self, precision: u8, scale: u8) -> Option<(i128, i128)> {
+        let unit = 10f64.powi(scale as i32);
+        // i128 holds up to 38 digits
+        let highest = match 10i128.checked_pow(precision as u32) {
+            Some(power) => power - 1,
+            None => i128::MAX,
+        };
+        let default_highest = 10i128.pow(precision.saturating_sub(scale).min(6) as u32)
+            * 10i128.pow(scale as u32)
+            - 1;
+
+        let (min, max) = match (self.min, self.max) {
+            (Some(min), Some(max)) => (min, max),
+            (Some(min), None) => (min, (default_highest as f64 / unit).max(min)),
+            (None, Some(max)) => (max.min(0.0), max),
+            (None, None) => (0.0, default_highest as f64 / unit),
+        };
+        let min = ((min * unit).ceil() as i128).max(-highest);
+        let max = ((max * unit).floor() as i128).min(highest);
+
+        (min <= max).then_some((min, max))
+    }
+
+    // First and last second of the datetimes between earliest and latest.
+    // A missing bound is replaced by the bound of the default range of datetimes.
+    pub(crate) fn datetime_range(&self) -> (i64, i64) {
+        let (default_start, default_end) = default_datetime_range();
+        let start = self
+            .earliest
+            .map_or(default_start, |earliest| earliest.and_utc().timestamp());
+        let end = self.latest.map_or(default_end.max(start), |latest| {
+            latest.and_utc().timestamp()
+        });
+
+        match self.earliest {
+            Some(_) => (start, end),
+            None => (start.min(end), end),
+        }
+    }
+}
+
+// Generates a value with the constraints that decide the values themselves.
+// Returns None if the constraints don't decide the values, the column gets random values of its column type then.
+// The constraints have to be checked with GenerationConstraints::check() before.
+pub(crate) fn generate_constrained_value<R: Rng>(
+    column: &Column,
+    constraints: &GenerationConstraints,
+    rng: &mut R,
+) -> Result<Option<Value>> {
+    let column_type = &column.column_type;
+
+    if !constraints.values.is_empty() {
+        let value = &constraints.values[rng.gen_range(0..constraints.values.len())];
+        return Ok(Some(value.clone()));
+    }
+
+    if let (false, ColumnType::Enum(values)) = (constraints.weights.is_empty(), column_type) {
+        return Ok(Some(weighted_enum_value(values, &constraints.weights, rng)));
+    }
+
+    if let Some(pattern) = &constraints.pattern {
+        let regex = compile_pattern(pattern).map_err(|message| invalid(column, &message))?;
+        for _ in 0..MAX_PATTERN_ATTEMPTS {
+            let text: String = match rng.sample::<std::result::Result<String, _>, _>(&regex) {
+                Ok(text) => text,
+                Err(_) => continue,
+            };
+            let value = text_value(column_type, text);
+            if value.fits(column_type) {
+                return Ok(Some(value));
+            }
+        }
+        return Err(Error::Generation(format!(
+            "Pattern '{}' doesn't generate text that fits into column '{}'",
+            pattern, column.column_name
+        )));
+    }
+
+    if constraints.min.is_some() || constraints.max.is_some() {
+        let value = match column_type {
+            ColumnType::Float | ColumnType::Double => {
+                let (min, max) = float_range(constraints.min, constraints.max);
+                // Rounded to two decimals like floats without constraints
+                let value = ((rng.gen_range(min..=max) * 100.0).round() / 100.0).clamp(min, max);
+                match column_type {
+                    ColumnType::Float => Value::Float(value as f32),
+                    _ => Value::Double(value),
+                }
+            }
+            ColumnType::Decimal(precision, scale) => {
+                let (min, max) = constraints
+                    .decimal_range(*precision, *scale)
+                    .ok_or_else(|| invalid(column, "min and max leave no value"))?;
+                Value::Decimal(format_decimal(rng.gen_range(min..=max), *scale))
+            }
+            _ => {
+                let bounds = integer_bounds(column_type)
+                    .ok_or_else(|| invalid(column, "min and max only apply to numbers"))?;
+                let (min, max) = constraints
+                    .integer_range(bounds)
+                    .ok_or_else(|| invalid(column, "min and max leave no value"))?;
+                let value = rng.gen_range(min..=max);
+                match column_type {
+                    ColumnType::Bit(_) => Value::Bit(value as u64),
+                    ColumnType::Year => Value::Year(value as u16),
+                    _ => integer_of_type(column_type, value)
+                        .ok_or_else(|| invalid(column, "min and max only apply to numbers"))?,
+                }
+            }
+        };
+        return Ok(Some(value));
+    }
+
+    if constraints.earliest.is_some() || constraints.latest.is_some() {
+        let (start, end) = constraints.datetime_range();
+        let datetime = DateTime::from_timestamp(rng.gen_range(start..=end), 0)
+            .ok_or_else(|| invalid(column, "earliest or latest is out of range"))?;
+        return Ok(Some(datetime_value(column_type, datetime)));
+    }
+
+    Ok(None)
+}
+
+// Picks a value of the enum with the probability of its weight.
+fn weighted_enum_value<R: Rng>(
+    values: &[String],
+    weights: &BTreeMap<String, f64>,
+    rng: &mut R,
+) -> Value {
+    let weights = enum_weights(values, weights);
+    let mut pick = rng.gen_range(0.0..weights.iter().sum::<f64>());
+    for (value, weight) in values.iter().zip(weights.iter()) {
+        pick -= weight;
+        if pick < 0.0 {
+            return Value::Enum(value.clone());
+        }
+    }
+
+    // Rounding errors can leave a tiny rest, it belongs to the last value that can be picked at all
+    let last = weights
+        .iter()
+        .rposition(|weight| *weight > 0.0)
+        .unwrap_or(values.len() - 1);
+    Value::Enum(values[last].clone())
+}
+
+// Probability of each value of the enum.
+// Values without a weight share the remaining probability.
+// If every value has a weight, the weights are used as they are and scaled when picking.
+pub(crate) fn enum_weights(values: &[String], weights: &BTreeMap<String, f64>) -> Vec<f64> {
+    let weighted_total: f64 = values.iter().filter_map(|value| weights.get(value)).sum();
+    let unweighted_count = values
+        .iter()
+        .filter(|value| !weights.contains_key(*value))
+        .count();
+
+    values
+        .iter()
+        .map(|value| match weights.get(value) {
+            Some(weight) => *weight,
+            None => (1.0 - weighted_total).max(0.0) / unweighted_count as f64,
+        })
+        .collect()
+}
+
+// Compiles the pattern for generating text.
+// Anchors at the start and end are removed, every generated text matches the whole pattern anyway.
+fn compile_pattern(pattern: &str) -> std::result::Result<rand_regex::Regex, String> {
+    let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+    let pattern = match pattern.strip_suffix('$') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => pattern,
+    };
+
+    rand_regex::Regex::compile(pattern, MAX_PATTERN_REPEAT)
+        .map_err(|error| format!("Pattern '{}' can't be used: {}", pattern, error))
+}
+
+// Range for a float column, a missing bound is replaced by the default range of -10000 to 10000.
+// If the given bound is outside of the default range, the range is as wide as the default range.
+fn float_range(min: Option<f64>, max: Option<f64>) -> (f64, f64) {
+    const DEFAULT_MIN: f64 = -10_000.0;
+    const DEFAULT_MAX: f64 = 10_000.0;
+    const SPAN: f64 = DEFAULT_MAX - DEFAULT_MIN;
+
+    match (min, max) {
+        (Some(min), Some(max)) => (min, max),
+        (Some(min), None) => (min, DEFAULT_MAX.max(min + SPAN)),
+        (None, Some(max)) => (DEFAULT_MIN.min(max - SPAN), max),
+        (None, None) => (DEFAULT_MIN, DEFAULT_MAX),
+    }
+}
+
+// Smallest and biggest value of integer, bit and year columns.
+pub(crate) fn integer_bounds(column_type: &ColumnType) -> Option<(i128, i128)> {
+    let bounds = match column_type {
+        ColumnType::Int8 => (i8::MIN as i128, i8::MAX as i128),
+        ColumnType::Uint8 => (0, u8::MAX as i128),
+        ColumnType::Int16 => (i16::MIN as i128, i16::MAX as i128),
+        ColumnType::Uint16 => (0, u16::MAX as i128),
+        ColumnType::Int32 => (i32::MIN as i128, i32::MAX as i128),
+        ColumnType::Uint32 => (0, u32::MAX as i128),
+        ColumnType::Int64 => (i64::MIN as i128, i64::MAX as i128),
+        ColumnType::Uint64 => (0, u64::MAX as i128),
+        ColumnType::Bit(size) if *size >= 64 => (0, u64::MAX as i128),
+        ColumnType::Bit(size) => (0, (1i128 << size) - 1),
+        // MySQL only allows years between 1901 and 2155
+        ColumnType::Year => (1901, 2155),
+        _ => return None,
+    };
+
+    Some(bounds)
+}
+
+// Formats a number in units of the last digit as decimal, e.g. -1234 with a scale of 2 becomes "-12.34".
+pub(crate) fn format_decimal(units: i128, scale: u8) -> String {
+    let sign = if units < 0 { "-" } else { "" };
+    let digits = format!(
+        "{:0width$}",
+        units.unsigned_abs(),
+        width = scale as usize + 1
+    );
+    let (integer, fraction) = digits.split_at(digits.len() - scale as usize);
+
+    if fraction.is_empty() {
+        format!("{}{}", sign, integer)
+    } else {
+        format!("{}{}.{}", sign, integer, fraction)
+    }
+}
+
+fn is_text(column_type: &ColumnType) -> bool {
+    matches!(
+        column_type,
+        ColumnType::Char(_)
+            | ColumnType::Varchar(_)
+            | ColumnType::Text
+            | ColumnType::MediumText
+            | ColumnType::LongText
+    )
+}
+
+// Wraps the text into the Value of the text column type.
+fn text_value(column_type: &ColumnType, text: String) -> Value {
+    match column_type {
+        ColumnType::Char(_) => Value::Char(text),
+        ColumnType::Varchar(_) => Value::Varchar(text),
+        _ => Value::Text(text),
+    }
+}
+
+fn invalid(column: &Column, message: &str) -> Error {
+    Error::Generation(format!(
+        "Invalid constraints of column '{}': {}",
+        column.column_name, message
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn column(column_type: ColumnType, constraints: GenerationConstraints) -> Column {
+        Column {
+            column_name: "value".to_string(),
+            column_type,
+            default: None,
+            auto_increment: false,
+            not_null: true,
+            generator: None,
+            constraints: Some(constraints),
+        }
+    }
+
+    // Generates values for the column after checking its constraints.
+    fn generate(column: &Column, count: usize) -> Vec<Value> {
+        let constraints = column.constraints.as_ref().unwrap();
+        constraints.check(column).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(23);
+        (0..count)
+            .map(|_| {
+                generate_constrained_value(column, constraints, &mut rng)
+                    .unwrap()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keeps_numbers_and_dates_in_range() {
+        let percent = column(
+            ColumnType::Uint8,
+            GenerationConstraints {
+                min: Some(0.0),
+                max: Some(100.0),
+                ..Default::default()
+            },
+        );
+        for value in generate(&percent, 200) {
+            assert!(matches!(value, Value::Uint8(0..=100)), "{:?}", value);
+        }
+
+        // Wide ranges stay finite, also for floats that are only 32 bits wide
+        for (column_type, bound) in [(ColumnType::Double, 1e300), (ColumnType::Float, 3e38)] {
+            let huge = column(
+                column_type,
+                GenerationConstraints {
+                    min: Some(-bound),
+                    max: Some(bound),
+                    ..Default::default()
+                },
+            );
+            for value in generate(&huge, 100) {
+                let finite = match value {
+                    Value::Float(value) => value.is_finite(),
+                    Value::Double(value) => value.is_finite(),
+                    _ => false,
+                };
+                assert!(finite, "{:?}", value);
+            }
+        }
+
+        let price = column(
+            ColumnType::Decimal(6, 2),
+            GenerationConstraints {
+                min: Some(0.5),
+                max: Some(1.5),
+                ..Default::default()
+            },
+        );
+        for value in generate(&price, 200) {
+            let Value::Decimal(text) = &value else {
+                panic!("Expected decimal");
+            };
+            let number: f64 = text.parse().unwrap();
+            assert!((0.5..=1.5).contains(&number) && value.fits(&price.column_type));
+        }
+
+        let earliest =
+            NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let latest =
+            NaiveDateTime::parse_from_str("2024-01-31 23:59:59", "%Y-%m-%d %H:%M:%S").unwrap();
+        let shipped_at = column(
+            ColumnType::Timestamp,
+            GenerationConstraints {
+                earliest: Some(earliest),
+                latest: Some(latest),
+                ..Default::default()
+            },
+        );
+        for value in generate(&shipped_at, 200) {
+            let Value::Timestamp(timestamp) = value else {
+                panic!("Expected timestamp");
+            };
+            assert!((earliest..=latest).contains(&timestamp.naive_utc()));
+        }
+    }
+
+    #[test]
+    fn follows_enum_weights() {
+        let status = column(
+            ColumnType::Enum(vec![
+                "active".to_string(),
+                "paused".to_string(),
+                "closed".to_string(),
+            ]),
+            GenerationConstraints {
+                weights: BTreeMap::from([("active".to_string(), 0.9)]),
+                ..Default::default()
+            },
+        );
+
+        let values = generate(&status, 2000);
+        let active = values
+            .iter()
+            .filter(|value| **value == Value::Enum("active".to_string()))
+            .count();
+        assert!((1700..=1900).contains(&active), "{} active", active);
+        assert!(values.contains(&Value::Enum("closed".to_string())));
+    }
+
+    #[test]
+    fn generates_text_matching_pattern_and_picks_fixed_values() {
+        let code = column(
+            ColumnType::Varchar(7),
+            GenerationConstraints {
+                pattern: Some("^[A-Z]{2}-[0-9]{4}$".to_string()),
+                ..Default::default()
+            },
+        );
+        for value in generate(&code, 100) {
+            let Value::Varchar(text) = value else {
+                panic!("Expected varchar");
+            };
+            let bytes = text.as_bytes();
+            assert_eq!(bytes.len(), 7);
+            assert!(bytes[..2].iter().all(u8::is_ascii_uppercase));
+            assert_eq!(bytes[2], b'-');
+            assert!(bytes[3..].iter().all(u8::is_ascii_digit));
+        }
+
+        let fixed = vec![Value::Int32(3), Value::Int32(5)];
+        let size = column(
+            ColumnType::Int32,
+            GenerationConstraints {
+                values: fixed.clone(),
+                ..Default::default()
+            },
+        );
+        assert!(generate(&size, 50)
+            .iter()
+            .all(|value| fixed.contains(value)));
+    }
+
+    #[test]
+    fn rejects_constraints_that_dont_fit_the_column() {
+        let cases = [
+            (
+                ColumnType::Varchar(10),
+                GenerationConstraints {
+                    min: Some(1.0),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Uint8,
+                GenerationConstraints {
+                    min: Some(300.0),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Int32,
+                GenerationConstraints {
+                    min: Some(5.0),
+                    max: Some(1.0),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Enum(vec!["a".to_string(), "b".to_string()]),
+                GenerationConstraints {
+                    weights: BTreeMap::from([("c".to_string(), 0.5)]),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Enum(vec!["a".to_string(), "b".to_string()]),
+                GenerationConstraints {
+                    weights: BTreeMap::from([("a".to_string(), 0.0), ("b".to_string(), 0.0)]),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Enum(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+                GenerationConstraints {
+                    weights: BTreeMap::from([("a".to_string(), 0.7), ("b".to_string(), 0.6)]),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Varchar(10),
+                GenerationConstraints {
+                    pattern: Some("[a-".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Int8,
+                GenerationConstraints {
+                    values: vec![Value::Int32(1)],
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Double,
+                GenerationConstraints {
+                    min: Some(-1e308),
+                    max: Some(1e308),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Float,
+                GenerationConstraints {
+                    min: Some(1e39),
+                    ..Default::default()
+                },
+            ),
+            (
+                ColumnType::Int8,
+                GenerationConstraints {
+                    null_probability: Some(1.5),
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        for (column_type, constraints) in cases {
+            let column = column(column_type, constraints);
+            assert!(
+                matches!(
+                    column.constraints.as_ref().unwrap().check(&column),
+                    Err(Error::Generation(_))
+                ),
+                "{:?}",
+                column.constraints
+            );
+        }
+    }
+}
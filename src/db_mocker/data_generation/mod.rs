@@ -0,0 +1,1191 @@
+// Thoughts about this code
+
+// Columns without not_null get NULL in about one of ten datasets.
+// Primary key and auto increment columns never get NULL, MySQL doesn't allow NULL in a primary key.
+
+// The generated values stay inside the ranges MySQL allows for the matching column type.
+// E.g. Year is only valid between 1901 and 2155.
+
+// Foreign keys are only considered when generating datasets for a whole VirtualDatabase.
+// Tables are generated in the order of their foreign keys so the referenced values exist before they get referenced.
+// A foreign key column only gets values of the referenced column, which also limits its distinct values for uniqueness.
+// A table referencing itself samples from its own datasets, including the dataset that is being generated.
+// This way the first dataset of such a table references itself, which is valid for MySQL.
+// NOT NULL foreign key columns never get NULL, even if the referenced column has NULL values.
+
+// Generation is reproducible with a seed in the GenerationSettings or by passing a seeded rng to the *_with_rng functions.
+// Every random decision, including the sampling of foreign keys, is made with that one rng in a fixed order.
+// So the same VirtualDatabase and seed always give the same datasets with the same version of this library.
+// The current time would break this, so seeded generation uses a fixed reference time unless one is set.
+
+// The GenerationConstraints of a column are checked before its first value is generated.
+// null_probability replaces NULL_PROBABILITY for the column, it can't make a NOT NULL or primary key column nullable.
+
+// Primary keys and unique columns get distinct values, see uniqueness for how.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use fake::faker::lorem::en::{Paragraph, Word, Words};
+use fake::Fake;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::json;
+use uuid::Builder;
+
+use super::error::{Error, Result};
+use super::{Column, ColumnType, Table, Value, VirtualDatabase};
+use constraints::generate_constrained_value;
+use semantic::{generate_semantic_value, generator_for_column, NameHeuristics};
+use spatial::{random_geometry, BoundingBox};
+use uniqueness::{make_distinct, unique_groups, UniqueGroup};
+
+pub mod constraints;
+pub mod semantic;
+pub mod spatial;
+mod uniqueness;
+
+// Settings for the DummyDataGenerator.
+#[derive(Default)]
+pub struct GenerationSettings {
+    // Chooses realistic generators for columns by their name, e.g. email addresses for a column named email
+    pub name_heuristics: NameHeuristics,
+    // Area the coordinates of spatial values are generated in
+    pub bounding_box: BoundingBox,
+    // Seed for the random number generator, the same seed always generates the same datasets
+    // If not set every generation gives different datasets
+    pub seed: Option<u64>,
+    // Point in time generators like PastDateTime count back from
+    // If not set it is the current time or, for seeded generation, the start of 2024
+    pub reference_time: Option<DateTime<Utc>>,
+}
+
+impl GenerationSettings {
+    // Random number generator for the functions without an rng parameter.
+    fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    }
+
+    // Returns the point in time generators like PastDateTime count back from.
+    pub fn reference_time(&self) -> DateTime<Utc> {
+        match (self.reference_time, self.seed) {
+            (Some(reference_time), _) => reference_time,
+            (None, Some(_)) => NaiveDate::from_ymd_opt(2024, 1, 1)
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .expect("Valid date")
+                .and_utc(),
+            (None, None) => Utc::now(),
+        }
+    }
+}
+
+// Share of the datasets in which a nullable column gets NULL.
+const NULL_PROBABILITY: f64 = 0.1;
+
+pub struct DummyDataGenerator {}
+
+impl DummyDataGenerator {
+    // Generates row_count random datasets for the table and appends them to its datasets.
+    pub fn fill_table(
+        table: &mut Table,
+        row_count: usize,
+        settings: &GenerationSettings,
+    ) -> Result<()> {
+        let mut datasets = Self::generate_datasets(table, row_count, settings)?;
+        table.datasets.append(&mut datasets);
+
+        Ok(())
+    }
+
+    // Generates row_count random datasets that fit the columns of the table.
+    // The table itself isn't changed.
+    // Auto increment columns continue after the highest value in the existing datasets of the table.
+    // The primary key and unique columns don't repeat values of each other or of the existing datasets.
+    // Fails if they don't have enough distinct values for row_count datasets.
+    pub fn generate_datasets(
+        table: &Table,
+        row_count: usize,
+        settings: &GenerationSettings,
+    ) -> Result<Vec<Vec<Value>>> {
+        Self::generate_datasets_with_rng(table, row_count, settings, &mut settings.rng())
+    }
+
+    // Same as generate_datasets but uses the passed random number generator.
+    // The seed of the settings is ignored, a seeded rng makes the datasets reproducible instead.
+    pub fn generate_datasets_with_rng<R: Rng>(
+        table: &Table,
+        row_count: usize,
+        settings: &GenerationSettings,
+        rng: &mut R,
+    ) -> Result<Vec<Vec<Value>>> {
+        let references = vec![None; table.columns.len()];
+        let self_references = vec![None; table.columns.len()];
+        generate_table_datasets(
+            table,
+            row_count,
+            settings,
+            &references,
+            &self_references,
+            rng,
+        )
+    }
+
+    // Generates row_count random datasets for every table of the VirtualDatabase and appends them to the table's datasets.
+    // Foreign key columns only get values that exist in the referenced column.
+    // Fails if the foreign keys of the tables form a cycle.
+    pub fn fill_database(
+        vdb: &mut VirtualDatabase,
+        row_count: usize,
+        settings: &GenerationSettings,
+    ) -> Result<()> {
+        Self::fill_database_with_rng(vdb, row_count, settings, &mut settings.rng())
+    }
+
+    // Same as fill_database but uses the passed random number generator.
+    // The seed of the settings is ignored, a seeded rng makes the datasets reproducible instead.
+    pub fn fill_database_with_rng<R: Rng>(
+        vdb: &mut VirtualDatabase,
+        row_count: usize,
+        settings: &GenerationSettings,
+        rng: &mut R,
+    ) -> Result<()> {
+        for table_index in vdb.table_dependency_order()? {
+            let table = &vdb.tables[table_index];
+            let references = foreign_key_references(&vdb.tables, table_index, row_count)?;
+            let self_references = self_references(table)?;
+            let mut datasets = generate_table_datasets(
+                table,
+                row_count,
+                settings,
+                &references,
+                &self_references,
+                rng,
+            )?;
+
+            vdb.tables[table_index].datasets.append(&mut datasets);
+        }
+
+        Ok(())
+    }
+}
+
+// Generates the datasets for generate_datasets_with_rng and fill_database_with_rng.
+// references are the values of the referenced column for each foreign key column, None for all other columns.
+// A foreign key column samples its values from them instead of generating values.
+// self_references are the referenced columns of the foreign key columns referencing the table itself, None for all other columns.
+// Those columns sample from the values of the referenced column in the existing datasets and the datasets generated so far.
+fn generate_table_datasets<R: Rng>(
+    table: &Table,
+    row_count: usize,
+    settings: &GenerationSettings,
+    references: &[Option<Vec<Value>>],
+    self_references: &[Option<usize>],
+    rng: &mut R,
+) -> Result<Vec<Vec<Value>>> {
+    // Next value for each auto increment column
+    // None for all other columns
+    let mut next_auto_increments = Vec::new();
+    // Probability of NULL for each column
+    let mut null_probabilities = Vec::new();
+    for (i, column) in table.columns.iter().enumerate() {
+        let constraints = column.constraints.clone().unwrap_or_default();
+        constraints.check(column)?;
+
+        if never_null(table, column) {
+            null_probabilities.push(0.0);
+        } else {
+            null_probabilities.push(constraints.null_probability.unwrap_or(NULL_PROBABILITY));
+        }
+
+        if column.auto_increment {
+            let highest = table
+                .datasets
+                .iter()
+                .filter_map(|dataset| dataset.get(i).and_then(integer_value))
+                .max()
+                .unwrap_or(0);
+            next_auto_increments.push(Some(highest + 1));
+        } else {
+            next_auto_increments.push(None);
+        }
+    }
+
+    // Values each self referencing column can sample, they grow with every generated dataset
+    let mut self_reference_values: Vec<Option<Vec<Value>>> = self_references
+        .iter()
+        .enumerate()
+        .map(|(i, origin)| {
+            origin.map(|origin| {
+                let values = table.datasets.iter().map(|dataset| dataset[origin].clone());
+                referenceable_values(table, &table.columns[i], values)
+            })
+        })
+        .collect();
+
+    // Groups with a self referencing column are made distinct after the other groups.
+    // This way the values of the current dataset those columns can reference don't change anymore.
+    let (mut self_referencing_groups, mut unique_groups): (Vec<UniqueGroup>, Vec<UniqueGroup>) =
+        unique_groups(table, row_count, settings, references, self_references)?
+            .into_iter()
+            .partition(|group| group.has_column(|i| self_references[i].is_some()));
+    let mut regenerate = |i: usize, rng: &mut R| {
+        column_value(&table.columns[i], references[i].as_deref(), settings, rng)
+    };
+
+    let mut datasets = Vec::with_capacity(row_count);
+    for _ in 0..row_count {
+        let mut dataset = Vec::with_capacity(table.columns.len());
+        // Self referencing columns that get a value once the other groups are distinct
+        let mut sampled_later = Vec::new();
+
+        for (i, column) in table.columns.iter().enumerate() {
+            let null_probability = null_probabilities[i];
+            let value = match &mut next_auto_increments[i] {
+                Some(next) => {
+                    let value = integer_column_value(column, *next)?;
+                    *next += 1;
+                    value
+                }
+                None if null_probability > 0.0 && rng.gen_bool(null_probability) => Value::Null,
+                None if self_references[i].is_some() => {
+                    sampled_later.push(i);
+                    Value::Null
+                }
+                None => column_value(column, references[i].as_deref(), settings, rng)?,
+            };
+            dataset.push(value);
+        }
+
+        make_distinct(&mut unique_groups, &mut dataset, &mut regenerate, rng)?;
+
+        // The current dataset is a candidate of its self references, so its values are added before sampling
+        for (i, values) in self_reference_values.iter_mut().enumerate() {
+            if let (Some(values), Some(origin)) = (values, self_references[i]) {
+                values.extend(referenceable_values(
+                    table,
+                    &table.columns[i],
+                    [dataset[origin].clone()],
+                ));
+            }
+        }
+        let mut regenerate_self_referencing =
+            |i: usize, rng: &mut R| match &self_reference_values[i] {
+                Some(values) => sample_self_reference(table, i, values, rng),
+                None => column_value(&table.columns[i], references[i].as_deref(), settings, rng),
+            };
+        for i in sampled_later {
+            dataset[i] = regenerate_self_referencing(i, rng)?;
+        }
+        make_distinct(
+            &mut self_referencing_groups,
+            &mut dataset,
+            &mut regenerate_self_referencing,
+            rng,
+        )?;
+
+        datasets.push(dataset);
+    }
+
+    Ok(datasets)
+}
+
+// Checks if the column can never be NULL.
+// MySQL doesn't allow NULL in a primary key, even if the column isn't NOT NULL.
+fn never_null(table: &Table, column: &Column) -> bool {
+    column.not_null || table.primary_keys.contains(&column.column_name)
+}
+
+// Returns the values a foreign key column can reference.
+// NULL is left out if the foreign key column can't be NULL.
+fn referenceable_values(
+    table: &Table,
+    fk_column: &Column,
+    values: impl IntoIterator<Item = Value>,
+) -> Vec<Value> {
+    let skip_null = never_null(table, fk_column);
+    values
+        .into_iter()
+        .filter(|value| !(skip_null && *value == Value::Null))
+        .collect()
+}
+
+// Samples one of the values for the self referencing column at index i.
+// Fails if there is no value to sample, e.g. if the referenced column of the first dataset is NULL.
+fn sample_self_reference<R: Rng>(
+    table: &Table,
+    i: usize,
+    values: &[Value],
+    rng: &mut R,
+) -> Result<Value> {
+    if values.is_empty() {
+        return Err(Error::Generation(format!(
+            "Foreign key '{}.{}' references no value that isn't NULL",
+            table.table_name, table.columns[i].column_name
+        )));
+    }
+
+    Ok(values[rng.gen_range(0..values.len())].clone())
+}
+
+// Generates a value for the column or samples one of the referenced values for a foreign key column.
+fn column_value<R: Rng>(
+    column: &Column,
+    reference: Option<&[Value]>,
+    settings: &GenerationSettings,
+    rng: &mut R,
+) -> Result<Value> {
+    match reference {
+        Some(values) => Ok(values[rng.gen_range(0..values.len())].clone()),
+        None => generate_value(column, settings, rng),
+    }
+}
+
+// Returns the values of the referenced column for each foreign key column of the table, None for all other columns.
+// The referenced tables have to be generated already.
+// References of the table to itself are None, see self_references for them.
+// NULL isn't a value a NOT NULL foreign key column can reference.
+fn foreign_key_references(
+    tables: &[Table],
+    table_index: usize,
+    row_count: usize,
+) -> Result<Vec<Option<Vec<Value>>>> {
+    let table = &tables[table_index];
+    let mut references = vec![None; table.columns.len()];
+
+    for fk in table.foreign_keys.iter() {
+        if fk.origin_table == table.table_name {
+            continue;
+        }
+
+        let fk_column_index = column_index(table, &fk.fk_column)?;
+        let origin_table = tables
+            .iter()
+            .find(|origin| origin.table_name == fk.origin_table)
+            .ok_or_else(|| Error::NotFound(format!("Table '{}' doesn't exist", fk.origin_table)))?;
+        let origin_column_index = column_index(origin_table, &fk.origin_column)?;
+
+        if origin_table.datasets.is_empty() && row_count > 0 {
+            return Err(Error::Generation(format!(
+                "Foreign key '{}.{}' references table '{}' which has no datasets",
+                table.table_name, fk.fk_column, origin_table.table_name
+            )));
+        }
+
+        let values = referenceable_values(
+            table,
+            &table.columns[fk_column_index],
+            origin_table
+                .datasets
+                .iter()
+                .map(|dataset| dataset[origin_column_index].clone()),
+        );
+        if values.is_empty() && row_count > 0 {
+            return Err(Error::Generation(format!(
+                "Foreign key '{}.{}' references no value that isn't NULL in table '{}'",
+                table.table_name, fk.fk_column, origin_table.table_name
+            )));
+        }
+        references[fk_column_index] = Some(values);
+    }
+
+    Ok(references)
+}
+
+// Returns the index of the referenced column for each foreign key column referencing the table itself, None for all other columns.
+fn self_references(table: &Table) -> Result<Vec<Option<usize>>> {
+    let mut self_references = vec![None; table.columns.len()];
+
+    for fk in table.foreign_keys.iter() {
+        if fk.origin_table == table.table_name {
+            self_references[column_index(table, &fk.fk_column)?] =
+                Some(column_index(table, &fk.origin_column)?);
+        }
+    }
+
+    Ok(self_references)
+}
+
+// Returns the position of the column in the columns of the table.
+fn column_index(table: &Table, column_name: &str) -> Result<usize> {
+    table
+        .columns
+        .iter()
+        .position(|column| column.column_name == column_name)
+        .ok_or_else(|| {
+            Error::NotFound(format!(
+                "Column '{}' doesn't exist in table '{}'",
+                column_name, table.table_name
+            ))
+        })
+}
+
+// Generates a random value fitting the column type of the column.
+// The constraints of the column decide the value if they set e.g. a range or a list of values.
+// Otherwise uses the generator of the column or the generator found by the name heuristics if there is one.
+// For Varchar the size of the column is kept and for DateTime the timezone of the column is kept.
+pub fn generate_value<R: Rng>(
+    column: &Column,
+    settings: &GenerationSettings,
+    rng: &mut R,
+) -> Result<Value> {
+    if let Some(constraints) = &column.constraints {
+        constraints.check(column)?;
+        if let Some(value) = generate_constrained_value(column, constraints, rng)? {
+            return Ok(value);
+        }
+    }
+
+    if let Some(kind) = generator_for_column(column, &settings.name_heuristics)? {
+        return Ok(generate_semantic_value(
+            kind,
+            column,
+            settings.reference_time(),
+            rng,
+        ));
+    }
+
+    let value = match &column.column_type {
+        ColumnType::Int8 => Value::Int8(rng.gen()),
+        ColumnType::Uint8 => Value::Uint8(rng.gen()),
+        ColumnType::Int16 => Value::Int16(rng.gen()),
+        ColumnType::Uint16 => Value::Uint16(rng.gen()),
+        ColumnType::Int32 => Value::Int32(rng.gen()),
+        ColumnType::Uint32 => Value::Uint32(rng.gen()),
+        ColumnType::Int64 => Value::Int64(rng.gen()),
+        ColumnType::Uint64 => Value::Uint64(rng.gen()),
+        // Floating point numbers are kept small and rounded to two decimals so they are readable
+        ColumnType::Float => {
+            Value::Float((rng.gen_range(-10_000.0f32..10_000.0) * 100.0).round() / 100.0)
+        }
+        ColumnType::Double => {
+            Value::Double((rng.gen_range(-10_000.0f64..10_000.0) * 100.0).round() / 100.0)
+        }
+        ColumnType::Decimal(precision, scale) => {
+            Value::Decimal(random_decimal(*precision, *scale, rng))
+        }
+        ColumnType::Boolean => Value::Boolean(rng.gen()),
+        ColumnType::Bit(size) if *size >= 64 => Value::Bit(rng.gen()),
+        ColumnType::Bit(size) => Value::Bit(rng.gen_range(0..1 << size)),
+        ColumnType::Char(size) => {
+            let words: Vec<String> = Words(1..3).fake_with_rng(rng);
+            Value::Char(words.join(" ").chars().take(*size as usize).collect())
+        }
+        ColumnType::Varchar(size) => {
+            let words: Vec<String> = Words(1..5).fake_with_rng(rng);
+            Value::Varchar(words.join(" ").chars().take(*size as usize).collect())
+        }
+        ColumnType::Text | ColumnType::MediumText | ColumnType::LongText => {
+            Value::Text(Paragraph(1..4).fake_with_rng(rng))
+        }
+        // BINARY is always filled up to its size
+        ColumnType::Binary(size) => Value::Binary(random_bytes(*size as usize, rng)),
+        // Binary data is kept short, it isn't readable anyway
+        ColumnType::Varbinary(size) => {
+            let length = rng.gen_range(0..=(*size as usize).min(64));
+            Value::Binary(random_bytes(length, rng))
+        }
+        ColumnType::Blob => {
+            let length = rng.gen_range(0..=64);
+            Value::Binary(random_bytes(length, rng))
+        }
+        ColumnType::Json => {
+            let name: String = Word().fake_with_rng(rng);
+            let tags: Vec<String> = Words(0..4).fake_with_rng(rng);
+            Value::Json(json!({
+                "id": rng.gen_range(1..10_000),
+                "name": name,
+                "active": rng.gen::<bool>(),
+                "tags": tags,
+            }))
+        }
+        ColumnType::Date | ColumnType::DateTime(_) | ColumnType::Timestamp => {
+            datetime_value(&column.column_type, random_datetime(rng))
+        }
+        ColumnType::Time => Value::Time(
+            NaiveTime::from_num_seconds_from_midnight_opt(rng.gen_range(0..86_400), 0)
+                .expect("Seconds of a day are a valid time"),
+        ),
+        // MySQL only allows years between 1901 and 2155
+        ColumnType::Year => Value::Year(rng.gen_range(1901..=2155)),
+        // The bytes come from rng instead of Uuid::new_v4(), so a seeded rng generates the same UUIDs
+        ColumnType::Uuid => Value::Uuid(Builder::from_random_bytes(rng.gen()).into_uuid()),
+        ColumnType::Enum(values) => {
+            if values.is_empty() {
+                return Err(Error::Generation(format!(
+                    "Enum column '{}' has no values",
+                    column.column_name
+                )));
+            }
+            Value::Enum(values[rng.gen_range(0..values.len())].clone())
+        }
+        // A set value is any combination of its members, including none of them
+        ColumnType::Set(values) => Value::Set(
+            values
+                .iter()
+                .filter(|_| rng.gen_bool(0.5))
+                .cloned()
+                .collect(),
+        ),
+        ColumnType::Geometry | ColumnType::Point | ColumnType::LineString | ColumnType::Polygon => {
+            Value::Geometry(random_geometry(
+                &column.column_type,
+                &settings.bounding_box,
+                rng,
+            )?)
+        }
+    };
+
+    Ok(value)
+}
+
+// Random decimal number with at most precision digits of which scale digits are after the decimal point.
+// Decimals are often amounts of money, so they are positive and use at most six digits before the decimal point.
+fn random_decimal<R: Rng>(precision: u8, scale: u8, rng: &mut R) -> String {
+    let integer_digits = precision.saturating_sub(scale).min(6) as u32;
+    let integer = rng.gen_range(0..10u32.pow(integer_digits));
+
+    let fraction: String = (0..scale)
+        .map(|_| char::from(b'0' + rng.gen_range(0..10)))
+        .collect();
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+fn random_bytes<R: Rng>(length: usize, rng: &mut R) -> Vec<u8> {
+    (0..length).map(|_| rng.gen()).collect()
+}
+
+// Random datetime in the default range of datetimes.
+fn random_datetime<R: Rng>(rng: &mut R) -> DateTime<Utc> {
+    let (start, end) = default_datetime_range();
+    DateTime::from_timestamp(rng.gen_range(start..=end), 0).expect("Timestamp in valid range")
+}
+
+// First and last second of generated datetimes, from 1970 to the end of 2037.
+// This range fits into every datetime type of the supported DBMS.
+// The first day is left out, MySQL's TIMESTAMP starts one second after 1970 in UTC.
+pub(crate) fn default_datetime_range() -> (i64, i64) {
+    let start = NaiveDate::from_ymd_opt(1970, 1, 2)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .expect("Valid date")
+        .and_utc()
+        .timestamp();
+    let end = NaiveDate::from_ymd_opt(2037, 12, 31)
+        .and_then(|date| date.and_hms_opt(23, 59, 59))
+        .expect("Valid date")
+        .and_utc()
+        .timestamp();
+
+    (start, end)
+}
+
+// Converts the datetime into the date or datetime type of the column.
+pub(crate) fn datetime_value(column_type: &ColumnType, datetime: DateTime<Utc>) -> Value {
+    match column_type {
+        ColumnType::Date => Value::Date(datetime.date_naive()),
+        ColumnType::DateTime(timezone) => Value::DateTime(datetime.with_timezone(timezone)),
+        ColumnType::Timestamp => Value::Timestamp(datetime),
+        _ => unreachable!("Only date, datetime and timestamp columns get datetimes"),
+    }
+}
+
+// Returns the value of an integer Value.
+// i128 holds the values of every signed and unsigned integer type.
+fn integer_value(value: &Value) -> Option<i128> {
+    match value {
+        Value::Int8(value) => Some(*value as i128),
+        Value::Uint8(value) => Some(*value as i128),
+        Value::Int16(value) => Some(*value as i128),
+        Value::Uint16(value) => Some(*value as i128),
+        Value::Int32(value) => Some(*value as i128),
+        Value::Uint32(value) => Some(*value as i128),
+        Value::Int64(value) => Some(*value as i128),
+        Value::Uint64(value) => Some(*value as i128),
+        _ => None,
+    }
+}
+
+// Creates a Value of the integer type of the column with the passed value.
+// Fails if the column isn't an integer column or the value doesn't fit into it.
+fn integer_column_value(column: &Column, value: i128) -> Result<Value> {
+    let out_of_range = || {
+        Error::Generation(format!(
+            "Auto increment value {} doesn't fit into column '{}'",
+            value, column.column_name
+        ))
+    };
+
+    match column.column_type {
+        ColumnType::Int8
+        | ColumnType::Uint8
+        | ColumnType::Int16
+        | ColumnType::Uint16
+        | ColumnType::Int32
+        | ColumnType::Uint32
+        | ColumnType::Int64
+        | ColumnType::Uint64 => {
+            integer_of_type(&column.column_type, value).ok_or_else(out_of_range)
+        }
+        _ => Err(Error::Generation(format!(
+            "Auto increment column '{}' isn't an integer column",
+            column.column_name
+        ))),
+    }
+}
+
+// Creates a Value of the integer column type with the passed value.
+// Returns None if the column type isn't an integer type or the value doesn't fit into it.
+pub(crate) fn integer_of_type(column_type: &ColumnType, value: i128) -> Option<Value> {
+    let value = match column_type {
+        ColumnType::Int8 => Value::Int8(value.try_into().ok()?),
+        ColumnType::Uint8 => Value::Uint8(value.try_into().ok()?),
+        ColumnType::Int16 => Value::Int16(value.try_into().ok()?),
+        ColumnType::Uint16 => Value::Uint16(value.try_into().ok()?),
+        ColumnType::Int32 => Value::Int32(value.try_into().ok()?),
+        ColumnType::Uint32 => Value::Uint32(value.try_into().ok()?),
+        ColumnType::Int64 => Value::Int64(value.try_into().ok()?),
+        ColumnType::Uint64 => Value::Uint64(value.try_into().ok()?),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashSet};
+
+    use chrono_tz::Tz;
+
+    use super::*;
+    use crate::db_mocker::ForeignKey;
+    use constraints::GenerationConstraints;
+    use uniqueness::value_key;
+
+    fn column(column_name: &str, column_type: ColumnType, auto_increment: bool) -> Column {
+        Column {
+            column_name: column_name.to_string(),
+            column_type,
+            default: None,
+            auto_increment,
+            not_null: true,
+            generator: None,
+            constraints: None,
+        }
+    }
+
+    fn example_table() -> Table {
+        Table {
+            table_name: "orders".to_string(),
+            columns: vec![
+                column("id", ColumnType::Uint8, true),
+                column("code", ColumnType::Varchar(5), false),
+                column("year", ColumnType::Year, false),
+                column(
+                    "status",
+                    ColumnType::Enum(vec!["new".to_string(), "done".to_string()]),
+                    false,
+                ),
+                column(
+                    "created_at",
+                    ColumnType::DateTime(Tz::Europe__Berlin),
+                    false,
+                ),
+            ],
+            primary_keys: vec!["id".to_string()],
+            foreign_keys: Vec::new(),
+            uniques: Vec::new(),
+            indices: Vec::new(),
+            datasets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn generated_values_fit_their_columns() {
+        let datasets = DummyDataGenerator::generate_datasets(
+            &example_table(),
+            100,
+            &GenerationSettings::default(),
+        )
+        .unwrap();
+        assert_eq!(datasets.len(), 100);
+
+        for (i, dataset) in datasets.iter().enumerate() {
+            assert!(matches!(dataset[0], Value::Uint8(id) if id as usize == i + 1));
+            assert!(matches!(&dataset[1], Value::Varchar(value) if value.chars().count() <= 5));
+            assert!(matches!(dataset[2], Value::Year(year) if (1901..=2155).contains(&year)));
+            assert!(matches!(&dataset[3], Value::Enum(value) if value == "new" || value == "done"));
+            assert!(
+                matches!(dataset[4], Value::DateTime(datetime) if datetime.timezone() == Tz::Europe__Berlin)
+            );
+        }
+    }
+
+    #[test]
+    fn generated_values_fit_every_column_type() {
+        let column_types = [
+            ColumnType::Decimal(10, 2),
+            ColumnType::Decimal(3, 3),
+            ColumnType::Char(2),
+            ColumnType::Text,
+            ColumnType::LongText,
+            ColumnType::Binary(16),
+            ColumnType::Varbinary(8),
+            ColumnType::Blob,
+            ColumnType::Json,
+            ColumnType::Timestamp,
+            ColumnType::Uuid,
+            ColumnType::Bit(3),
+            ColumnType::Bit(64),
+            ColumnType::Geometry,
+            ColumnType::Point,
+            ColumnType::LineString,
+            ColumnType::Polygon,
+        ];
+        let table = Table {
+            table_name: "values".to_string(),
+            columns: column_types
+                .iter()
+                .enumerate()
+                .map(|(i, column_type)| column(&format!("c{}", i), column_type.clone(), false))
+                .collect(),
+            primary_keys: Vec::new(),
+            foreign_keys: Vec::new(),
+            uniques: Vec::new(),
+            indices: Vec::new(),
+            datasets: Vec::new(),
+        };
+
+        let datasets =
+            DummyDataGenerator::generate_datasets(&table, 50, &GenerationSettings::default())
+                .unwrap();
+        for dataset in datasets.iter() {
+            for (value, column_type) in dataset.iter().zip(column_types.iter()) {
+                assert!(value.fits(column_type), "{:?} in {:?}", value, column_type);
+            }
+            assert!(matches!(&dataset[5], Value::Binary(bytes) if bytes.len() == 16));
+        }
+    }
+
+    #[test]
+    fn auto_increment_continues_existing_datasets_and_fails_on_overflow() {
+        let mut table = example_table();
+        DummyDataGenerator::fill_table(&mut table, 100, &GenerationSettings::default()).unwrap();
+        DummyDataGenerator::fill_table(&mut table, 155, &GenerationSettings::default()).unwrap();
+        assert!(matches!(table.datasets[254][0], Value::Uint8(255)));
+
+        // Uint8 can't go past 255
+        assert!(
+            DummyDataGenerator::fill_table(&mut table, 1, &GenerationSettings::default()).is_err()
+        );
+    }
+
+    #[test]
+    fn only_nullable_columns_get_null() {
+        let mut table = example_table();
+        table.columns[1].not_null = false;
+
+        let datasets =
+            DummyDataGenerator::generate_datasets(&table, 200, &GenerationSettings::default())
+                .unwrap();
+        assert!(datasets.iter().any(|dataset| dataset[1] == Value::Null));
+        for dataset in datasets.iter() {
+            assert!(dataset
+                .iter()
+                .enumerate()
+                .all(|(i, value)| i == 1 || *value != Value::Null));
+        }
+    }
+
+    #[test]
+    fn columns_follow_their_constraints() {
+        let mut table = example_table();
+        table.columns[3].column_type = ColumnType::Enum(vec![
+            "active".to_string(),
+            "paused".to_string(),
+            "closed".to_string(),
+        ]);
+        table.columns[3].constraints = Some(GenerationConstraints {
+            weights: BTreeMap::from([("active".to_string(), 0.9)]),
+            ..Default::default()
+        });
+        table.columns[1].not_null = false;
+        table.columns[1].constraints = Some(GenerationConstraints {
+            null_probability: Some(1.0),
+            ..Default::default()
+        });
+
+        let datasets =
+            DummyDataGenerator::generate_datasets(&table, 200, &GenerationSettings::default())
+                .unwrap();
+        let active = datasets
+            .iter()
+            .filter(|dataset| dataset[3] == Value::Enum("active".to_string()))
+            .count();
+        assert!((160..=195).contains(&active), "{} active", active);
+        assert!(datasets.iter().all(|dataset| dataset[1] == Value::Null));
+    }
+
+    #[test]
+    fn equal_values_have_the_same_key() {
+        let key = |value: &str| value_key(&Value::Decimal(value.to_string()));
+        assert_eq!(key("1.0"), key("1.00"));
+        assert_eq!(key("1"), key("001.000"));
+        assert_eq!(key("-0.00"), key("0"));
+        assert_ne!(key("1.5"), key("15"));
+        assert_ne!(key("-1"), key("1"));
+
+        assert_eq!(
+            value_key(&Value::Double(0.0)),
+            value_key(&Value::Double(-0.0))
+        );
+        assert_eq!(
+            value_key(&Value::Float(0.0)),
+            value_key(&Value::Float(-0.0))
+        );
+        assert_ne!(
+            value_key(&Value::Text("NULL".to_string())),
+            value_key(&Value::Null)
+        );
+
+        let text = |value: &str| value_key(&Value::Varchar(value.to_string()));
+        assert_eq!(text("Smith"), text("smith  "));
+        assert_ne!(text("Smith"), text(" Smith"));
+    }
+
+    #[test]
+    fn unique_columns_never_repeat_values() {
+        let mut table = example_table();
+        table.columns[2].constraints = Some(GenerationConstraints {
+            min: Some(2000.0),
+            max: Some(2049.0),
+            unique: true,
+            ..Default::default()
+        });
+
+        DummyDataGenerator::fill_table(&mut table, 40, &GenerationSettings::default()).unwrap();
+        DummyDataGenerator::fill_table(&mut table, 10, &GenerationSettings::default()).unwrap();
+        let years: HashSet<String> = table
+            .datasets
+            .iter()
+            .map(|dataset| value_key(&dataset[2]))
+            .collect();
+        assert_eq!(years.len(), 50);
+
+        // There are only 50 years between min and max
+        let error = DummyDataGenerator::fill_table(&mut table, 1, &GenerationSettings::default())
+            .unwrap_err();
+        assert!(error.to_string().contains("year"));
+    }
+
+    fn foreign_key(fk_column: &str, origin_table: &str, origin_column: &str) -> ForeignKey {
+        ForeignKey {
+            fk_column: fk_column.to_string(),
+            origin_table: origin_table.to_string(),
+            origin_column: origin_column.to_string(),
+        }
+    }
+
+    fn table(table_name: &str, foreign_keys: Vec<ForeignKey>) -> Table {
+        Table {
+            table_name: table_name.to_string(),
+            columns: vec![
+                column("id", ColumnType::Int32, true),
+                column("parent_id", ColumnType::Int32, false),
+            ],
+            primary_keys: vec!["id".to_string()],
+            foreign_keys,
+            uniques: Vec::new(),
+            indices: Vec::new(),
+            datasets: Vec::new(),
+        }
+    }
+
+    fn vdb(tables: Vec<Table>) -> VirtualDatabase {
+        VirtualDatabase {
+            database_name: "shop".to_string(),
+            users: Vec::new(),
+            tables,
+        }
+    }
+
+    #[test]
+    fn foreign_keys_reference_generated_parent_values() {
+        // Child is listed before its parent
+        let mut vdb = vdb(vec![
+            table("orders", vec![foreign_key("parent_id", "customers", "id")]),
+            table("customers", Vec::new()),
+        ]);
+
+        DummyDataGenerator::fill_database(&mut vdb, 20, &GenerationSettings::default()).unwrap();
+
+        let customer_ids: Vec<i128> = vdb.tables[1]
+            .datasets
+            .iter()
+            .map(|dataset| integer_value(&dataset[0]).unwrap())
+            .collect();
+        assert_eq!(customer_ids.len(), 20);
+        for dataset in vdb.tables[0].datasets.iter() {
+            assert!(customer_ids.contains(&integer_value(&dataset[1]).unwrap()));
+        }
+    }
+
+    #[test]
+    fn self_references_sample_from_own_datasets() {
+        let mut vdb = vdb(vec![table(
+            "categories",
+            vec![foreign_key("parent_id", "categories", "id")],
+        )]);
+
+        DummyDataGenerator::fill_database(&mut vdb, 10, &GenerationSettings::default()).unwrap();
+
+        for (i, dataset) in vdb.tables[0].datasets.iter().enumerate() {
+            let parent_id = integer_value(&dataset[1]).unwrap();
+            assert!((1..=i as i128 + 1).contains(&parent_id));
+        }
+    }
+
+    #[test]
+    fn unique_self_references_never_repeat_values() {
+        let mut categories = table(
+            "categories",
+            vec![foreign_key("parent_id", "categories", "id")],
+        );
+        categories.uniques = vec!["parent_id".to_string()];
+        let mut vdb = vdb(vec![categories]);
+
+        let settings = GenerationSettings::default();
+        DummyDataGenerator::fill_database(&mut vdb, 30, &settings).unwrap();
+        DummyDataGenerator::fill_database(&mut vdb, 20, &settings).unwrap();
+
+        let ids: Vec<i128> = vdb.tables[0]
+            .datasets
+            .iter()
+            .map(|dataset| integer_value(&dataset[0]).unwrap())
+            .collect();
+        let parent_ids: HashSet<i128> = vdb.tables[0]
+            .datasets
+            .iter()
+            .map(|dataset| integer_value(&dataset[1]).unwrap())
+            .collect();
+        assert_eq!(parent_ids.len(), 50);
+        assert!(parent_ids.iter().all(|parent_id| ids.contains(parent_id)));
+    }
+
+    #[test]
+    fn not_null_foreign_keys_never_reference_null() {
+        let mut customers = table("customers", Vec::new());
+        customers.columns[1].not_null = false;
+        customers.datasets = vec![
+            vec![Value::Int32(1), Value::Null],
+            vec![Value::Int32(2), Value::Int32(7)],
+        ];
+        // parent_id references legacy_id, which can be NULL
+        let mut categories = table(
+            "categories",
+            vec![
+                foreign_key("parent_id", "categories", "legacy_id"),
+                foreign_key("customer_id", "customers", "parent_id"),
+            ],
+        );
+        let mut legacy_id = column("legacy_id", ColumnType::Int32, false);
+        legacy_id.not_null = false;
+        categories.columns.push(legacy_id);
+        categories
+            .columns
+            .push(column("customer_id", ColumnType::Int32, false));
+        categories.datasets = vec![
+            vec![
+                Value::Int32(1),
+                Value::Int32(5),
+                Value::Null,
+                Value::Int32(7),
+            ],
+            vec![
+                Value::Int32(2),
+                Value::Int32(5),
+                Value::Int32(5),
+                Value::Int32(7),
+            ],
+        ];
+        let mut vdb = vdb(vec![customers, categories]);
+
+        DummyDataGenerator::fill_database(&mut vdb, 20, &GenerationSettings::default()).unwrap();
+
+        for dataset in vdb.tables[1].datasets.iter() {
+            assert_ne!(dataset[1], Value::Null);
+            assert_ne!(dataset[3], Value::Null);
+        }
+    }
+
+    #[test]
+    fn same_seed_generates_identical_datasets() {
+        let mut orders = table("orders", vec![foreign_key("parent_id", "customers", "id")]);
+        orders.columns.push(column(
+            "created_at",
+            ColumnType::DateTime(Tz::Europe__Berlin),
+            false,
+        ));
+        orders.columns.push(column("note", ColumnType::Text, false));
+        let empty = vdb(vec![orders, table("customers", Vec::new())]);
+
+        let generate = |seed: u64| {
+            let settings = GenerationSettings {
+                seed: Some(seed),
+                ..Default::default()
+            };
+            let mut vdb = empty.clone();
+            DummyDataGenerator::fill_database(&mut vdb, 30, &settings).unwrap();
+            serde_json::to_string(&vdb).unwrap()
+        };
+
+        assert_eq!(generate(42), generate(42));
+        assert_ne!(generate(42), generate(43));
+    }
+
+    // Number of distinct combinations of the columns in the datasets
+    fn distinct_count(datasets: &[Vec<Value>], columns: &[usize]) -> usize {
+        datasets
+            .iter()
+            .map(|dataset| {
+                columns
+                    .iter()
+                    .map(|&i| value_key(&dataset[i]))
+                    .collect::<Vec<String>>()
+            })
+            .collect::<HashSet<Vec<String>>>()
+            .len()
+    }
+
+    #[test]
+    fn primary_keys_and_uniques_get_distinct_values() {
+        let mut table = table("codes", Vec::new());
+        table.columns[0] = column("id", ColumnType::Int8, false);
+        table
+            .columns
+            .push(column("serial", ColumnType::Uint32, false));
+        table.uniques.push("serial".to_string());
+
+        // Every TINYINT value is used exactly once
+        let datasets =
+            DummyDataGenerator::generate_datasets(&table, 256, &GenerationSettings::default())
+                .unwrap();
+        assert_eq!(distinct_count(&datasets, &[0]), 256);
+        assert_eq!(distinct_count(&datasets, &[2]), 256);
+
+        let error =
+            DummyDataGenerator::generate_datasets(&table, 257, &GenerationSettings::default())
+                .unwrap_err();
+        assert!(error.to_string().contains("primary key (id)"));
+    }
+
+    #[test]
+    fn composite_primary_keys_get_distinct_combinations() {
+        let mut table = table("shifts", Vec::new());
+        table.columns = vec![
+            column("night", ColumnType::Boolean, false),
+            column(
+                "day",
+                ColumnType::Enum(vec![
+                    "mon".to_string(),
+                    "tue".to_string(),
+                    "wed".to_string(),
+                ]),
+                false,
+            ),
+        ];
+        table.primary_keys = vec!["night".to_string(), "day".to_string()];
+
+        DummyDataGenerator::fill_table(&mut table, 4, &GenerationSettings::default()).unwrap();
+        DummyDataGenerator::fill_table(&mut table, 2, &GenerationSettings::default()).unwrap();
+        assert_eq!(distinct_count(&table.datasets, &[0, 1]), 6);
+
+        let error = DummyDataGenerator::fill_table(&mut table, 1, &GenerationSettings::default())
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("only 0 of its 6 possible values are left"));
+    }
+
+    #[test]
+    fn foreign_keys_in_composite_primary_keys_get_distinct_combinations() {
+        let mut order_items = table(
+            "order_items",
+            vec![
+                foreign_key("id", "orders", "id"),
+                foreign_key("parent_id", "products", "id"),
+            ],
+        );
+        order_items.columns[0].auto_increment = false;
+        order_items.primary_keys = vec!["id".to_string(), "parent_id".to_string()];
+        let mut tables = vec![
+            order_items,
+            table("orders", Vec::new()),
+            table("products", Vec::new()),
+        ];
+        let settings = GenerationSettings::default();
+        DummyDataGenerator::fill_table(&mut tables[1], 4, &settings).unwrap();
+        DummyDataGenerator::fill_table(&mut tables[2], 4, &settings).unwrap();
+
+        // 4 orders and 4 products allow 16 order items
+        let references = foreign_key_references(&tables, 0, 16).unwrap();
+        let self_references = self_references(&tables[0]).unwrap();
+        let mut rng = rand::thread_rng();
+        let mut generate = |row_count: usize| {
+            generate_table_datasets(
+                &tables[0],
+                row_count,
+                &settings,
+                &references,
+                &self_references,
+                &mut rng,
+            )
+        };
+        assert_eq!(distinct_count(&generate(16).unwrap(), &[0, 1]), 16);
+        assert!(generate(17).is_err());
+    }
+
+    #[test]
+    fn foreign_key_cycles_are_rejected() {
+        let mut vdb = vdb(vec![
+            table("a", vec![foreign_key("parent_id", "b", "id")]),
+            table("b", vec![foreign_key("parent_id", "a", "id")]),
+        ]);
+
+        let error = DummyDataGenerator::fill_database(&mut vdb, 1, &GenerationSettings::default())
+            .unwrap_err();
+        assert!(error.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn columns_get_semantic_values() {
+        let mut table = example_table();
+        table
+            .columns
+            .push(column("email", ColumnType::Varchar(255), false));
+        // Explicit generator wins over the column name
+        let mut city = column("code_2", ColumnType::Varchar(100), false);
+        city.generator = Some(semantic::GeneratorKind::City);
+        table.columns.push(city);
+
+        let datasets =
+            DummyDataGenerator::generate_datasets(&table, 10, &GenerationSettings::default())
+                .unwrap();
+        for dataset in datasets.iter() {
+            assert!(matches!(&dataset[5], Value::Varchar(value) if value.contains('@')));
+            assert!(matches!(&dataset[6], Value::Varchar(value) if !value.is_empty()));
+        }
+
+        // An explicit generator has to fit the column type
+        table.columns[2].generator = Some(semantic::GeneratorKind::Email);
+        assert!(
+            DummyDataGenerator::generate_datasets(&table, 1, &GenerationSettings::default())
+                .is_err()
+        );
+    }
+}
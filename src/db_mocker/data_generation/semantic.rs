@@ -0,0 +1,311 @@
+// Thoughts about this code
+
+// A GeneratorKind is chosen for a column in this order:
+//     1. The generator explicitly set on the Column
+//     2. The first pattern of the NameHeuristics matching the column name
+//     3. None, the column gets random values for its column type
+// An explicit generator that doesn't fit the column type is an error.
+// A generator found by the heuristics that doesn't fit the column type is ignored,
+// because a column named e.g. phone_count is an integer and not a phone number.
+
+use chrono::{DateTime, Duration, Utc};
+use fake::faker::address::en::{CityName, CountryCode, CountryName, StreetName, ZipCode};
+use fake::faker::company::en::CompanyName;
+use fake::faker::creditcard::en::CreditCardNumber;
+use fake::faker::currency::en::CurrencyCode;
+use fake::faker::finance::en::Bic;
+use fake::faker::internet::en::{Password, SafeEmail, Username, IP};
+use fake::faker::lorem::en::Sentence;
+use fake::faker::name::en::{FirstName, LastName, Name};
+use fake::faker::phone_number::en::PhoneNumber;
+use fake::Fake;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::datetime_value;
+use crate::db_mocker::error::{Error, Result};
+use crate::db_mocker::{Column, ColumnType, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GeneratorKind {
+    Email,
+    Username,
+    Password,
+    FirstName,
+    LastName,
+    FullName,
+    CompanyName,
+    Street,
+    City,
+    ZipCode,
+    Country,
+    CountryCode,
+    Phone,
+    Iban,
+    Bic,
+    CreditCardNumber,
+    CurrencyCode,
+    IpAddress,
+    Sentence,
+    // Date or datetime within the last ten years
+    PastDateTime,
+}
+
+impl GeneratorKind {
+    // Returns if the generator can produce values for the column type.
+    pub fn fits(&self, column_type: &ColumnType) -> bool {
+        match self {
+            GeneratorKind::PastDateTime => {
+                matches!(
+                    column_type,
+                    ColumnType::Date | ColumnType::DateTime(_) | ColumnType::Timestamp
+                )
+            }
+            _ => matches!(
+                column_type,
+                ColumnType::Char(_)
+                    | ColumnType::Varchar(_)
+                    | ColumnType::Text
+                    | ColumnType::MediumText
+                    | ColumnType::LongText
+            ),
+        }
+    }
+}
+
+// Mapping from column name patterns to generators.
+// Patterns are matched case insensitive against the whole column name.
+// A '*' in a pattern matches any sequence of characters, e.g. "*_at" matches "created_at".
+pub struct NameHeuristics {
+    patterns: Vec<(String, GeneratorKind)>,
+}
+
+impl NameHeuristics {
+    // Creates NameHeuristics without any patterns.
+    pub fn empty() -> Self {
+        NameHeuristics {
+            patterns: Vec::new(),
+        }
+    }
+
+    // Adds a pattern that takes precedence over all patterns added before it.
+    pub fn add_pattern(&mut self, pattern: &str, kind: GeneratorKind) {
+        self.patterns.insert(0, (pattern.to_lowercase(), kind));
+    }
+
+    // Returns the generator of the first pattern matching the column name.
+    pub fn generator_for(&self, column_name: &str) -> Option<GeneratorKind> {
+        let column_name = column_name.to_lowercase();
+
+        self.patterns
+            .iter()
+            .find(|(pattern, _)| matches_pattern(pattern, &column_name))
+            .map(|(_, kind)| *kind)
+    }
+}
+
+impl Default for NameHeuristics {
+    // Creates NameHeuristics with patterns for common column names.
+    fn default() -> Self {
+        let patterns = [
+            ("*email*", GeneratorKind::Email),
+            ("*username*", GeneratorKind::Username),
+            ("login", GeneratorKind::Username),
+            ("*password*", GeneratorKind::Password),
+            ("first_name", GeneratorKind::FirstName),
+            ("firstname", GeneratorKind::FirstName),
+            ("given_name", GeneratorKind::FirstName),
+            ("last_name", GeneratorKind::LastName),
+            ("lastname", GeneratorKind::LastName),
+            ("surname", GeneratorKind::LastName),
+            ("family_name", GeneratorKind::LastName),
+            ("name", GeneratorKind::FullName),
+            ("full_name", GeneratorKind::FullName),
+            ("fullname", GeneratorKind::FullName),
+            ("company*", GeneratorKind::CompanyName),
+            ("*street*", GeneratorKind::Street),
+            ("address", GeneratorKind::Street),
+            ("city", GeneratorKind::City),
+            ("*_city", GeneratorKind::City),
+            ("town", GeneratorKind::City),
+            ("zip*", GeneratorKind::ZipCode),
+            ("*postal_code*", GeneratorKind::ZipCode),
+            ("postcode", GeneratorKind::ZipCode),
+            ("country", GeneratorKind::Country),
+            ("country_code", GeneratorKind::CountryCode),
+            ("*phone*", GeneratorKind::Phone),
+            ("mobile*", GeneratorKind::Phone),
+            ("*iban*", GeneratorKind::Iban),
+            ("bic", GeneratorKind::Bic),
+            ("*_bic", GeneratorKind::Bic),
+            ("swift*", GeneratorKind::Bic),
+            ("*credit_card*", GeneratorKind::CreditCardNumber),
+            ("*currency*", GeneratorKind::CurrencyCode),
+            ("ip", GeneratorKind::IpAddress),
+            ("*ip_address*", GeneratorKind::IpAddress),
+            ("description", GeneratorKind::Sentence),
+            ("*comment*", GeneratorKind::Sentence),
+            ("notes", GeneratorKind::Sentence),
+            ("*_at", GeneratorKind::PastDateTime),
+            ("*_on", GeneratorKind::PastDateTime),
+            ("created*", GeneratorKind::PastDateTime),
+            ("updated*", GeneratorKind::PastDateTime),
+        ];
+
+        NameHeuristics {
+            patterns: patterns
+                .into_iter()
+                .map(|(pattern, kind)| (pattern.to_string(), kind))
+                .collect(),
+        }
+    }
+}
+
+// Returns the generator to use for the column, if any.
+// Fails if the generator explicitly set on the column doesn't fit its column type.
+pub fn generator_for_column(
+    column: &Column,
+    heuristics: &NameHeuristics,
+) -> Result<Option<GeneratorKind>> {
+    if let Some(kind) = column.generator {
+        if !kind.fits(&column.column_type) {
+            return Err(Error::Generation(format!(
+                "Generator {:?} doesn't fit the column type of column '{}'",
+                kind, column.column_name
+            )));
+        }
+        return Ok(Some(kind));
+    }
+
+    Ok(heuristics
+        .generator_for(&column.column_name)
+        .filter(|kind| kind.fits(&column.column_type)))
+}
+
+// Generates a value with the generator for the column.
+// The generator has to fit the column type of the column.
+// Char and Varchar values are cut to the size of the column.
+// PastDateTime generates datetimes of the ten years before reference_time.
+pub fn generate_semantic_value<R: Rng>(
+    kind: GeneratorKind,
+    column: &Column,
+    reference_time: DateTime<Utc>,
+    rng: &mut R,
+) -> Value {
+    let text: String = match kind {
+        GeneratorKind::Email => SafeEmail().fake_with_rng(rng),
+        GeneratorKind::Username => Username().fake_with_rng(rng),
+        GeneratorKind::Password => Password(8..17).fake_with_rng(rng),
+        GeneratorKind::FirstName => FirstName().fake_with_rng(rng),
+        GeneratorKind::LastName => LastName().fake_with_rng(rng),
+        GeneratorKind::FullName => Name().fake_with_rng(rng),
+        GeneratorKind::CompanyName => CompanyName().fake_with_rng(rng),
+        GeneratorKind::Street => StreetName().fake_with_rng(rng),
+        GeneratorKind::City => CityName().fake_with_rng(rng),
+        GeneratorKind::ZipCode => ZipCode().fake_with_rng(rng),
+        GeneratorKind::Country => CountryName().fake_with_rng(rng),
+        GeneratorKind::CountryCode => CountryCode().fake_with_rng(rng),
+        GeneratorKind::Phone => PhoneNumber().fake_with_rng(rng),
+        GeneratorKind::Iban => random_iban(rng),
+        GeneratorKind::Bic => Bic().fake_with_rng(rng),
+        GeneratorKind::CreditCardNumber => CreditCardNumber().fake_with_rng(rng),
+        GeneratorKind::CurrencyCode => CurrencyCode().fake_with_rng(rng),
+        GeneratorKind::IpAddress => IP().fake_with_rng(rng),
+        GeneratorKind::Sentence => Sentence(3..10).fake_with_rng(rng),
+        GeneratorKind::PastDateTime => {
+            let past = reference_time
+                - Duration::days(rng.gen_range(0..3650))
+                - Duration::seconds(rng.gen_range(0..86_400));
+            return datetime_value(&column.column_type, past);
+        }
+    };
+
+    match &column.column_type {
+        ColumnType::Char(size) => Value::Char(text.chars().take(*size as usize).collect()),
+        ColumnType::Varchar(size) => Value::Varchar(text.chars().take(*size as usize).collect()),
+        ColumnType::Text | ColumnType::MediumText | ColumnType::LongText => Value::Text(text),
+        _ => unreachable!("Text generators only fit char, varchar and text columns"),
+    }
+}
+
+// Generates a German IBAN with valid check digits.
+// fake has no IBAN generator, so it is built here from a random bank code and account number.
+fn random_iban<R: Rng>(rng: &mut R) -> String {
+    let bban: String = (0..18)
+        .map(|_| char::from(b'0' + rng.gen_range(0..10)))
+        .collect();
+
+    // Check digits are 98 minus the remainder of BBAN + country code + "00" modulo 97
+    // Letters of the country code count as numbers, A = 10 to Z = 35, so DE becomes 1314
+    let remainder = format!("{}131400", bban)
+        .chars()
+        .fold(0u32, |remainder, digit| {
+            (remainder * 10 + digit.to_digit(10).expect("Only digits")) % 97
+        });
+
+    format!("DE{:02}{}", 98 - remainder, bban)
+}
+
+// Matches the name against a pattern where '*' matches any sequence of characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            // Try every possible length for the sequence the '*' matches
+            (0..=name.len())
+                .filter(|&i| name.is_char_boundary(i))
+                .any(|i| matches_pattern(rest, &name[i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::thread_rng;
+
+    use super::*;
+
+    #[test]
+    fn matches_patterns_with_wildcards() {
+        assert!(matches_pattern("*_at", "created_at"));
+        assert!(matches_pattern("*email*", "billing_email_address"));
+        assert!(matches_pattern("city", "city"));
+        assert!(!matches_pattern("city", "city_id"));
+        assert!(!matches_pattern("*_at", "created_on"));
+    }
+
+    #[test]
+    fn later_patterns_take_precedence() {
+        let mut heuristics = NameHeuristics::default();
+        assert_eq!(
+            heuristics.generator_for("Email"),
+            Some(GeneratorKind::Email)
+        );
+
+        heuristics.add_pattern("email", GeneratorKind::Username);
+        assert_eq!(
+            heuristics.generator_for("email"),
+            Some(GeneratorKind::Username)
+        );
+        assert_eq!(NameHeuristics::empty().generator_for("email"), None);
+    }
+
+    #[test]
+    fn ibans_have_valid_check_digits() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let iban = random_iban(&mut rng);
+            assert_eq!(iban.len(), 22);
+
+            // Moving the first four characters to the end has to give a remainder of 1
+            let rearranged = format!("{}1314{}", &iban[4..], &iban[2..4]);
+            let remainder = rearranged.chars().fold(0u32, |remainder, digit| {
+                (remainder * 10 + digit.to_digit(10).unwrap()) % 97
+            });
+            assert_eq!(remainder, 1);
+        }
+    }
+}
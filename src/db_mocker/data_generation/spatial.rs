@@ -0,0 +1,223 @@
+// Thoughts about this code
+
+// Spatial values are generated inside the BoundingBox of the GenerationSettings.
+// The default box covers the whole world in longitude and latitude.
+// A smaller box keeps the values realistic, e.g. delivery points inside the area of a city.
+
+// Coordinates are rounded to six decimals, which is about 10 cm for longitude and latitude.
+// Polygons are star shaped around their center, so their ring never crosses itself and is always valid.
+
+use std::f64::consts::TAU;
+
+use rand::Rng;
+
+use crate::db_mocker::error::{Error, Result};
+use crate::db_mocker::geometry::Geometry;
+use crate::db_mocker::ColumnType;
+
+// Area the coordinates of generated spatial values lie in.
+// For geographic data x is the longitude and y the latitude.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Default for BoundingBox {
+    // The whole world
+    fn default() -> Self {
+        BoundingBox {
+            min_x: -180.0,
+            min_y: -90.0,
+            max_x: 180.0,
+            max_y: 90.0,
+        }
+    }
+}
+
+impl BoundingBox {
+    // Checks if the point lies inside the box, including its border.
+    pub fn contains(&self, (x, y): (f64, f64)) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    // A box without area can't hold a polygon.
+    fn check(&self) -> Result<()> {
+        let finite = [self.min_x, self.min_y, self.max_x, self.max_y]
+            .iter()
+            .all(|value| value.is_finite());
+        if !finite || self.min_x >= self.max_x || self.min_y >= self.max_y {
+            return Err(Error::Generation(format!(
+                "Bounding box from ({} {}) to ({} {}) has no area",
+                self.min_x, self.min_y, self.max_x, self.max_y
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+// Generates a random geometry for the spatial column type inside the bounding box.
+// A Geometry column gets a point, line string or polygon.
+pub fn random_geometry<R: Rng>(
+    column_type: &ColumnType,
+    bounding_box: &BoundingBox,
+    rng: &mut R,
+) -> Result<Geometry> {
+    bounding_box.check()?;
+
+    let geometry = match column_type {
+        ColumnType::Point => random_point(bounding_box, rng),
+        ColumnType::LineString => random_line_string(bounding_box, rng),
+        ColumnType::Polygon => random_polygon(bounding_box, rng),
+        ColumnType::Geometry => match rng.gen_range(0..3) {
+            0 => random_point(bounding_box, rng),
+            1 => random_line_string(bounding_box, rng),
+            _ => random_polygon(bounding_box, rng),
+        },
+        _ => {
+            return Err(Error::Generation(format!(
+                "{:?} isn't a spatial column type",
+                column_type
+            )))
+        }
+    };
+
+    Ok(geometry)
+}
+
+fn random_point<R: Rng>(bounding_box: &BoundingBox, rng: &mut R) -> Geometry {
+    let (x, y) = random_coordinates(bounding_box, rng);
+    Geometry::Point(x, y)
+}
+
+// Line string of two to six points.
+fn random_line_string<R: Rng>(bounding_box: &BoundingBox, rng: &mut R) -> Geometry {
+    let length = rng.gen_range(2..=6);
+    Geometry::LineString(
+        (0..length)
+            .map(|_| random_coordinates(bounding_box, rng))
+            .collect(),
+    )
+}
+
+// Polygon without holes and with three to eight corners.
+// The corners are placed counter clockwise around a center with a random distance each.
+fn random_polygon<R: Rng>(bounding_box: &BoundingBox, rng: &mut R) -> Geometry {
+    // The polygon covers between 5% and 50% of the width and height of the box
+    let width = (bounding_box.max_x - bounding_box.min_x) * rng.gen_range(0.05..=0.5);
+    let height = (bounding_box.max_y - bounding_box.min_y) * rng.gen_range(0.05..=0.5);
+    let center_x =
+        rng.gen_range(bounding_box.min_x + width / 2.0..=bounding_box.max_x - width / 2.0);
+    let center_y =
+        rng.gen_range(bounding_box.min_y + height / 2.0..=bounding_box.max_y - height / 2.0);
+
+    // Every corner gets its own slice of the circle, so the angles always increase
+    let corners = rng.gen_range(3..=8);
+    let mut ring: Vec<(f64, f64)> = (0..corners)
+        .map(|i| {
+            let angle = (i as f64 + rng.gen_range(0.0..1.0)) / corners as f64 * TAU;
+            let distance = rng.gen_range(0.5..=1.0);
+            (
+                round_coordinate(
+                    center_x + angle.cos() * distance * width / 2.0,
+                    bounding_box.min_x,
+                    bounding_box.max_x,
+                ),
+                round_coordinate(
+                    center_y + angle.sin() * distance * height / 2.0,
+                    bounding_box.min_y,
+                    bounding_box.max_y,
+                ),
+            )
+        })
+        .collect();
+    // The ring ends with its first point
+    ring.push(ring[0]);
+
+    Geometry::Polygon(vec![ring])
+}
+
+fn random_coordinates<R: Rng>(bounding_box: &BoundingBox, rng: &mut R) -> (f64, f64) {
+    (
+        round_coordinate(
+            rng.gen_range(bounding_box.min_x..=bounding_box.max_x),
+            bounding_box.min_x,
+            bounding_box.max_x,
+        ),
+        round_coordinate(
+            rng.gen_range(bounding_box.min_y..=bounding_box.max_y),
+            bounding_box.min_y,
+            bounding_box.max_y,
+        ),
+    )
+}
+
+// Rounds the coordinate to six decimals without leaving the range of the box.
+fn round_coordinate(value: f64, min: f64, max: f64) -> f64 {
+    ((value * 1_000_000.0).round() / 1_000_000.0).clamp(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::db_mocker::Value;
+
+    // Around Berlin
+    fn berlin() -> BoundingBox {
+        BoundingBox {
+            min_x: 13.08,
+            min_y: 52.33,
+            max_x: 13.76,
+            max_y: 52.68,
+        }
+    }
+
+    fn points(geometry: &Geometry) -> Vec<(f64, f64)> {
+        match geometry {
+            Geometry::Point(x, y) => vec![(*x, *y)],
+            Geometry::LineString(points) => points.clone(),
+            Geometry::Polygon(rings) => rings.concat(),
+        }
+    }
+
+    #[test]
+    fn generates_valid_geometries_inside_the_bounding_box() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let bounding_box = berlin();
+
+        for column_type in [
+            ColumnType::Point,
+            ColumnType::LineString,
+            ColumnType::Polygon,
+            ColumnType::Geometry,
+        ] {
+            for _ in 0..200 {
+                let geometry = random_geometry(&column_type, &bounding_box, &mut rng).unwrap();
+                assert!(Value::Geometry(geometry.clone()).fits(&column_type));
+                assert!(points(&geometry)
+                    .into_iter()
+                    .all(|point| bounding_box.contains(point)));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_bounding_box_without_area() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let bounding_box = BoundingBox {
+            max_x: 13.08,
+            ..berlin()
+        };
+
+        assert!(matches!(
+            random_geometry(&ColumnType::Point, &bounding_box, &mut rng),
+            Err(Error::Generation(_))
+        ));
+    }
+}
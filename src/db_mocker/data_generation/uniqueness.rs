@@ -0,0 +1,513 @@
+// Thoughts about this code
+
+// The primary key, every column in uniques and every column with the unique constraint form a UniqueGroup.
+// The values of the columns of a group have to be distinct as a combination, e.g. (order_id, product_id) of a composite primary key.
+// Groups with an auto increment column are left out, the auto increment values are distinct already.
+// Index has no unique flag, so a unique index over multiple columns can't be described and isn't kept distinct.
+// NULL never equals anything in the DBMS, so a dataset with NULL in a group doesn't use up a combination.
+
+// Two strategies keep the combinations distinct:
+//     - Enumerating, if the group has few possible combinations compared to the datasets, e.g. a TINYINT or an enum with three values.
+//       Every combination that isn't used yet is a candidate and every dataset takes a random one of them.
+//       Random values would repeat more and more often the fewer combinations are left, this never needs a retry.
+//     - Retrying, for all other groups. A combination that is used already is generated again, up to MAX_UNIQUE_ATTEMPTS times.
+// If the group can't have enough combinations for the datasets, generation fails before generating anything.
+
+// Enumerated candidates are picked with equal probability, so weights of enum values aren't kept for them.
+// Values of self referencing foreign keys can only be sampled once the referenced column is distinct.
+// So their groups are made distinct in a second pass and are never enumerated, the values they can reference grow with every dataset.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use super::constraints::{enum_weights, format_decimal, integer_bounds};
+use super::semantic::generator_for_column;
+use super::GenerationSettings;
+use crate::db_mocker::error::{Error, Result};
+use crate::db_mocker::{Column, ColumnType, Table, Value};
+
+// Attempts to generate a combination that isn't used yet in a group.
+const MAX_UNIQUE_ATTEMPTS: usize = 1000;
+// Groups with more possible combinations are never enumerated.
+const ENUMERATION_LIMIT: u128 = 1 << 16;
+
+// Columns whose combined values are distinct in every dataset.
+pub(crate) struct UniqueGroup {
+    // Description for error messages, e.g. "primary key (order_id, product_id)"
+    description: String,
+    table_name: String,
+    columns: Vec<usize>,
+    // Combinations in the existing and the generated datasets
+    used: HashSet<Vec<String>>,
+    // Combinations not used yet, if the group is enumerated
+    candidates: Option<Vec<Vec<Value>>>,
+}
+
+impl UniqueGroup {
+    // Checks if one of the columns of the group matches the predicate.
+    pub(crate) fn has_column(&self, predicate: impl Fn(usize) -> bool) -> bool {
+        self.columns.iter().any(|&i| predicate(i))
+    }
+
+    // Key of the combination in the dataset, None if one of the values is NULL.
+    fn key(&self, dataset: &[Value]) -> Option<Vec<String>> {
+        self.columns
+            .iter()
+            .map(|&i| match &dataset[i] {
+                Value::Null => None,
+                value => Some(value_key(value)),
+            })
+            .collect()
+    }
+
+    // Marks the combination as used and removes it from the candidates.
+    fn commit(&mut self, key: Vec<String>, pick: Option<usize>) {
+        if let Some(candidates) = &mut self.candidates {
+            let is_key =
+                |candidate: &Vec<Value>| candidate.iter().map(value_key).eq(key.iter().cloned());
+            // The picked candidate only differs if another group overwrote one of its values
+            let position = match pick {
+                Some(pick) if is_key(&candidates[pick]) => Some(pick),
+                _ => candidates.iter().position(is_key),
+            };
+            if let Some(position) = position {
+                candidates.swap_remove(position);
+            }
+        }
+
+        self.used.insert(key);
+    }
+
+    fn not_enough_combinations(&self, row_count: usize, available: u128, possible: u128) -> Error {
+        Error::Generation(format!(
+            "Table '{}' can't get {} more datasets with distinct values in {}, only {} of its {} possible values are left",
+            self.table_name, row_count, self.description, available, possible
+        ))
+    }
+
+    fn used_up(&self) -> Error {
+        Error::Generation(format!(
+            "Table '{}' has no distinct values left in {}",
+            self.table_name, self.description
+        ))
+    }
+}
+
+// Key of a value for comparing values of the same column.
+// Value can't be hashed because of its floating point numbers.
+// Values the DBMS considers equal get the same key, e.g. the decimals 1.0 and 1.00 or the floats 0.0 and -0.0.
+// Text is compared like the default collations of MySQL do, without case and trailing spaces.
+pub(crate) fn value_key(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Int8(value) => format!("int:{}", value),
+        Value::Uint8(value) => format!("int:{}", value),
+        Value::Int16(value) => format!("int:{}", value),
+        Value::Uint16(value) => format!("int:{}", value),
+        Value::Int32(value) => format!("int:{}", value),
+        Value::Uint32(value) => format!("int:{}", value),
+        Value::Int64(value) => format!("int:{}", value),
+        Value::Uint64(value) => format!("int:{}", value),
+        // Adding 0.0 turns -0.0 into 0.0
+        Value::Float(value) => format!("float:{}", value + 0.0),
+        Value::Double(value) => format!("float:{}", value + 0.0),
+        Value::Decimal(value) => format!("decimal:{}", decimal_key(value)),
+        Value::Boolean(value) => format!("bool:{}", value),
+        Value::Bit(value) => format!("bit:{}", value),
+        Value::Char(value) | Value::Varchar(value) | Value::Text(value) => {
+            format!("text:{}", text_key(value))
+        }
+        Value::Binary(value) => format!("binary:{:02x?}", value),
+        Value::Json(value) => format!("json:{}", value),
+        Value::Date(value) => format!("date:{}", value),
+        Value::Time(value) => format!("time:{}", value),
+        // The same instant in different time zones is the same value
+        Value::DateTime(value) => format!("datetime:{}", value.naive_utc()),
+        Value::Timestamp(value) => format!("datetime:{}", value.naive_utc()),
+        Value::Year(value) => format!("year:{}", value),
+        Value::Uuid(value) => format!("uuid:{}", value),
+        Value::Enum(value) => format!("enum:{}", text_key(value)),
+        // Members of a set have no order
+        Value::Set(members) => {
+            let mut members: Vec<String> = members.iter().map(|member| text_key(member)).collect();
+            members.sort();
+            members.dedup();
+            format!("set:{}", members.join(","))
+        }
+        Value::Geometry(geometry) => format!("geometry:{}", geometry.to_wkt()),
+    }
+}
+
+// Normalizes text like "Smith  " to "smith".
+// MySQL compares text case insensitive and pads it with spaces, so trailing spaces don't count.
+fn text_key(value: &str) -> String {
+    value.trim_end_matches(' ').to_lowercase()
+}
+
+// Normalizes a decimal like "-012.50" to "-12.5", "-0.00" becomes "0".
+fn decimal_key(value: &str) -> String {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let integer = integer.trim_start_matches('0');
+    let fraction = fraction.trim_end_matches('0');
+
+    let mut key = if integer.is_empty() {
+        "0".to_string()
+    } else {
+        integer.to_string()
+    };
+    if !fraction.is_empty() {
+        key = format!("{}.{}", key, fraction);
+    }
+    if negative && key != "0" {
+        key = format!("-{}", key);
+    }
+    key
+}
+
+// Collects the groups of the table whose values have to be distinct and chooses the strategy for each of them.
+// references are the values of the referenced column for each foreign key column, they are all the column can get.
+// self_references mark the foreign key columns referencing the table itself, their possible values are unknown yet.
+// Fails if a group can't have row_count more distinct combinations.
+pub(crate) fn unique_groups(
+    table: &Table,
+    row_count: usize,
+    settings: &GenerationSettings,
+    references: &[Option<Vec<Value>>],
+    self_references: &[Option<usize>],
+) -> Result<Vec<UniqueGroup>> {
+    let column_index = |column_name: &String| {
+        table
+            .columns
+            .iter()
+            .position(|column| column.column_name == *column_name)
+            .ok_or_else(|| {
+                Error::NotFound(format!(
+                    "Column '{}' doesn't exist in table '{}'",
+                    column_name, table.table_name
+                ))
+            })
+    };
+
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    if !table.primary_keys.is_empty() {
+        let columns = table
+            .primary_keys
+            .iter()
+            .map(column_index)
+            .collect::<Result<Vec<usize>>>()?;
+        groups.push((
+            format!("primary key ({})", table.primary_keys.join(", ")),
+            columns,
+        ));
+    }
+    for column_name in table.uniques.iter() {
+        groups.push((
+            format!("unique column '{}'", column_name),
+            vec![column_index(column_name)?],
+        ));
+    }
+    for (i, column) in table.columns.iter().enumerate() {
+        if column
+            .constraints
+            .as_ref()
+            .is_some_and(|constraints| constraints.unique)
+        {
+            groups.push((format!("unique column '{}'", column.column_name), vec![i]));
+        }
+    }
+
+    let mut unique_groups: Vec<UniqueGroup> = Vec::new();
+    for (description, columns) in groups {
+        let already_collected = unique_groups.iter().any(|group| group.columns == columns);
+        if already_collected || columns.iter().any(|&i| table.columns[i].auto_increment) {
+            continue;
+        }
+
+        let mut group = UniqueGroup {
+            description,
+            table_name: table.table_name.clone(),
+            columns,
+            used: HashSet::new(),
+            candidates: None,
+        };
+        group.used = table
+            .datasets
+            .iter()
+            .filter_map(|dataset| group.key(dataset))
+            .collect();
+
+        // Number of possible combinations, None if at least one column has unknown or practically unlimited values
+        let possible = group
+            .columns
+            .iter()
+            .map(|&i| match self_references[i] {
+                Some(_) => None,
+                None => domain_size(&table.columns[i], references[i].as_deref(), settings),
+            })
+            .try_fold(1u128, |product, size| product.checked_mul(size?));
+
+        if let Some(possible) = possible {
+            let needed = group.used.len() as u128 + row_count as u128;
+            if possible <= ENUMERATION_LIMIT && possible < 2 * needed {
+                group.candidates = enumerate_combinations(&group, table, references, settings);
+            }
+
+            // Columns that can be NULL may need fewer combinations, they fail while generating if they run out
+            let never_null = group.columns.iter().all(|&i| {
+                table.columns[i].not_null
+                    || table.primary_keys.contains(&table.columns[i].column_name)
+            });
+            let available = match &group.candidates {
+                Some(candidates) => candidates.len() as u128,
+                None => possible.saturating_sub(group.used.len() as u128),
+            };
+            if never_null && available < row_count as u128 {
+                return Err(group.not_enough_combinations(row_count, available, possible));
+            }
+        }
+
+        unique_groups.push(group);
+    }
+
+    Ok(unique_groups)
+}
+
+// Changes the values of the unique groups in the dataset until every combination is distinct and marks them as used.
+// Enumerated groups take one of their candidates, the other groups get new values from regenerate if they collide.
+pub(crate) fn make_distinct<R: Rng>(
+    groups: &mut [UniqueGroup],
+    dataset: &mut [Value],
+    regenerate: &mut dyn FnMut(usize, &mut R) -> Result<Value>,
+    rng: &mut R,
+) -> Result<()> {
+    let mut collided = vec![false; groups.len()];
+
+    for _ in 0..MAX_UNIQUE_ATTEMPTS {
+        let mut picks = vec![None; groups.len()];
+        for (g, group) in groups.iter().enumerate() {
+            match &group.candidates {
+                Some(candidates) => {
+                    if group.key(dataset).is_none() {
+                        continue;
+                    }
+                    if candidates.is_empty() {
+                        return Err(group.used_up());
+                    }
+                    let pick = rng.gen_range(0..candidates.len());
+                    for (&i, value) in group.columns.iter().zip(candidates[pick].iter()) {
+                        dataset[i] = value.clone();
+                    }
+                    picks[g] = Some(pick);
+                }
+                None if collided[g] => {
+                    for &i in group.columns.iter() {
+                        dataset[i] = regenerate(i, rng)?;
+                    }
+                }
+                None => {}
+            }
+        }
+
+        let keys: Vec<Option<Vec<String>>> =
+            groups.iter().map(|group| group.key(dataset)).collect();
+        for (g, key) in keys.iter().enumerate() {
+            collided[g] = key.as_ref().is_some_and(|key| groups[g].used.contains(key));
+        }
+
+        if !collided.contains(&true) {
+            for ((group, key), pick) in groups.iter_mut().zip(keys).zip(picks) {
+                if let Some(key) = key {
+                    group.commit(key, pick);
+                }
+            }
+            return Ok(());
+        }
+    }
+
+    let group = &groups[collided.iter().position(|collided| *collided).unwrap_or(0)];
+    Err(Error::Generation(format!(
+        "Found no distinct values for {} of table '{}' after {} attempts, its possible values are probably used up",
+        group.description, group.table_name, MAX_UNIQUE_ATTEMPTS
+    )))
+}
+
+// Lists every combination of the group that isn't used yet.
+// Returns None if the values of a column can't be listed.
+fn enumerate_combinations(
+    group: &UniqueGroup,
+    table: &Table,
+    references: &[Option<Vec<Value>>],
+    settings: &GenerationSettings,
+) -> Option<Vec<Vec<Value>>> {
+    let mut combinations: Vec<Vec<Value>> = vec![Vec::new()];
+    for &i in group.columns.iter() {
+        let values = domain_values(&table.columns[i], references[i].as_deref(), settings)?;
+        combinations = combinations
+            .into_iter()
+            .flat_map(|combination| {
+                values.iter().map(move |value| {
+                    let mut combination = combination.clone();
+                    combination.push(value.clone());
+                    combination
+                })
+            })
+            .collect();
+    }
+
+    combinations.retain(|combination| {
+        !group
+            .used
+            .contains(&combination.iter().map(value_key).collect::<Vec<String>>())
+    });
+    Some(combinations)
+}
+
+// Number of distinct values except NULL the column can get.
+// None if it is unknown or practically unlimited, e.g. for text or UUIDs.
+fn domain_size(
+    column: &Column,
+    reference: Option<&[Value]>,
+    settings: &GenerationSettings,
+) -> Option<u128> {
+    let constraints = column.constraints.clone().unwrap_or_default();
+
+    if let Some(values) = listed_values(column, reference) {
+        return Some(values.len() as u128);
+    }
+    // Semantic generators have their own values, e.g. PastDateTime only generates the last ten years
+    if !constraints.shapes_values()
+        && generator_for_column(column, &settings.name_heuristics)
+            .ok()
+            .flatten()
+            .is_some()
+    {
+        return None;
+    }
+    // A pattern can generate anything
+    if constraints.pattern.is_some() {
+        return None;
+    }
+
+    let size = match &column.column_type {
+        ColumnType::Boolean => 2,
+        ColumnType::Set(values) => 1u128.checked_shl(values.len() as u32)?,
+        ColumnType::Decimal(precision, scale) => {
+            let (min, max) = constraints.decimal_range(*precision, *scale)?;
+            (max - min) as u128 + 1
+        }
+        ColumnType::Date => {
+            let (start, end) = constraints.datetime_range();
+            (end.div_euclid(86_400) - start.div_euclid(86_400)) as u128 + 1
+        }
+        ColumnType::DateTime(_) | ColumnType::Timestamp => {
+            let (start, end) = constraints.datetime_range();
+            (end - start) as u128 + 1
+        }
+        ColumnType::Time => 86_400,
+        column_type => {
+            let (min, max) = constraints.integer_range(integer_bounds(column_type)?)?;
+            (max - min) as u128 + 1
+        }
+    };
+
+    Some(size)
+}
+
+// Every distinct value except NULL the column can get.
+// Only used for columns whose domain_size is small enough to be enumerated.
+fn domain_values(
+    column: &Column,
+    reference: Option<&[Value]>,
+    settings: &GenerationSettings,
+) -> Option<Vec<Value>> {
+    if domain_size(column, reference, settings)? > ENUMERATION_LIMIT {
+        return None;
+    }
+    if let Some(values) = listed_values(column, reference) {
+        return Some(values);
+    }
+
+    let constraints = column.constraints.clone().unwrap_or_default();
+    let values = match &column.column_type {
+        ColumnType::Boolean => vec![Value::Boolean(false), Value::Boolean(true)],
+        // Every combination of the members, keeping the order of the members like generated sets do
+        ColumnType::Set(values) => (0..1usize << values.len())
+            .map(|bits| {
+                Value::Set(
+                    values
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| bits & (1 << i) != 0)
+                        .map(|(_, value)| value.clone())
+                        .collect(),
+                )
+            })
+            .collect(),
+        ColumnType::Decimal(precision, scale) => {
+            let (min, max) = constraints.decimal_range(*precision, *scale)?;
+            (min..=max)
+                .map(|units| Value::Decimal(format_decimal(units, *scale)))
+                .collect()
+        }
+        ColumnType::Date => {
+            let (start, end) = constraints.datetime_range();
+            (start.div_euclid(86_400)..=end.div_euclid(86_400))
+                .filter_map(|day| chrono::DateTime::from_timestamp(day * 86_400, 0))
+                .map(|datetime| Value::Date(datetime.date_naive()))
+                .collect()
+        }
+        ColumnType::Bit(_) | ColumnType::Year => {
+            let (min, max) = constraints.integer_range(integer_bounds(&column.column_type)?)?;
+            (min..=max)
+                .map(|value| match column.column_type {
+                    ColumnType::Bit(_) => Value::Bit(value as u64),
+                    _ => Value::Year(value as u16),
+                })
+                .collect()
+        }
+        column_type => {
+            let (min, max) = constraints.integer_range(integer_bounds(column_type)?)?;
+            (min..=max)
+                .map(|value| super::integer_of_type(column_type, value))
+                .collect::<Option<Vec<Value>>>()?
+        }
+    };
+
+    Some(values)
+}
+
+// Values of columns that can only get the values of a list: foreign keys, columns with fixed values and enums.
+// Duplicates and NULL are removed.
+fn listed_values(column: &Column, reference: Option<&[Value]>) -> Option<Vec<Value>> {
+    let constraints = column.constraints.clone().unwrap_or_default();
+
+    let values: Vec<Value> = if let Some(reference) = reference {
+        reference.to_vec()
+    } else if !constraints.values.is_empty() {
+        constraints.values
+    } else if let ColumnType::Enum(values) = &column.column_type {
+        // Values with a weight of 0 are never generated
+        let weights = enum_weights(values, &constraints.weights);
+        values
+            .iter()
+            .zip(weights)
+            .filter(|(_, weight)| constraints.weights.is_empty() || *weight > 0.0)
+            .map(|(value, _)| Value::Enum(value.clone()))
+            .collect()
+    } else {
+        return None;
+    };
+
+    let mut seen = HashSet::new();
+    Some(
+        values
+            .into_iter()
+            .filter(|value| *value != Value::Null && seen.insert(value_key(value)))
+            .collect(),
+    )
+}
@@ -0,0 +1,647 @@
+// Thoughts about this code
+
+// sqlx has no driver for Microsoft SQL Server since version 0.7.
+// So there is no DbWriter or DbReader yet, only the statements are rendered, e.g. for a .sql export that is run with sqlcmd.
+
+// SQL Server has no unsigned integers except TINYINT, which is unsigned only.
+// Types without an equivalent are widened to the next bigger type and get a CHECK constraint that keeps the values in their original range.
+//     Int8, Year -> SMALLINT
+//     Uint16 -> INT
+//     Uint32 -> BIGINT
+//     Uint64 -> DECIMAL(20, 0)
+// Text is stored as NVARCHAR, so every character of a VARCHAR fits no matter the collation.
+// Enums and sets are NVARCHAR columns with a CHECK constraint that only allows their members.
+// A set value stores its members separated by commas like in MySQL.
+// DateTime is stored as DATETIMEOFFSET, which keeps the point in time but not the name of the timezone.
+// BIT only holds a single bit, so bit columns with more bits are stored as integers.
+// Every spatial type is a GEOMETRY column, a CHECK constraint keeps the values of Point and the others to their subtype.
+
+// SQL Server separates logins on the server from users in a database.
+// A User becomes a login with the same name and a database user for that login.
+// The host of a User is ignored.
+
+use super::{check_datasets, check_finite_floats, hex_digits, join_insert_rows};
+use super::{SqlRenderer, Table, User};
+use crate::db_mocker::error::Result;
+use crate::db_mocker::{parse_bit_literal, Column, ColumnType, UserType, Value};
+
+pub struct MsSQLConnector {}
+
+impl SqlRenderer for MsSQLConnector {
+    // Renders the statement creating a database with the passed name.
+    // The batch is ended with GO, so the next batch can already use the database.
+    fn render_create_database(database_name: &str) -> String {
+        format!("CREATE DATABASE {};\nGO", quote_identifier(database_name))
+    }
+
+    // Renders the statement switching to the database with the passed name.
+    fn render_use_database(database_name: &str) -> String {
+        format!("USE {};\nGO", quote_identifier(database_name))
+    }
+
+    // Renders the statements creating the passed Table.
+    // Indices are created after the table, because an index inside CREATE TABLE would need a name anyway.
+    fn render_create_table(table: &Table) -> Vec<String> {
+        /*
+            Structure of the statements
+            Every word beginnig with !!! is data given by the user
+            Identifiers are quoted with brackets and values are quoted as string literals with quote_identifier() and quote_literal()
+            The other words are fixed
+
+            CREATE TABLE [!!!tbl_name] (
+                [!!!col_name] datatype(!!!size) NOT NULL DEFAULT N'!!!def_value' IDENTITY(1, 1) CHECK (...),
+                ...
+                PRIMARY KEY ([!!!col_name_1], ...),
+                FOREIGN KEY ([!!!col_name]) REFERENCES [!!!foreign_tbl_name]([!!!foreign_col_name]),
+                ...
+                UNIQUE ([!!!col_name]),
+                ...
+            );
+            CREATE INDEX [!!!tbl_name_!!!col_name_1_..._index] ON [!!!tbl_name] ([!!!col_name_1], ...);
+            ...
+        */
+
+        let mut sections = Vec::new();
+
+        // One section per column
+        // [col_name] datatype NOT NULL DEFAULT def_value IDENTITY(1, 1) CHECK (...)
+        for column in table.columns.iter() {
+            let mut section = format!(
+                "{} {}",
+                quote_identifier(&column.column_name),
+                render_column_type(column)
+            );
+
+            if column.not_null {
+                section += " NOT NULL";
+            }
+
+            if let Some(value) = &column.default {
+                section += " DEFAULT ";
+                section += &render_default(&column.column_type, value);
+            }
+
+            if column.auto_increment {
+                section += " IDENTITY(1, 1)";
+            }
+
+            if let Some(check) = render_check(column) {
+                section += &format!(" CHECK ({})", check);
+            }
+
+            sections.push(section);
+        }
+
+        if !table.primary_keys.is_empty() {
+            sections.push(format!(
+                "PRIMARY KEY ({})",
+                quote_identifier_list(&table.primary_keys)
+            ));
+        }
+
+        for fk in table.foreign_keys.iter() {
+            sections.push(format!(
+                "FOREIGN KEY ({}) REFERENCES {}({})",
+                quote_identifier(&fk.fk_column),
+                quote_identifier(&fk.origin_table),
+                quote_identifier(&fk.origin_column)
+            ));
+        }
+
+        for unique in table.uniques.iter() {
+            sections.push(format!("UNIQUE ({})", quote_identifier(unique)));
+        }
+
+        let mut statements = vec![format!(
+            "CREATE TABLE {} ({});",
+            quote_identifier(&table.table_name),
+            sections.join(", ")
+        )];
+
+        // SQL Server needs a name for every index
+        for index in table.indices.iter() {
+            let index_name = format!("{}_{}_index", table.table_name, index.columns.join("_"));
+            statements.push(format!(
+                "CREATE INDEX {} ON {} ({});",
+                quote_identifier(&index_name),
+                quote_identifier(&table.table_name),
+                quote_identifier_list(&index.columns)
+            ));
+        }
+
+        statements
+    }
+
+    // Renders the statements creating a login and a database user for the passed User.
+    // The database has to be the current one, so the user is created inside of it.
+    fn render_create_user(user: &User, _database_name: &str) -> Vec<String> {
+        let name = quote_identifier(&user.user_name);
+
+        let mut statements = vec![
+            format!(
+                "CREATE LOGIN {} WITH PASSWORD = {};",
+                name,
+                quote_literal(&user.password)
+            ),
+            format!("CREATE USER {} FOR LOGIN {};", name, name),
+        ];
+
+        statements.push(match user.user_type {
+            // Admin user becomes an owner of the database
+            UserType::Admin => format!("ALTER ROLE [db_owner] ADD MEMBER {};", name),
+            // Non admin only gets rights to execute CRUD statements
+            UserType::CRUD => format!(
+                "GRANT SELECT, INSERT, UPDATE, DELETE ON SCHEMA::[dbo] TO {};",
+                name
+            ),
+        });
+
+        statements
+    }
+
+    // Renders multi row INSERT statements with all datasets of the passed Table.
+    // The values are part of the statements as literals, so the statements can be executed without binding anything.
+    // SQL Server allows at most 1000 rows per VALUES clause, statements are also kept below 1 MiB.
+    // Identity columns only accept values while IDENTITY_INSERT is turned on for their table.
+    fn render_insert_datasets(table: &Table) -> Result<Vec<String>> {
+        const MAX_STATEMENT_SIZE: usize = 1024 * 1024;
+        const MAX_ROWS: usize = 1000;
+
+        // Every dataset needs exactly one value per column
+        check_datasets(table)?;
+        // T-SQL has no literals for NaN or infinity and FLOAT can't store them
+        check_finite_floats(table, "SQL Server", false)?;
+
+        if table.datasets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Statement start shared by all statements
+        // INSERT INTO [tbl_name] ([col_name_1], ...) VALUES
+        let column_names: Vec<String> = table
+            .columns
+            .iter()
+            .map(|column| column.column_name.clone())
+            .collect();
+        let statement_start = format!(
+            "INSERT INTO {} ({}) VALUES ",
+            quote_identifier(&table.table_name),
+            quote_identifier_list(&column_names)
+        );
+
+        let rows: Vec<String> = table
+            .datasets
+            .iter()
+            .map(|dataset| {
+                let values: Vec<String> = dataset.iter().map(render_value).collect();
+                format!("({})", values.join(", "))
+            })
+            .collect();
+
+        let mut statements = join_insert_rows(&statement_start, rows, MAX_STATEMENT_SIZE, MAX_ROWS);
+
+        if table.columns.iter().any(|column| column.auto_increment) {
+            let table_name = quote_identifier(&table.table_name);
+            statements.insert(0, format!("SET IDENTITY_INSERT {} ON;", table_name));
+            statements.push(format!("SET IDENTITY_INSERT {} OFF;", table_name));
+        }
+
+        Ok(statements)
+    }
+}
+
+// Renders the SQL Server type of a column.
+fn render_column_type(column: &Column) -> String {
+    match &column.column_type {
+        ColumnType::Int8 | ColumnType::Int16 | ColumnType::Year => "SMALLINT".to_string(),
+        ColumnType::Uint8 => "TINYINT".to_string(),
+        ColumnType::Uint16 | ColumnType::Int32 => "INT".to_string(),
+        ColumnType::Uint32 | ColumnType::Int64 => "BIGINT".to_string(),
+        ColumnType::Uint64 => "DECIMAL(20, 0)".to_string(),
+        ColumnType::Float => "REAL".to_string(),
+        ColumnType::Double => "FLOAT".to_string(),
+        ColumnType::Decimal(precision, scale) => format!("DECIMAL({}, {})", precision, scale),
+        ColumnType::Boolean | ColumnType::Bit(1) => "BIT".to_string(),
+        ColumnType::Bit(size) if *size < 64 => "BIGINT".to_string(),
+        ColumnType::Bit(_) => "DECIMAL(20, 0)".to_string(),
+        ColumnType::Char(size) => format!("NCHAR({})", size),
+        ColumnType::Varchar(size) => render_nvarchar(*size as usize),
+        ColumnType::Text | ColumnType::MediumText | ColumnType::LongText | ColumnType::Json => {
+            "NVARCHAR(MAX)".to_string()
+        }
+        ColumnType::Binary(size) => format!("BINARY({})", size),
+        ColumnType::Varbinary(size) => render_varbinary(*size as usize),
+        ColumnType::Blob => "VARBINARY(MAX)".to_string(),
+        ColumnType::Date => "DATE".to_string(),
+        ColumnType::Time => "TIME".to_string(),
+        ColumnType::DateTime(_) => "DATETIMEOFFSET".to_string(),
+        // TIMESTAMP is a row version in SQL Server, DATETIME2 holds the UTC time
+        ColumnType::Timestamp => "DATETIME2".to_string(),
+        ColumnType::Uuid => "UNIQUEIDENTIFIER".to_string(),
+        // Long enough for the longest member
+        ColumnType::Enum(values) => render_nvarchar(
+            values
+                .iter()
+                .map(|value| value.chars().count())
+                .max()
+                .unwrap_or(1),
+        ),
+        // Long enough for all members separated by commas
+        ColumnType::Set(values) => render_nvarchar(
+            values
+                .iter()
+                .map(|value| value.chars().count() + 1)
+                .sum::<usize>()
+                .max(1),
+        ),
+        ColumnType::Geometry | ColumnType::Point | ColumnType::LineString | ColumnType::Polygon => {
+            "GEOMETRY".to_string()
+        }
+    }
+}
+
+// Renders a VARBINARY type of the passed size.
+// VARBINARY allows at most 8000 bytes, bigger sizes need VARBINARY(MAX).
+fn render_varbinary(size: usize) -> String {
+    if size > 8000 {
+        "VARBINARY(MAX)".to_string()
+    } else {
+        format!("VARBINARY({})", size.max(1))
+    }
+}
+
+// Renders an NVARCHAR type of the passed size.
+// NVARCHAR allows at most 4000 characters, bigger sizes need NVARCHAR(MAX).
+fn render_nvarchar(size: usize) -> String {
+    if size > 4000 {
+        "NVARCHAR(MAX)".to_string()
+    } else {
+        format!("NVARCHAR({})", size.max(1))
+    }
+}
+
+// Renders the condition of the CHECK constraint that keeps the values of a column in the range of its ColumnType.
+// Returns None if the SQL Server type has the same range as the ColumnType.
+fn render_check(column: &Column) -> Option<String> {
+    let column_name = quote_identifier(&column.column_name);
+
+    let (min, max) = match &column.column_type {
+        ColumnType::Int8 => (i8::MIN.to_string(), i8::MAX.to_string()),
+        ColumnType::Uint16 => ("0".to_string(), u16::MAX.to_string()),
+        ColumnType::Uint32 => ("0".to_string(), u32::MAX.to_string()),
+        ColumnType::Uint64 => ("0".to_string(), u64::MAX.to_string()),
+        ColumnType::Year => ("1901".to_string(), "2155".to_string()),
+        ColumnType::Bit(size) if *size >= 64 => ("0".to_string(), u64::MAX.to_string()),
+        ColumnType::Bit(size) if *size > 1 => ("0".to_string(), ((1u64 << size) - 1).to_string()),
+        ColumnType::Enum(values) => {
+            return Some(format!(
+                "{} IN ({})",
+                column_name,
+                quote_literal_list(values)
+            ))
+        }
+        ColumnType::Point => return Some(geometry_type_check(&column_name, "Point")),
+        ColumnType::LineString => return Some(geometry_type_check(&column_name, "LineString")),
+        ColumnType::Polygon => return Some(geometry_type_check(&column_name, "Polygon")),
+        // Every member surrounded by commas is removed from the value surrounded by commas
+        // The commas between the members are doubled first, so every member has its own commas
+        // If only members of the set were in the value nothing remains
+        ColumnType::Set(values) => {
+            let mut remaining = format!("N',' + REPLACE({}, N',', N',,') + N','", column_name);
+            for value in values {
+                remaining = format!(
+                    "REPLACE({}, {}, N'')",
+                    remaining,
+                    quote_literal(&format!(",{},", value))
+                );
+            }
+            return Some(format!("{} = N'' OR {} = N''", column_name, remaining));
+        }
+        _ => return None,
+    };
+
+    Some(format!("{} BETWEEN {} AND {}", column_name, min, max))
+}
+
+// Renders the condition that only allows geometries of the passed subtype.
+fn geometry_type_check(column_name: &str, geometry_type: &str) -> String {
+    format!("{}.STGeometryType() = N'{}'", column_name, geometry_type)
+}
+
+// Renders a Value as a SQL literal.
+// Booleans are BIT values, DateTime keeps its offset so DATETIMEOFFSET stores the right point in time.
+// Floats are rendered with an exponent, without one T-SQL reads them as DECIMAL with at most 38 digits.
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::Int8(value) => value.to_string(),
+        Value::Uint8(value) => value.to_string(),
+        Value::Int16(value) => value.to_string(),
+        Value::Uint16(value) => value.to_string(),
+        Value::Int32(value) => value.to_string(),
+        Value::Uint32(value) => value.to_string(),
+        Value::Int64(value) => value.to_string(),
+        Value::Uint64(value) => value.to_string(),
+        Value::Float(value) => format!("{:e}", value),
+        Value::Double(value) => format!("{:e}", value),
+        Value::Decimal(value) => value.clone(),
+        Value::Boolean(value) => (*value as u8).to_string(),
+        Value::Bit(value) => value.to_string(),
+        Value::Char(value) | Value::Varchar(value) | Value::Text(value) => quote_literal(value),
+        Value::Binary(value) => format!("0x{}", hex_digits(value)),
+        Value::Json(value) => quote_literal(&value.to_string()),
+        Value::Date(value) => quote_literal(&value.format("%Y-%m-%d").to_string()),
+        Value::Time(value) => quote_literal(&value.format("%H:%M:%S%.f").to_string()),
+        Value::DateTime(value) => quote_literal(&value.to_rfc3339()),
+        Value::Timestamp(value) => quote_literal(&value.format("%Y-%m-%d %H:%M:%S%.f").to_string()),
+        Value::Year(value) => value.to_string(),
+        Value::Uuid(value) => quote_literal(&value.to_string()),
+        Value::Enum(value) => quote_literal(value),
+        // The members of a set are separated by commas like in MySQL
+        Value::Set(members) => quote_literal(&members.join(",")),
+        Value::Geometry(geometry) => format!(
+            "geometry::STGeomFromText({}, 0)",
+            quote_literal(&geometry.to_wkt())
+        ),
+    }
+}
+
+// Quotes an identifier like a table or column name with brackets.
+// Closing brackets inside the identifier are escaped by doubling them.
+fn quote_identifier(identifier: &str) -> String {
+    format!("[{}]", identifier.replace(']', "]]"))
+}
+
+// Quotes and joins identifiers for a list like the columns of a key.
+fn quote_identifier_list(identifiers: &[String]) -> String {
+    let quoted: Vec<String> = identifiers
+        .iter()
+        .map(|identifier| quote_identifier(identifier))
+        .collect();
+    quoted.join(", ")
+}
+
+// Quotes a value as a unicode string literal.
+// Single quotes are escaped by doubling them, T-SQL has no other escape sequences.
+fn quote_literal(value: &str) -> String {
+    format!("N'{}'", value.replace('\'', "''"))
+}
+
+// Quotes and joins values for a list like the members of an enum.
+fn quote_literal_list(values: &[String]) -> String {
+    let quoted: Vec<String> = values.iter().map(|value| quote_literal(value)).collect();
+    quoted.join(", ")
+}
+
+// Renders the default value of a column for a CREATE TABLE statement.
+// Numbers and NULL are kept as they are.
+// MySQL specific defaults are translated, e.g. TRUE for a boolean becomes 1 and CURDATE() becomes CAST(GETDATE() AS DATE).
+// Everything else is quoted as a string literal.
+fn render_default(column_type: &ColumnType, default: &str) -> String {
+    let default = default.trim();
+    let uppercase = default.to_uppercase();
+
+    if uppercase == "NULL" {
+        return uppercase;
+    }
+
+    match column_type {
+        ColumnType::Int8
+        | ColumnType::Uint8
+        | ColumnType::Int16
+        | ColumnType::Uint16
+        | ColumnType::Int32
+        | ColumnType::Uint32
+        | ColumnType::Int64
+        | ColumnType::Uint64
+        | ColumnType::Float
+        | ColumnType::Double
+        | ColumnType::Decimal(_, _)
+        | ColumnType::Year
+            if default.parse::<f64>().is_ok() =>
+        {
+            return default.to_string()
+        }
+        ColumnType::Boolean => match uppercase.as_str() {
+            "TRUE" | "1" => return "1".to_string(),
+            "FALSE" | "0" => return "0".to_string(),
+            _ => {}
+        },
+        // MySQL bit defaults are numbers or literals like b'101'
+        ColumnType::Bit(_) => {
+            if let Some(value) = parse_bit_literal(default) {
+                return value.to_string();
+            }
+        }
+        ColumnType::Date | ColumnType::Time | ColumnType::DateTime(_) | ColumnType::Timestamp => {
+            match uppercase.split('(').next().unwrap_or_default() {
+                "CURRENT_TIMESTAMP" | "NOW" => return "SYSDATETIMEOFFSET()".to_string(),
+                "CURRENT_DATE" | "CURDATE" => return "CAST(GETDATE() AS DATE)".to_string(),
+                "CURRENT_TIME" | "CURTIME" => return "CAST(GETDATE() AS TIME)".to_string(),
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+
+    let unquoted = default
+        .strip_prefix('\'')
+        .and_then(|default| default.strip_suffix('\''))
+        .map(|default| default.replace("''", "'"))
+        .unwrap_or_else(|| default.to_string());
+    quote_literal(&unquoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db_mocker::error::Error;
+    use crate::db_mocker::geometry::Geometry;
+    use crate::db_mocker::{ForeignKey, Index};
+
+    fn column(column_name: &str, column_type: ColumnType) -> Column {
+        Column {
+            column_name: column_name.to_string(),
+            column_type,
+            default: None,
+            auto_increment: false,
+            not_null: true,
+            generator: None,
+            constraints: None,
+        }
+    }
+
+    fn orders_table() -> Table {
+        let mut id = column("id", ColumnType::Uint32);
+        id.auto_increment = true;
+        let mut status = column(
+            "status",
+            ColumnType::Enum(vec!["new".to_string(), "done".to_string()]),
+        );
+        status.default = Some("new".to_string());
+        let mut express = column("express", ColumnType::Boolean);
+        express.default = Some("TRUE".to_string());
+
+        Table {
+            table_name: "orders".to_string(),
+            columns: vec![
+                id,
+                column("customer_id", ColumnType::Int32),
+                column("code", ColumnType::Varchar(20)),
+                status,
+                express,
+            ],
+            primary_keys: vec!["id".to_string()],
+            foreign_keys: vec![ForeignKey {
+                fk_column: "customer_id".to_string(),
+                origin_table: "customers".to_string(),
+                origin_column: "id".to_string(),
+            }],
+            uniques: vec!["code".to_string()],
+            indices: vec![Index {
+                columns: vec!["customer_id".to_string(), "status".to_string()],
+            }],
+            datasets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn renders_create_table() {
+        assert_eq!(
+            MsSQLConnector::render_create_table(&orders_table()),
+            vec![
+                "CREATE TABLE [orders] (\
+                 [id] BIGINT NOT NULL IDENTITY(1, 1) CHECK ([id] BETWEEN 0 AND 4294967295), \
+                 [customer_id] INT NOT NULL, \
+                 [code] NVARCHAR(20) NOT NULL, \
+                 [status] NVARCHAR(4) NOT NULL DEFAULT N'new' CHECK ([status] IN (N'new', N'done')), \
+                 [express] BIT NOT NULL DEFAULT 1, \
+                 PRIMARY KEY ([id]), \
+                 FOREIGN KEY ([customer_id]) REFERENCES [customers]([id]), \
+                 UNIQUE ([code]));",
+                "CREATE INDEX [orders_customer_id_status_index] ON [orders] ([customer_id], [status]);",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_sets_and_quotes_identifiers() {
+        let tags = column(
+            "tag]s",
+            ColumnType::Set(vec!["gift".to_string(), "it's".to_string()]),
+        );
+
+        assert_eq!(render_column_type(&tags), "NVARCHAR(10)");
+        assert_eq!(
+            render_check(&tags).unwrap(),
+            "[tag]]s] = N'' OR REPLACE(REPLACE(N',' + REPLACE([tag]]s], N',', N',,') + N',', N',gift,', N''), N',it''s,', N'') = N''"
+        );
+        assert_eq!(
+            render_column_type(&column("text", ColumnType::Varchar(5000))),
+            "NVARCHAR(MAX)"
+        );
+    }
+
+    #[test]
+    fn renders_bit_and_spatial_columns() {
+        let flags = column("flags", ColumnType::Bit(4));
+        assert_eq!(render_column_type(&flags), "BIGINT");
+        assert_eq!(render_check(&flags).unwrap(), "[flags] BETWEEN 0 AND 15");
+        assert_eq!(
+            render_column_type(&column("flag", ColumnType::Bit(1))),
+            "BIT"
+        );
+
+        let location = column("location", ColumnType::Point);
+        assert_eq!(render_column_type(&location), "GEOMETRY");
+        assert_eq!(
+            render_check(&location).unwrap(),
+            "[location].STGeometryType() = N'Point'"
+        );
+        assert_eq!(
+            render_value(&Value::Geometry(Geometry::Point(13.4, 52.5))),
+            "geometry::STGeomFromText(N'POINT(13.4 52.5)', 0)"
+        );
+    }
+
+    #[test]
+    fn renders_login_and_user() {
+        let user = User {
+            user_name: "mocker".to_string(),
+            password: "it's secret".to_string(),
+            host: "%".to_string(),
+            user_type: UserType::Admin,
+        };
+
+        assert_eq!(
+            MsSQLConnector::render_create_user(&user, "shop"),
+            vec![
+                "CREATE LOGIN [mocker] WITH PASSWORD = N'it''s secret';",
+                "CREATE USER [mocker] FOR LOGIN [mocker];",
+                "ALTER ROLE [db_owner] ADD MEMBER [mocker];",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_inserts_in_batches_of_1000_rows() {
+        let mut table = orders_table();
+        table.datasets = (0..2500)
+            .map(|i| {
+                vec![
+                    Value::Uint32(i),
+                    Value::Int32(7),
+                    Value::Varchar(format!("A-{}", i)),
+                    Value::Enum("new".to_string()),
+                    Value::Boolean(i % 2 == 0),
+                ]
+            })
+            .collect();
+
+        let statements = MsSQLConnector::render_insert_datasets(&table).unwrap();
+        assert_eq!(statements.len(), 5);
+        assert_eq!(statements[0], "SET IDENTITY_INSERT [orders] ON;");
+        assert!(statements[1].starts_with(
+            "INSERT INTO [orders] ([id], [customer_id], [code], [status], [express]) VALUES \
+             (0, 7, N'A-0', N'new', 1), (1, 7, N'A-1', N'new', 0), "
+        ));
+        assert_eq!(statements[1].matches("), (").count(), 999);
+        assert_eq!(statements[3].matches("), (").count(), 499);
+        assert_eq!(statements[4], "SET IDENTITY_INSERT [orders] OFF;");
+    }
+
+    #[test]
+    fn rejects_decimals_that_arent_numbers() {
+        let mut table = orders_table();
+        table.columns = vec![column("total", ColumnType::Decimal(10, 2))];
+        table.datasets = vec![vec![Value::Decimal("-12.50".to_string())]];
+        assert!(MsSQLConnector::render_insert_datasets(&table).is_ok());
+
+        table.datasets = vec![vec![Value::Decimal("1); DROP TABLE users; --".to_string())]];
+        assert!(matches!(
+            MsSQLConnector::render_insert_datasets(&table),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_floats_that_arent_finite() {
+        let mut table = orders_table();
+        table.columns = vec![column("weight", ColumnType::Float)];
+        for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            table.datasets = vec![vec![Value::Float(value)]];
+            assert!(matches!(
+                MsSQLConnector::render_insert_datasets(&table),
+                Err(Error::Validation(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn renders_floats_with_an_exponent() {
+        assert_eq!(render_value(&Value::Double(1e300)), "1e300");
+        assert_eq!(render_value(&Value::Double(-2.5e-300)), "-2.5e-300");
+        assert_eq!(render_value(&Value::Float(3e38)), "3e38");
+        assert_eq!(render_value(&Value::Float(0.1)), "1e-1");
+    }
+}
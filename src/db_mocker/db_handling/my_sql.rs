@@ -1,172 +1,297 @@
 // Thoughts about this code
 
-// Would it be better to give these Connectors a connection to use
-// rather than having these Connectors establish their own connection?
-// This may require splitting the functionality into create_database() and create_tables()
+// The writer gets a pool from create_database() or connect() and shares it between the statements.
+// The readers still work on a single connection, e.g. one acquired from that pool.
 
 // Have create table call private sub function like add_default() for separating concerns
 
-// For Validation:
-// -----------------------------------
-// Only one auto_increment per table
-// varchar default have to be between single quotes ''
-// A table can have three types of keys:
-//     Pri -> Primary Key
-//     Uni -> Unique Key (Index)
-//     Mul -> Non unique index (Index where multiple values are possible)
+// The rules a VirtualDatabase has to follow for MySQL are checked by validation::my_sql::MySQLValidator
+
+// MySQL has no UUID type, so Uuid columns are created as CHAR(36).
+// Reading the schema can't tell them apart from other CHAR(36) columns and returns Char(36).
+
+// Spatial values are bound as WKB and converted with ST_GeomFromWKB() in the statement.
+// The literals of render_insert_datasets() use WKT and ST_GeomFromText() instead, so the statements stay readable.
 
-use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::{mysql::MySqlConnectOptions, ConnectOptions};
-use sqlx::{Connection, MySqlConnection};
+use chrono_tz::Tz;
+use sqlx::mysql::{MySqlArguments, MySqlConnectOptions, MySqlPoolOptions, MySqlSslMode};
+use sqlx::query::Query;
+use sqlx::{ConnectOptions, Connection, MySql, MySqlConnection, MySqlPool};
+use tracing::warn;
 
-use super::{ConnectionSettings, DbReader, DbWriter};
+use super::{check_datasets, check_finite_floats, hex_digits, join_insert_rows};
+use super::{ConnectionSettings, DbReader, DbWriter, SqlRenderer, TlsMode};
 use super::{Table, User};
+use crate::db_mocker::error::{Error, Result};
+use crate::db_mocker::{parse_bit_literal, Column, ColumnType, ForeignKey, Index, UserType, Value};
 
 pub struct MySQLConnector {}
 
+impl MySQLConnector {
+    // Builds the options to connect to the passed database, or to the DBMS without a database if None is passed.
+    pub fn connect_options(
+        connection_settings: &ConnectionSettings,
+        database_name: Option<&str>,
+    ) -> MySqlConnectOptions {
+        let mut options = MySqlConnectOptions::new()
+            .host(&connection_settings.address)
+            .username(&connection_settings.user_name)
+            .password(&connection_settings.password)
+            .ssl_mode(match connection_settings.tls_mode {
+                TlsMode::Disabled => MySqlSslMode::Disabled,
+                TlsMode::Preferred => MySqlSslMode::Preferred,
+                TlsMode::Required => MySqlSslMode::Required,
+                TlsMode::VerifyCa => MySqlSslMode::VerifyCa,
+                TlsMode::VerifyIdentity => MySqlSslMode::VerifyIdentity,
+            });
+
+        if let Some(port) = connection_settings.port {
+            options = options.port(port);
+        }
+        if let Some(socket) = &connection_settings.socket {
+            options = options.socket(socket);
+        }
+        if let Some(database_name) = database_name {
+            options = options.database(database_name);
+        }
+
+        options
+    }
+
+    async fn connect_pool(
+        connection_settings: &ConnectionSettings,
+        database_name: Option<&str>,
+    ) -> Result<MySqlPool> {
+        MySqlPoolOptions::new()
+            .max_connections(connection_settings.max_connections)
+            .connect_with(Self::connect_options(connection_settings, database_name))
+            .await
+            .map_err(Error::connection(&connection_settings.address))
+    }
+}
+
 #[async_trait]
 impl DbWriter for MySQLConnector {
-    type C = MySqlConnection;
+    type C = MySqlPool;
+
+    // Connects to the database of the ConnectionSettings.
+    async fn connect(connection_settings: &ConnectionSettings) -> Result<Self::C> {
+        Self::connect_pool(connection_settings, connection_settings.database.as_deref()).await
+    }
 
     // Creates a new database from the passed name on the connected DBMS.
-    // Returns a new Connection pointing to the database.
+    // Returns a new pool pointing to the database.
     async fn create_database(
-        connection_settings: &mut ConnectionSettings,
+        connection_settings: &ConnectionSettings,
         database_name: &str,
     ) -> Result<Self::C> {
-        // Create connection to the dbms
-        let mut connection = MySqlConnectOptions::new()
-            .host(&connection_settings.address)
-            .username(&connection_settings.user_name)
-            .password(&connection_settings.password)
+        // The database doesn't exist yet, so the first connection goes to the DBMS without a database
+        let mut connection = Self::connect_options(connection_settings, None)
             .connect()
-            .await?;
+            .await
+            .map_err(Error::connection(&connection_settings.address))?;
 
-        // Bind database name to CREATE DATABASE statement and execute the statement
-        sqlx::query("CREATE DATABASE ?;")
-            .bind(database_name)
+        let sql_statement = Self::render_create_database(database_name);
+        sqlx::query(&sql_statement)
             .execute(&mut connection)
-            .await?;
+            .await
+            .map_err(Error::sql(&sql_statement))?;
 
-        // Close connection and establish a new one to the new database
-        connection.close();
+        connection
+            .close()
+            .await
+            .map_err(Error::connection(&connection_settings.address))?;
 
-        // Update connection address in the ConnectionSettings to point ot the just created database
-        // Create character sequence to append to address
-        // Consisting of '/' and database_name
-        let add_on = "/".to_string() + database_name;
-        connection_settings.address.push_str(&add_on);
+        Self::connect_pool(connection_settings, Some(database_name)).await
+    }
 
-        // Re-establish connection using the updated address of the ConnectionSettings
-        connection = MySqlConnectOptions::new()
-            .host(&connection_settings.address)
-            .username(&connection_settings.user_name)
-            .password(&connection_settings.password)
-            .connect()
-            .await?;
+    // Creates a new table from the passed Table for the connected database.
+    async fn create_table(pool: &Self::C, table: &Table) -> Result<()> {
+        for sql_statement in Self::render_create_table(table) {
+            sqlx::query(&sql_statement)
+                .execute(pool)
+                .await
+                .map_err(Error::sql(&sql_statement))?;
+        }
 
-        // Return the Connection pointing to the new database
-        Ok(connection)
+        Ok(())
     }
 
-    // Creates a new table from the passed Table for the connected database.
-    // Takes ownership of the connection and returns it.
-    // This is done for consistency purposes so that this function is called/used in the same way as the other functions in DbWriter for MySQLConnector.
-    async fn create_table(mut connection: Self::C, table: &Table) -> Result<Self::C> {
+    // Creates a new user from the passed User and grants it privileges on the passed database.
+    async fn create_user(pool: &Self::C, user: &User, database_name: &str) -> Result<()> {
+        // The password isn't part of the error
+        let password = quote_literal(&user.password);
+        for sql_statement in Self::render_create_user(user, database_name) {
+            sqlx::query(&sql_statement)
+                .execute(pool)
+                .await
+                .map_err(Error::sql_with_secret(&sql_statement, &password))?;
+        }
+
+        Ok(())
+    }
+
+    // Inserts all datasets of the passed Table into the table of the connected database.
+    // The datasets are inserted with multi row INSERT statements inside one transaction.
+    // Each statement carries as many datasets as fit into max_allowed_packet of the DBMS.
+    async fn insert_datasets(pool: &Self::C, table: &Table) -> Result<()> {
+        if table.datasets.is_empty() {
+            return Ok(());
+        }
+
+        // Every dataset needs exactly one value per column
+        check_datasets(table)?;
+        // MySQL has no NaN or infinity
+        check_finite_floats(table, "MySQL", false)?;
+
+        // Only use half of the allowed packet size to leave room for the protocol overhead
+        let sql_statement = "SELECT CAST(@@max_allowed_packet AS UNSIGNED);";
+        let max_allowed_packet = sqlx::query_scalar::<_, u64>(sql_statement)
+            .fetch_one(pool)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+        let batches = batch_datasets(
+            &table.datasets,
+            (max_allowed_packet / 2) as usize,
+            table.columns.len(),
+        );
+
+        // Statement start shared by all batches
+        // INSERT INTO tbl_name (col_name_1, ...) VALUES
+        let column_names: Vec<String> = table
+            .columns
+            .iter()
+            .map(|column| quote_identifier(&column.column_name))
+            .collect();
+        let statement_start = format!(
+            "INSERT INTO {} ({}) VALUES ",
+            quote_identifier(&table.table_name),
+            column_names.join(", ")
+        );
+        // Placeholders for a single dataset
+        // (?, ?, ...)
+        let placeholders: Vec<&str> = table
+            .columns
+            .iter()
+            .map(|column| placeholder(&column.column_type))
+            .collect();
+        let row_placeholders = format!("({})", placeholders.join(", "));
+
+        let mut transaction = pool.begin().await.map_err(Error::sql("BEGIN"))?;
+        for batch in batches {
+            let datasets = &table.datasets[batch];
+
+            let sql_statement = statement_start.clone()
+                + &vec![row_placeholders.as_str(); datasets.len()].join(", ");
+
+            let mut query = sqlx::query(&sql_statement);
+            for value in datasets.iter().flatten() {
+                query = bind_value(query, value);
+            }
+
+            query
+                .execute(&mut *transaction)
+                .await
+                .map_err(Error::sql(&sql_statement))?;
+        }
+        transaction.commit().await.map_err(Error::sql("COMMIT"))?;
+
+        Ok(())
+    }
+}
+
+impl SqlRenderer for MySQLConnector {
+    // Renders the statement creating a database with the passed name.
+    fn render_create_database(database_name: &str) -> String {
+        // Identifiers can't be bound to placeholders, so the quoted database name is part of the statement
+        format!("CREATE DATABASE {};", quote_identifier(database_name))
+    }
+
+    // Renders the statement switching to the database with the passed name.
+    fn render_use_database(database_name: &str) -> String {
+        format!("USE {};", quote_identifier(database_name))
+    }
+
+    // Renders the CREATE TABLE statement for the passed Table.
+    fn render_create_table(table: &Table) -> Vec<String> {
         /*
             Structure of CREATE TABLE statement
-            Every word beginnig with !!! is data given by the user and needs to be bound to a placeholder for security
+            Every word beginnig with !!! is data given by the user
+            MySQL doesn't allow placeholders for identifiers and type arguments
+            So identifiers are quoted with backticks and values are quoted as string literals with quote_identifier() and quote_literal()
             The other words are fixed
 
-            CREATE TABLE !!!tbl_name (
-                !!!col_name datatype(!!!size) NOT NULL DEFAULT !!!def_value AUTO_INCREMENT,
+            CREATE TABLE `!!!tbl_name` (
+                `!!!col_name` datatype(!!!size) NOT NULL DEFAULT '!!!def_value' AUTO_INCREMENT,
                 ...
-                PRIMARY KEY (!!!col_name_1, ...),
-                FOREIGN KEY (!!!col_name) REFERENCES !!!foreign_tbl_name(!!!foreign_col_name),
+                PRIMARY KEY (`!!!col_name_1`, ...),
+                FOREIGN KEY (`!!!col_name`) REFERENCES `!!!foreign_tbl_name`(`!!!foreign_col_name`),
                 ...
-                UNIQUE (!!!col_name),
+                UNIQUE (`!!!col_name`),
                 ...
-                INDEX (!!!col_name_1, ...)
+                INDEX (`!!!col_name_1`, ...)
                 ...
             );
         */
 
-        // Vec to store the information to be bound to the CREATE TABLE statement
-        let mut sql_data: Vec<&str> = Vec::new();
-
         // String to store the statement for creating the table
-        let mut sql_statement = String::from("CREATE TABLE ? (");
-
-        // Add table name to data
-        sql_data.push(&table.table_name);
+        let mut sql_statement = format!("CREATE TABLE {} (", quote_identifier(&table.table_name));
 
         // Add each column's details to sql statement
         for (i, column) in table.columns.iter().enumerate() {
             // For each column add this section
-            // col_name datatype(size) NOT NULL DEFAULT def_value AUTO_INCREMENT,
+            // `col_name` datatype(size) NOT NULL DEFAULT def_value AUTO_INCREMENT,
 
-            // Add placholders and data to sql statement and data to be bound
             // Add column name
-            sql_statement += "? ";
-            sql_data.push(&column.column_name);
+            sql_statement += &quote_identifier(&column.column_name);
+            sql_statement += " ";
 
-            // Add datatype and the size if varchar
-            // For varchar add it's size to data
-            // For enum and set also add their possible values to the statement and the data
+            // Add column type and the size if varchar
+            // For enum and set also add their possible values
             // Don't add space add the end of this section because it could be the last section
-            match &column.datatype {
-                crate::db_mocker::DataType::Int8(_) => sql_statement += "TINYINT",
-                crate::db_mocker::DataType::Uint8(_) => sql_statement += "TINYINT UNSIGNED",
-                crate::db_mocker::DataType::Int16(_) => sql_statement += "SMALLINT",
-                crate::db_mocker::DataType::Uint16(_) => sql_statement += "SMALLINT UNSIGNED",
-                crate::db_mocker::DataType::Int32(_) => sql_statement += "INT",
-                crate::db_mocker::DataType::Uint32(_) => sql_statement += "INT UNSIGNED",
-                crate::db_mocker::DataType::Int64(_) => sql_statement += "BIGINT",
-                crate::db_mocker::DataType::Uint64(_) => sql_statement += "BIGINT UNSIGNED",
-                crate::db_mocker::DataType::Float(_) => sql_statement += "FLOAT",
-                crate::db_mocker::DataType::Double(_) => sql_statement += "DOUBLE",
-                crate::db_mocker::DataType::Boolean(_) => sql_statement += "BOOLEAN",
-                crate::db_mocker::DataType::Varchar(size, _) => {
-                    sql_statement += "VARCHAR (?)";
-                    sql_data.push(&size);
+            match &column.column_type {
+                ColumnType::Int8 => sql_statement += "TINYINT",
+                ColumnType::Uint8 => sql_statement += "TINYINT UNSIGNED",
+                ColumnType::Int16 => sql_statement += "SMALLINT",
+                ColumnType::Uint16 => sql_statement += "SMALLINT UNSIGNED",
+                ColumnType::Int32 => sql_statement += "INT",
+                ColumnType::Uint32 => sql_statement += "INT UNSIGNED",
+                ColumnType::Int64 => sql_statement += "BIGINT",
+                ColumnType::Uint64 => sql_statement += "BIGINT UNSIGNED",
+                ColumnType::Float => sql_statement += "FLOAT",
+                ColumnType::Double => sql_statement += "DOUBLE",
+                ColumnType::Decimal(precision, scale) => {
+                    sql_statement += &format!("DECIMAL({}, {})", precision, scale)
                 }
-                crate::db_mocker::DataType::Date(_) => sql_statement += "DATE",
-                crate::db_mocker::DataType::Time(_) => sql_statement += "TIME",
-                crate::db_mocker::DataType::DateTime(_) => sql_statement += "DATETIME",
-                crate::db_mocker::DataType::Year(_) => sql_statement += "YEAR",
-                crate::db_mocker::DataType::Enum(values) => {
-                    // Start value list
-                    sql_statement += "(";
-                    // Add each value to sql statement and data
-                    for (i, value) in values.iter().enumerate() {
-                        sql_statement += "'?'";
-                        sql_data.push(value);
-
-                        // For each value that isn't the last add a comma and space as a separator to the next value
-                        if i + 1 != values.len() {
-                            sql_statement += ", ";
-                        }
-                    }
-                    // End value list
-                    sql_statement += ")";
+                ColumnType::Boolean => sql_statement += "BOOLEAN",
+                ColumnType::Bit(size) => sql_statement += &format!("BIT({})", size),
+                ColumnType::Char(size) => sql_statement += &format!("CHAR({})", size),
+                ColumnType::Varchar(size) => sql_statement += &format!("VARCHAR({})", size),
+                ColumnType::Text => sql_statement += "TEXT",
+                ColumnType::MediumText => sql_statement += "MEDIUMTEXT",
+                ColumnType::LongText => sql_statement += "LONGTEXT",
+                ColumnType::Binary(size) => sql_statement += &format!("BINARY({})", size),
+                ColumnType::Varbinary(size) => sql_statement += &format!("VARBINARY({})", size),
+                ColumnType::Blob => sql_statement += "BLOB",
+                ColumnType::Json => sql_statement += "JSON",
+                ColumnType::Date => sql_statement += "DATE",
+                ColumnType::Time => sql_statement += "TIME",
+                ColumnType::DateTime(_) => sql_statement += "DATETIME",
+                ColumnType::Timestamp => sql_statement += "TIMESTAMP",
+                ColumnType::Year => sql_statement += "YEAR",
+                // MySQL has no UUID type, the text form of a UUID has 36 characters
+                ColumnType::Uuid => sql_statement += "CHAR(36)",
+                ColumnType::Enum(values) => {
+                    sql_statement += &format!("ENUM({})", quote_literal_list(values))
                 }
-                crate::db_mocker::DataType::Set(values) => {
-                    // Start value list
-                    sql_statement += "(";
-                    // Add each value to sql statement and data
-                    for (i, value) in values.iter().enumerate() {
-                        sql_statement += "'?'";
-                        sql_data.push(value);
-
-                        // For each value that isn't the last add a comma and space as a separator to the next value
-                        if i + 1 != values.len() {
-                            sql_statement += ", ";
-                        }
-                    }
-                    // End value list
-                    sql_statement += ")";
+                ColumnType::Set(values) => {
+                    sql_statement += &format!("SET({})", quote_literal_list(values))
                 }
+                ColumnType::Geometry => sql_statement += "GEOMETRY",
+                ColumnType::Point => sql_statement += "POINT",
+                ColumnType::LineString => sql_statement += "LINESTRING",
+                ColumnType::Polygon => sql_statement += "POLYGON",
             }
 
             // Add NOT NULL if needed
@@ -175,12 +300,9 @@ impl DbWriter for MySQLConnector {
             }
 
             // Add DEFAULT and the default value if needed
-            match &column.default {
-                Some(value) => {
-                    sql_statement += " DEFAULT ?";
-                    sql_data.push(&value);
-                }
-                None => {}
+            if let Some(value) = &column.default {
+                sql_statement += " DEFAULT ";
+                sql_statement += &render_default(&column.column_type, value);
             }
 
             // Add AUTO_INCREMENT if needed
@@ -198,156 +320,1101 @@ impl DbWriter for MySQLConnector {
         // Check if there is a primary key before starting to generate the primary key section
         // In a proper database each table should have a primary key
         // Eventually a missing priamry key should create a warning but for now nothing will be done
-        if table.primary_keys.len() > 0 {
-            // Start PRIMARY KEY section of sql statement
-            sql_statement += ", PRIMARY KEY (";
-            // Add primary key details to sql statement
-            for (i, col_name) in table.primary_keys.iter().enumerate() {
-                // Add placeholder to sql statement and column name to data
-                sql_statement += "?";
-                sql_data.push(&col_name);
-
-                // If this column is not the last column of the primary key then add a comma and space to separate the next column
-                // This is done so that there is no extra trailing comma if the CREATE TABLE statement ends after the primary key section
-                if i + 1 != table.primary_keys.len() {
-                    sql_statement += ", ";
-                }
-            }
-            // End PRIMARY KEY section
-            sql_statement += ")";
+        if !table.primary_keys.is_empty() {
+            sql_statement += &format!(
+                ", PRIMARY KEY ({})",
+                quote_identifier_list(&table.primary_keys)
+            );
         }
 
         // Add foreign key details to sql statement
         // For each foreign key one FOREIGN KEY section is created
         // Eventually this should create errors if a non existing primary key gets referenced but for now nothing will be done
         for fk in table.foreign_keys.iter() {
-            // Add FOREIGN KEY section to statement
-            sql_statement += ", FOREIGN KEY (?) REFERENCES ?(?)";
-
-            // Add data to be bound
-            // Add column name of column in this table that has the reference to the foreign primary key
-            sql_data.push(&fk.fk_column);
-            // Add name of foreign table where the referenced primary key lies
-            sql_data.push(&fk.origin_table);
-            // Add column name of referenced primary key
-            sql_data.push(&fk.origin_column);
+            sql_statement += &format!(
+                ", FOREIGN KEY ({}) REFERENCES {}({})",
+                quote_identifier(&fk.fk_column),
+                quote_identifier(&fk.origin_table),
+                quote_identifier(&fk.origin_column)
+            );
         }
 
         // Add indices to sql statement
         // For each index one INDEX section is created
         // Eventually this should create warnings but for now nothing will be done
         for index in table.indices.iter() {
-            // Start INDEX section
-            sql_statement += ", INDEX (";
-            // Add columns data of this index to sql statement and data
-            for (i, column) in index.columns.iter().enumerate() {
-                // Add placeholder to sql statement and column name to data
-                sql_statement += "?";
-                sql_data.push(&column);
-
-                // If this column is not the last column of the index then add a comma and space to separate the next column
-                // This is done so that there is no extra trailing comma if the INDEX section ends after this column
-                if i + 1 != index.columns.len() {
-                    sql_statement += ", ";
-                }
-            }
-            // End INDEX section
-            sql_statement += ")";
+            sql_statement += &format!(", INDEX ({})", quote_identifier_list(&index.columns));
         }
 
         // Add unique constraints to sql statement
         // For each unique constraint one UNIQUE section is created
         // Eventually a unique constraint on a non existing column should create an error but for now nothing will be done
         for unique in table.uniques.iter() {
-            // Add placeholder to sql statement and column name to data
-            sql_statement += ", UNIQUE (?)";
-            sql_data.push(&unique);
+            sql_statement += &format!(", UNIQUE ({})", quote_identifier(unique));
         }
 
         // End CREATE TABLE statement
         sql_statement += ");";
 
-        // Statement execution
-        // First create QUery
-        let mut query = sqlx::query(&sql_statement);
-
-        // Then add all binds by iterating over them
-        // Because query doesn't implement copy query needs to be assinged teh result of binding to it
-        for element in sql_data.iter() {
-            query = query.bind(element);
-        }
-
-        // Finally execute the query
-        query.execute(&mut connection).await?;
-
-        Ok(connection)
+        vec![sql_statement]
     }
 
-    // Takes a connection and a User to create a new user from the passed User using the connection.
-    // Takes ownership of the connection and returns it.
-    // This is done because multiple queries have to be executed on this connection.
-    async fn create_user(
-        mut connection: Self::C,
-        user: &User,
-        database_name: &str,
-    ) -> Result<Self::C> {
+    // Renders the statements creating the passed User and granting it privileges on the database.
+    fn render_create_user(user: &User, database_name: &str) -> Vec<String> {
+        // Account names and the password are string literals, the database name is an identifier
+        // Neither can be bound to placeholders in these statements
+        let account = format!(
+            "{}@{}",
+            quote_literal(&user.user_name),
+            quote_literal(&user.host)
+        );
+        let database_name = quote_identifier(database_name);
+
         // Create user from User struct
-        sqlx::query("CREATE USER '?'@'?' IDENTIFIED BY '?';")
-            .bind(&user.user_name)
-            .bind(&user.host)
-            .bind(&user.password)
-            .execute(&mut connection)
-            .await?;
+        let create_statement = format!(
+            "CREATE USER {} IDENTIFIED BY {};",
+            account,
+            quote_literal(&user.password)
+        );
 
         // Grant user rigths depending if it is an admin or not
-        match user.user_type {
+        let grant_statement = match user.user_type {
             // Admin user gets all privileges
-            crate::db_mocker::UserType::Admin => {
-                sqlx::query("GRANT ALL ON ?.* TO '?'@'?' WITH GRANT OPTION;")
-                    .bind(database_name)
-                    .bind(&user.user_name)
-                    .bind(&user.host)
-                    .execute(&mut connection)
-                    .await?;
-            }
+            UserType::Admin => format!(
+                "GRANT ALL ON {}.* TO {} WITH GRANT OPTION;",
+                database_name, account
+            ),
             // Non admin only gets rights to execute CRUD statements
-            crate::db_mocker::UserType::CRUD => {
-                sqlx::query("GRANT INSERT, SELECT, UPDATE, DELETE ON ?.* TO '?'@'?';")
-                    .bind(database_name)
-                    .bind(&user.user_name)
-                    .bind(&user.host)
-                    .execute(&mut connection)
-                    .await?;
-            }
+            UserType::CRUD => format!(
+                "GRANT INSERT, SELECT, UPDATE, DELETE ON {}.* TO {};",
+                database_name, account
+            ),
+        };
+
+        vec![create_statement, grant_statement]
+    }
+
+    // Renders multi row INSERT statements with all datasets of the passed Table.
+    // The values are part of the statements as literals, so the statements can be executed without binding anything.
+    // Each statement is kept below 1 MiB so it fits into the default max_allowed_packet of MySQL.
+    fn render_insert_datasets(table: &Table) -> Result<Vec<String>> {
+        const MAX_STATEMENT_SIZE: usize = 1024 * 1024;
+
+        // Statement start shared by all statements
+        // INSERT INTO `tbl_name` (`col_name_1`, ...) VALUES
+        let column_names: Vec<String> = table
+            .columns
+            .iter()
+            .map(|column| column.column_name.clone())
+            .collect();
+        let statement_start = format!(
+            "INSERT INTO {} ({}) VALUES ",
+            quote_identifier(&table.table_name),
+            quote_identifier_list(&column_names)
+        );
+
+        check_datasets(table)?;
+        check_finite_floats(table, "MySQL", false)?;
+
+        let rows: Vec<String> = table
+            .datasets
+            .iter()
+            .map(|dataset| {
+                let values: Vec<String> = dataset.iter().map(render_value).collect();
+                format!("({})", values.join(", "))
+            })
+            .collect();
+
+        Ok(join_insert_rows(
+            &statement_start,
+            rows,
+            MAX_STATEMENT_SIZE,
+            usize::MAX,
+        ))
+    }
+}
+
+// Splits the datasets into ranges so that the values of each range fit into one statement.
+// A range is ended when its values would exceed the byte budget or the placeholder limit of MySQL.
+// A single dataset that is bigger than the budget still gets its own range.
+fn batch_datasets(
+    datasets: &[Vec<Value>],
+    byte_budget: usize,
+    column_count: usize,
+) -> Vec<std::ops::Range<usize>> {
+    // MySQL allows at most 65535 placeholders in a prepared statement
+    let max_rows = (u16::MAX as usize / column_count.max(1)).max(1);
+
+    let mut batches = Vec::new();
+    let mut start = 0;
+    let mut batch_size = 0;
+    for (i, dataset) in datasets.iter().enumerate() {
+        // Placeholders and separators of the statement text plus the bound values
+        let dataset_size = column_count * 3 + dataset.iter().map(encoded_size).sum::<usize>();
+
+        if i > start && (batch_size + dataset_size > byte_budget || i - start >= max_rows) {
+            batches.push(start..i);
+            start = i;
+            batch_size = 0;
         }
+        batch_size += dataset_size;
+    }
+    if start < datasets.len() {
+        batches.push(start..datasets.len());
+    }
 
-        Ok(connection)
+    batches
+}
+
+// Estimated number of bytes a value takes when bound to a statement.
+// Includes the type information and length prefix that are sent with the value.
+fn encoded_size(value: &Value) -> usize {
+    let size = match value {
+        Value::Null => 0,
+        Value::Int8(_) | Value::Uint8(_) | Value::Boolean(_) => 1,
+        Value::Int16(_) | Value::Uint16(_) | Value::Year(_) => 2,
+        Value::Int32(_) | Value::Uint32(_) | Value::Float(_) => 4,
+        Value::Int64(_) | Value::Uint64(_) | Value::Double(_) | Value::Bit(_) => 8,
+        Value::Date(_) => 5,
+        Value::Time(_) => 13,
+        Value::DateTime(_) | Value::Timestamp(_) => 12,
+        Value::Decimal(value)
+        | Value::Char(value)
+        | Value::Varchar(value)
+        | Value::Text(value)
+        | Value::Enum(value) => value.len() + 9,
+        Value::Binary(value) => value.len() + 9,
+        Value::Json(value) => value.to_string().len() + 9,
+        Value::Uuid(_) => 36 + 9,
+        Value::Set(members) => members.iter().map(|member| member.len() + 1).sum::<usize>() + 9,
+        Value::Geometry(geometry) => geometry.to_wkb().len() + 9,
+    };
+
+    size + 2
+}
+
+// Binds the Value to the next placeholder of the query.
+// DateTime is stored as the wall clock time of its timezone, because DATETIME has no timezone.
+// Timestamp is bound in UTC, sqlx sets the timezone of the session to UTC.
+fn bind_value<'q>(
+    query: Query<'q, MySql, MySqlArguments>,
+    value: &'q Value,
+) -> Query<'q, MySql, MySqlArguments> {
+    match value {
+        Value::Null => query.bind(None::<String>),
+        Value::Int8(value) => query.bind(value),
+        Value::Uint8(value) => query.bind(value),
+        Value::Int16(value) => query.bind(value),
+        Value::Uint16(value) => query.bind(value),
+        Value::Int32(value) => query.bind(value),
+        Value::Uint32(value) => query.bind(value),
+        Value::Int64(value) => query.bind(value),
+        Value::Uint64(value) => query.bind(value),
+        Value::Float(value) => query.bind(value),
+        Value::Double(value) => query.bind(value),
+        // Decimals are sent as text, MySQL converts them without losing digits
+        Value::Decimal(value) => query.bind(value),
+        Value::Boolean(value) => query.bind(value),
+        Value::Bit(value) => query.bind(value),
+        Value::Char(value) | Value::Varchar(value) | Value::Text(value) | Value::Enum(value) => {
+            query.bind(value)
+        }
+        Value::Binary(value) => query.bind(value),
+        Value::Json(value) => query.bind(value.to_string()),
+        Value::Date(value) => query.bind(value),
+        Value::Time(value) => query.bind(value),
+        Value::DateTime(value) => query.bind(value.naive_local()),
+        Value::Timestamp(value) => query.bind(value),
+        Value::Year(value) => query.bind(value),
+        Value::Uuid(value) => query.bind(value.to_string()),
+        // MySQL expects the members of a set separated by commas
+        Value::Set(members) => query.bind(members.join(",")),
+        // The placeholder of a spatial column converts the WKB with ST_GeomFromWKB()
+        Value::Geometry(geometry) => query.bind(geometry.to_wkb()),
+    }
+}
+
+// Renders the Value as a SQL literal.
+// DateTime is rendered as the wall clock time of its timezone, because DATETIME has no timezone.
+// Timestamp is rendered as the wall clock time of UTC without an offset, offsets in literals need MySQL 8.0.19.
+// So the statements expect a session in UTC, like the sessions of sqlx.
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::Int8(value) => value.to_string(),
+        Value::Uint8(value) => value.to_string(),
+        Value::Int16(value) => value.to_string(),
+        Value::Uint16(value) => value.to_string(),
+        Value::Int32(value) => value.to_string(),
+        Value::Uint32(value) => value.to_string(),
+        Value::Int64(value) => value.to_string(),
+        Value::Uint64(value) => value.to_string(),
+        Value::Float(value) => value.to_string(),
+        Value::Double(value) => value.to_string(),
+        Value::Decimal(value) => value.clone(),
+        Value::Boolean(value) => value.to_string().to_uppercase(),
+        Value::Bit(value) => format!("b'{:b}'", value),
+        Value::Char(value) | Value::Varchar(value) | Value::Text(value) | Value::Enum(value) => {
+            quote_literal(value)
+        }
+        Value::Binary(value) => format!("X'{}'", hex_digits(value)),
+        Value::Json(value) => quote_literal(&value.to_string()),
+        Value::Date(value) => quote_literal(&value.format("%Y-%m-%d").to_string()),
+        Value::Time(value) => quote_literal(&value.format("%H:%M:%S%.f").to_string()),
+        Value::DateTime(value) => quote_literal(
+            &value
+                .naive_local()
+                .format("%Y-%m-%d %H:%M:%S%.f")
+                .to_string(),
+        ),
+        Value::Timestamp(value) => quote_literal(&value.format("%Y-%m-%d %H:%M:%S%.f").to_string()),
+        Value::Year(value) => value.to_string(),
+        Value::Uuid(value) => quote_literal(&value.to_string()),
+        // MySQL expects the members of a set separated by commas
+        Value::Set(members) => quote_literal(&members.join(",")),
+        Value::Geometry(geometry) => {
+            format!("ST_GeomFromText({})", quote_literal(&geometry.to_wkt()))
+        }
+    }
+}
+
+// Returns the placeholder for a value of the column type in an INSERT statement.
+fn placeholder(column_type: &ColumnType) -> &'static str {
+    match column_type {
+        ColumnType::Geometry | ColumnType::Point | ColumnType::LineString | ColumnType::Polygon => {
+            "ST_GeomFromWKB(?)"
+        }
+        _ => "?",
+    }
+}
+
+// Quotes an identifier like a table or column name with backticks.
+// Backticks inside the identifier are escaped by doubling them.
+fn quote_identifier(identifier: &str) -> String {
+    format!("`{}`", identifier.replace('`', "``"))
+}
+
+// Quotes and joins identifiers for a list like the columns of a key.
+fn quote_identifier_list(identifiers: &[String]) -> String {
+    let quoted: Vec<String> = identifiers
+        .iter()
+        .map(|identifier| quote_identifier(identifier))
+        .collect();
+    quoted.join(", ")
+}
+
+// Quotes a value as a string literal with single quotes.
+// Backslashes and single quotes are escaped, so the value can't end the literal.
+// Control characters that would break the statement are escaped as well.
+fn quote_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('\'');
+    for c in value.chars() {
+        match c {
+            '\'' => literal.push_str("''"),
+            '\\' => literal.push_str("\\\\"),
+            '\0' => literal.push_str("\\0"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\x1a' => literal.push_str("\\Z"),
+            _ => literal.push(c),
+        }
     }
+    literal.push('\'');
+    literal
+}
+
+// Quotes and joins values for a list like the members of an enum.
+fn quote_literal_list(values: &[String]) -> String {
+    let quoted: Vec<String> = values.iter().map(|value| quote_literal(value)).collect();
+    quoted.join(", ")
+}
+
+// Renders the default value of a column for a CREATE TABLE statement.
+// Numbers, NULL and datetime functions like CURRENT_TIMESTAMP are kept as they are.
+// Everything else is quoted as a string literal.
+// A default that is already surrounded by single quotes is unquoted first so it doesn't get quoted twice.
+fn render_default(column_type: &ColumnType, default: &str) -> String {
+    let default = default.trim();
+    let uppercase = default.to_uppercase();
+
+    if uppercase == "NULL" {
+        return uppercase;
+    }
+
+    let keep_as_is = match column_type {
+        ColumnType::Int8
+        | ColumnType::Uint8
+        | ColumnType::Int16
+        | ColumnType::Uint16
+        | ColumnType::Int32
+        | ColumnType::Uint32
+        | ColumnType::Int64
+        | ColumnType::Uint64
+        | ColumnType::Float
+        | ColumnType::Double
+        | ColumnType::Decimal(_, _)
+        | ColumnType::Year => default.parse::<f64>().is_ok(),
+        ColumnType::Boolean => matches!(uppercase.as_str(), "TRUE" | "FALSE" | "0" | "1"),
+        // Bit values are numbers or bit literals like b'101'
+        ColumnType::Bit(_) => parse_bit_literal(default).is_some(),
+        ColumnType::Date | ColumnType::Time | ColumnType::DateTime(_) | ColumnType::Timestamp => {
+            let function = uppercase.split('(').next().unwrap_or_default();
+            matches!(
+                function,
+                "CURRENT_TIMESTAMP"
+                    | "NOW"
+                    | "CURRENT_DATE"
+                    | "CURDATE"
+                    | "CURRENT_TIME"
+                    | "CURTIME"
+            )
+        }
+        _ => false,
+    };
+
+    if keep_as_is {
+        return default.to_string();
+    }
+
+    let unquoted = default
+        .strip_prefix('\'')
+        .and_then(|default| default.strip_suffix('\''))
+        .map(|default| default.replace("''", "'"))
+        .unwrap_or_else(|| default.to_string());
+    quote_literal(&unquoted)
 }
 
 #[async_trait]
 impl DbReader for MySQLConnector {
-    async fn get_databases<MySqlConnection>(
-        connection_settings: &mut MySqlConnection,
-    ) -> Result<()> {
-        todo!()
+    type C = MySqlConnection;
+
+    // Returns the names of all databases on the connected DBMS.
+    // The system databases of MySQL are left out because they aren't something to mock.
+    async fn get_databases(connection: &mut Self::C) -> Result<Vec<String>> {
+        // information_schema returns some columns as binary strings depending on the MySQL version
+        // Casting them to CHAR makes sure they can always be decoded as String
+        let sql_statement = "SELECT CAST(SCHEMA_NAME AS CHAR) FROM information_schema.SCHEMATA \
+             WHERE SCHEMA_NAME NOT IN ('information_schema', 'mysql', 'performance_schema', 'sys') \
+             ORDER BY SCHEMA_NAME;";
+        let databases = sqlx::query_scalar::<_, String>(sql_statement)
+            .fetch_all(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        Ok(databases)
+    }
+
+    // Returns the name of the database the connection points to.
+    async fn get_current_database(connection: &mut Self::C) -> Result<String> {
+        // DATABASE() is NULL if the connection doesn't point to a database
+        let sql_statement = "SELECT DATABASE();";
+        let database_name = sqlx::query_scalar::<_, Option<String>>(sql_statement)
+            .fetch_one(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        database_name
+            .ok_or_else(|| Error::NotFound("Connection doesn't point to a database".to_string()))
     }
 
-    async fn get_database_schema<MySqlConnection>(
-        connection_settings: &mut MySqlConnection,
+    // Returns every table of the passed database with its columns, keys and indices.
+    // The database doesn't need to be the one the connection points to.
+    async fn get_database_schema(
+        connection: &mut Self::C,
         database_name: &str,
-    ) -> Result<()> {
-        todo!()
+    ) -> Result<Vec<Table>> {
+        let mut tables = Vec::new();
+
+        for table_name in read_table_names(connection, Some(database_name)).await? {
+            tables.push(read_table(connection, Some(database_name), &table_name).await?);
+        }
+
+        Ok(tables)
+    }
+
+    // Returns the names of all tables of the database the connection points to.
+    async fn get_tables(connection: &mut Self::C) -> Result<Vec<String>> {
+        read_table_names(connection, None).await
+    }
+
+    // Returns the passed table of the database the connection points to.
+    async fn get_table_schema(connection: &mut Self::C, table_name: &str) -> Result<Table> {
+        read_table(connection, None, table_name).await
+    }
+
+    // Returns all users that have privileges on the passed database.
+    // A user with privileges beyond CRUD statements or who is allowed to grant privileges is read as an admin.
+    // Passwords are only stored as hashes by MySQL, so the returned users have empty passwords.
+    async fn get_users(connection: &mut Self::C, database_name: &str) -> Result<Vec<User>> {
+        // Row layout: grantee, privilege type, is grantable
+        // The grantee has the format 'user_name'@'host'
+        let sql_statement = "SELECT CAST(GRANTEE AS CHAR), CAST(PRIVILEGE_TYPE AS CHAR), CAST(IS_GRANTABLE AS CHAR) \
+             FROM information_schema.SCHEMA_PRIVILEGES \
+             WHERE TABLE_SCHEMA = ? \
+             ORDER BY GRANTEE;";
+        let privilege_rows = sqlx::query_as::<_, (String, String, String)>(sql_statement)
+            .bind(database_name)
+            .fetch_all(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        let mut users: Vec<User> = Vec::new();
+        for (grantee, privilege, is_grantable) in privilege_rows {
+            let (user_name, host) = parse_grantee(&grantee)?;
+            let admin = is_grantable == "YES"
+                || !matches!(
+                    privilege.as_str(),
+                    "SELECT" | "INSERT" | "UPDATE" | "DELETE"
+                );
+
+            // Rows of the same user follow each other because they are ordered by grantee
+            match users.last_mut() {
+                Some(user) if user.user_name == user_name && user.host == host => {
+                    if admin {
+                        user.user_type = UserType::Admin;
+                    }
+                }
+                _ => users.push(User {
+                    user_name,
+                    password: String::new(),
+                    host,
+                    user_type: if admin {
+                        UserType::Admin
+                    } else {
+                        UserType::CRUD
+                    },
+                }),
+            }
+        }
+
+        Ok(users)
+    }
+}
+
+// Splits a grantee like 'user_name'@'host' into user name and host.
+fn parse_grantee(grantee: &str) -> Result<(String, String)> {
+    let (user_name, host) = grantee
+        .split_once("'@'")
+        .ok_or_else(|| Error::Parse(format!("Invalid grantee '{}'", grantee)))?;
+
+    // Remove the outer quotes and unescape quotes inside the names
+    let user_name = user_name.strip_prefix('\'').unwrap_or(user_name);
+    let host = host.strip_suffix('\'').unwrap_or(host);
+
+    Ok((user_name.replace("''", "'"), host.replace("''", "'")))
+}
+
+// Reads the names of all base tables (no views) of a database.
+// If no database name is passed the database the connection points to is used.
+async fn read_table_names(
+    connection: &mut MySqlConnection,
+    database_name: Option<&str>,
+) -> Result<Vec<String>> {
+    // COALESCE falls back to the current database when NULL is bound for the database name
+    let sql_statement = "SELECT CAST(TABLE_NAME AS CHAR) FROM information_schema.TABLES \
+         WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_TYPE = 'BASE TABLE' \
+         ORDER BY TABLE_NAME;";
+    let table_names = sqlx::query_scalar::<_, String>(sql_statement)
+        .bind(database_name)
+        .fetch_all(connection)
+        .await
+        .map_err(Error::sql(sql_statement))?;
+
+    Ok(table_names)
+}
+
+// Reads the schema of a single table and constructs a Table from it.
+// If no database name is passed the database the connection points to is used.
+// The datasets of the table are not read, so the returned Table has none.
+async fn read_table(
+    connection: &mut MySqlConnection,
+    database_name: Option<&str>,
+    table_name: &str,
+) -> Result<Table> {
+    // Read columns
+    // Row layout: column name, column type, is nullable, default, extra
+    let sql_statement =
+        "SELECT CAST(COLUMN_NAME AS CHAR), CAST(COLUMN_TYPE AS CHAR), CAST(IS_NULLABLE AS CHAR), \
+         CAST(COLUMN_DEFAULT AS CHAR), CAST(EXTRA AS CHAR) \
+         FROM information_schema.COLUMNS \
+         WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ? \
+         ORDER BY ORDINAL_POSITION;";
+    let column_rows =
+        sqlx::query_as::<_, (String, String, String, Option<String>, String)>(sql_statement)
+            .bind(database_name)
+            .bind(table_name)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+    // A table always has at least one column, so no rows means the table doesn't exist
+    if column_rows.is_empty() {
+        return Err(Error::NotFound(format!(
+            "Table '{}' doesn't exist",
+            table_name
+        )));
+    }
+
+    let mut columns = Vec::new();
+    for (column_name, column_type, is_nullable, default, extra) in column_rows {
+        columns.push(Column {
+            column_type: parse_column_type(&column_type)
+                .map_err(|error| error.in_column(table_name, &column_name))?,
+            column_name,
+            default,
+            auto_increment: extra.to_lowercase().contains("auto_increment"),
+            not_null: is_nullable == "NO",
+            generator: None,
+            constraints: None,
+        });
+    }
+
+    // Read primary and foreign keys
+    // Row layout: constraint name, column name, referenced table, referenced column
+    // Only foreign keys have a referenced table and column
+    let sql_statement = "SELECT CAST(CONSTRAINT_NAME AS CHAR), CAST(COLUMN_NAME AS CHAR), \
+         CAST(REFERENCED_TABLE_NAME AS CHAR), CAST(REFERENCED_COLUMN_NAME AS CHAR) \
+         FROM information_schema.KEY_COLUMN_USAGE \
+         WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ? \
+         ORDER BY CONSTRAINT_NAME, ORDINAL_POSITION;";
+    let key_rows =
+        sqlx::query_as::<_, (String, String, Option<String>, Option<String>)>(sql_statement)
+            .bind(database_name)
+            .bind(table_name)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+    let mut primary_keys = Vec::new();
+    let mut foreign_keys = Vec::new();
+    for (constraint_name, column_name, origin_table, origin_column) in key_rows {
+        match (origin_table, origin_column) {
+            (Some(origin_table), Some(origin_column)) => foreign_keys.push(ForeignKey {
+                fk_column: column_name,
+                origin_table,
+                origin_column,
+            }),
+            _ if constraint_name == "PRIMARY" => primary_keys.push(column_name),
+            // Unique constraints also show up here but are read together with the indices
+            _ => {}
+        }
+    }
+
+    // Read indices
+    // Row layout: index name, non unique, column name
+    // The rows are ordered so that the columns of one index follow each other in their index order
+    // Parts of functional indices are expressions without a column name
+    let sql_statement =
+        "SELECT CAST(INDEX_NAME AS CHAR), CAST(NON_UNIQUE AS SIGNED), CAST(COLUMN_NAME AS CHAR) \
+         FROM information_schema.STATISTICS \
+         WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ? \
+         ORDER BY INDEX_NAME, SEQ_IN_INDEX;";
+    let index_rows = sqlx::query_as::<_, (String, i64, Option<String>)>(sql_statement)
+        .bind(database_name)
+        .bind(table_name)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(Error::sql(sql_statement))?;
+
+    // Group the rows by index name
+    let mut grouped_indices: Vec<(String, bool, Vec<Option<String>>)> = Vec::new();
+    for (index_name, non_unique, column_name) in index_rows {
+        match grouped_indices.last_mut() {
+            Some((name, _, columns)) if *name == index_name => columns.push(column_name),
+            _ => grouped_indices.push((index_name, non_unique == 0, vec![column_name])),
+        }
+    }
+
+    let mut uniques = Vec::new();
+    let mut indices = Vec::new();
+    for (index_name, unique, index_parts) in grouped_indices {
+        // The primary key is already covered by primary_keys
+        if index_name == "PRIMARY" {
+            continue;
+        }
+
+        // Indices with an expression can't be stored, only keeping their columns would change what they mean
+        let Some(mut index_columns) = index_parts.into_iter().collect::<Option<Vec<String>>>()
+        else {
+            warn!(
+                "Index '{}' of table '{}' contains an expression and is left out",
+                index_name, table_name
+            );
+            continue;
+        };
+
+        if unique && index_columns.len() == 1 {
+            uniques.append(&mut index_columns);
+        } else {
+            // Table can only store single column unique constraints
+            // So a unique constraint over multiple columns is kept as a normal index
+            if unique {
+                warn!(
+                    "Unique index '{}' of table '{}' spans multiple columns and is read as a non unique index",
+                    index_name, table_name
+                );
+            }
+            indices.push(Index {
+                columns: index_columns,
+            });
+        }
+    }
+
+    Ok(Table {
+        table_name: table_name.to_string(),
+        columns,
+        primary_keys,
+        foreign_keys,
+        uniques,
+        indices,
+        datasets: Vec::new(),
+    })
+}
+
+// Maps a MySQL column type like it is found in information_schema.COLUMNS.COLUMN_TYPE to a ColumnType.
+// E.g. "int(10) unsigned", "varchar(255)" or "enum('a','b')"
+pub(crate) fn parse_column_type(column_type: &str) -> Result<ColumnType> {
+    let column_type = column_type.trim();
+    let lowercase = column_type.to_lowercase();
+
+    // Split column type into its name, its arguments between the parentheses and its attributes like unsigned
+    let (name, arguments, attributes) = match (column_type.find('('), column_type.rfind(')')) {
+        (Some(start), Some(end)) if start < end => (
+            lowercase[..start].trim(),
+            &column_type[start + 1..end],
+            &lowercase[end + 1..],
+        ),
+        _ => match lowercase.split_once(' ') {
+            Some((name, attributes)) => (name, "", attributes),
+            None => (lowercase.as_str(), "", ""),
+        },
+    };
+    let unsigned = attributes.contains("unsigned");
+
+    let parsed_type = match (name, unsigned) {
+        // MySQL stores BOOLEAN as TINYINT(1)
+        ("tinyint", false) if arguments == "1" => ColumnType::Boolean,
+        ("bool" | "boolean", _) => ColumnType::Boolean,
+        // Without a size BIT holds a single bit
+        ("bit", _) if arguments.trim().is_empty() => ColumnType::Bit(1),
+        ("bit", _) => ColumnType::Bit(parse_size(arguments, "bit size", column_type)?),
+        ("tinyint", false) => ColumnType::Int8,
+        ("tinyint", true) => ColumnType::Uint8,
+        ("smallint", false) => ColumnType::Int16,
+        ("smallint", true) => ColumnType::Uint16,
+        // There is no 24 bit integer so MEDIUMINT is widened to the next bigger integer
+        ("mediumint" | "int" | "integer", false) => ColumnType::Int32,
+        ("mediumint" | "int" | "integer", true) => ColumnType::Uint32,
+        ("bigint", false) => ColumnType::Int64,
+        ("bigint", true) => ColumnType::Uint64,
+        ("float", _) => ColumnType::Float,
+        ("double" | "real", _) => ColumnType::Double,
+        ("decimal" | "numeric" | "dec" | "fixed", _) => {
+            let (precision, scale) = arguments.split_once(',').unwrap_or((arguments, "0"));
+            // Without arguments MySQL uses DECIMAL(10, 0)
+            let precision = match precision.trim() {
+                "" => 10,
+                precision => parse_size(precision, "decimal precision", column_type)?,
+            };
+            ColumnType::Decimal(precision, parse_size(scale, "decimal scale", column_type)?)
+        }
+        // Without a size CHAR and BINARY hold a single character or byte
+        ("char" | "character", _) if arguments.trim().is_empty() => ColumnType::Char(1),
+        ("char" | "character", _) => {
+            ColumnType::Char(parse_size(arguments, "char size", column_type)?)
+        }
+        ("varchar", _) => ColumnType::Varchar(parse_size(arguments, "varchar size", column_type)?),
+        ("tinytext" | "text", _) => ColumnType::Text,
+        ("mediumtext", _) => ColumnType::MediumText,
+        ("longtext", _) => ColumnType::LongText,
+        ("binary", _) if arguments.trim().is_empty() => ColumnType::Binary(1),
+        ("binary", _) => ColumnType::Binary(parse_size(arguments, "binary size", column_type)?),
+        ("varbinary", _) => {
+            ColumnType::Varbinary(parse_size(arguments, "varbinary size", column_type)?)
+        }
+        // There is only one Blob type, larger blobs are created as BLOB again
+        ("tinyblob" | "blob" | "mediumblob" | "longblob", _) => ColumnType::Blob,
+        ("json", _) => ColumnType::Json,
+        ("date", _) => ColumnType::Date,
+        ("time", _) => ColumnType::Time,
+        ("datetime", _) => ColumnType::DateTime(Tz::UTC),
+        ("timestamp", _) => ColumnType::Timestamp,
+        ("year", _) => ColumnType::Year,
+        ("enum", _) => ColumnType::Enum(parse_value_list(arguments)),
+        ("set", _) => ColumnType::Set(parse_value_list(arguments)),
+        ("geometry", _) => ColumnType::Geometry,
+        ("point", _) => ColumnType::Point,
+        ("linestring", _) => ColumnType::LineString,
+        ("polygon", _) => ColumnType::Polygon,
+        _ => {
+            return Err(Error::unsupported(
+                "MySQL",
+                format!("Column type '{}'", column_type),
+            ))
+        }
+    };
+
+    Ok(parsed_type)
+}
+
+// Parses a size like the length of a varchar from the arguments of a column type.
+fn parse_size<T: std::str::FromStr>(arguments: &str, name: &str, column_type: &str) -> Result<T> {
+    arguments
+        .trim()
+        .parse()
+        .map_err(|_| Error::Parse(format!("Invalid {} in '{}'", name, column_type)))
+}
+
+// Parses the value list of an enum or set column type.
+// E.g. "'a','b','it''s'" becomes ["a", "b", "it's"]
+pub(crate) fn parse_value_list(arguments: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = arguments.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            // A doubled quote inside a value is an escaped quote
+            '\'' if in_quotes && chars.peek() == Some(&'\'') => {
+                current.push('\'');
+                chars.next();
+            }
+            '\'' if in_quotes => {
+                values.push(std::mem::take(&mut current));
+                in_quotes = false;
+            }
+            '\'' => in_quotes = true,
+            _ if in_quotes => current.push(c),
+            // Separators and whitespace between values are skipped
+            _ => {}
+        }
     }
 
-    async fn get_tables<MySqlConnection>(connection_settings: &mut MySqlConnection) -> Result<()> {
-        todo!()
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::db_mocker::geometry::Geometry;
+    use crate::db_mocker::VirtualDatabase;
+
+    #[test]
+    fn parses_integer_column_types() {
+        assert!(matches!(
+            parse_column_type("tinyint(1)").unwrap(),
+            ColumnType::Boolean
+        ));
+        assert!(matches!(
+            parse_column_type("tinyint(4)").unwrap(),
+            ColumnType::Int8
+        ));
+        assert!(matches!(
+            parse_column_type("int(10) unsigned").unwrap(),
+            ColumnType::Uint32
+        ));
+        assert!(matches!(
+            parse_column_type("bigint unsigned zerofill").unwrap(),
+            ColumnType::Uint64
+        ));
+    }
+
+    #[test]
+    fn parses_parameterized_column_types() {
+        assert!(matches!(
+            parse_column_type("varchar(255)").unwrap(),
+            ColumnType::Varchar(255)
+        ));
+
+        match parse_column_type("enum('new','it''s done')").unwrap() {
+            ColumnType::Enum(values) => assert_eq!(values, vec!["new", "it's done"]),
+            _ => panic!("Expected enum"),
+        }
+
+        for (column_type, expected) in [
+            ("decimal(10,2)", ColumnType::Decimal(10, 2)),
+            ("decimal", ColumnType::Decimal(10, 0)),
+            ("char(36)", ColumnType::Char(36)),
+            ("char", ColumnType::Char(1)),
+            ("binary(16)", ColumnType::Binary(16)),
+            ("varbinary(255)", ColumnType::Varbinary(255)),
+            ("mediumtext", ColumnType::MediumText),
+            ("mediumblob", ColumnType::Blob),
+            ("longblob", ColumnType::Blob),
+            ("json", ColumnType::Json),
+            ("timestamp", ColumnType::Timestamp),
+            ("bit(12)", ColumnType::Bit(12)),
+            ("bit", ColumnType::Bit(1)),
+            ("point", ColumnType::Point),
+            ("polygon", ColumnType::Polygon),
+        ] {
+            assert_eq!(parse_column_type(column_type).unwrap(), expected);
+        }
+        assert!(parse_column_type("char(300)").is_err());
+    }
+
+    #[test]
+    fn renders_values_as_literals() {
+        let timestamp = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        assert_eq!(
+            render_value(&Value::Decimal("-12.50".to_string())),
+            "-12.50"
+        );
+        assert_eq!(render_value(&Value::Binary(vec![0, 255, 16])), "X'00ff10'");
+        assert_eq!(
+            render_value(&Value::Json(serde_json::json!({ "it's": [1, null] }))),
+            r#"'{"it''s":[1,null]}'"#
+        );
+        assert_eq!(
+            render_value(&Value::Timestamp(timestamp)),
+            "'2023-11-14 22:13:20'"
+        );
+        assert_eq!(
+            render_value(&Value::Uuid(uuid)),
+            "'67e55044-10b1-426f-9247-bb680e5fe0c8'"
+        );
+        assert_eq!(render_value(&Value::Bit(5)), "b'101'");
+        assert_eq!(
+            render_value(&Value::Geometry(Geometry::Point(13.4, 52.5))),
+            "ST_GeomFromText('POINT(13.4 52.5)')"
+        );
+        assert_eq!(render_value(&Value::Null), "NULL");
+    }
+
+    #[test]
+    fn parses_grantees() {
+        assert_eq!(
+            parse_grantee("'mocker'@'%'").unwrap(),
+            ("mocker".to_string(), "%".to_string())
+        );
+        assert!(parse_grantee("mocker").is_err());
+    }
+
+    #[test]
+    fn batches_datasets_by_size_and_placeholder_limit() {
+        let datasets: Vec<Vec<Value>> = (0..10)
+            .map(|i| vec![Value::Int32(i), Value::Varchar("x".repeat(10))])
+            .collect();
+
+        // Each dataset takes 6 + 21 + 6 = 33 bytes
+        assert_eq!(
+            batch_datasets(&datasets, 100, 2),
+            vec![0..3, 3..6, 6..9, 9..10]
+        );
+        assert_eq!(batch_datasets(&datasets, 10, 2).len(), 10);
+
+        // 65535 placeholders allow 32767 datasets with two columns
+        let datasets = vec![vec![Value::Int8(0), Value::Int8(0)]; 40_000];
+        assert_eq!(
+            batch_datasets(&datasets, usize::MAX, 2),
+            vec![0..32_767, 32_767..40_000]
+        );
     }
 
-    async fn get_table_schema<MySqlConnection>(
-        connection_settings: &mut MySqlConnection,
-        table_name: &str,
-    ) -> Result<()> {
-        todo!()
+    #[test]
+    fn quotes_identifiers_and_literals() {
+        assert_eq!(quote_identifier("order`s"), "`order``s`");
+        assert_eq!(quote_literal(r"it's a \ test"), r"'it''s a \\ test'");
+        assert_eq!(
+            quote_literal_list(&["a".to_string(), "b'c".to_string()]),
+            "'a', 'b''c'"
+        );
+    }
+
+    #[test]
+    fn renders_defaults() {
+        assert_eq!(render_default(&ColumnType::Int32, "42"), "42");
+        assert_eq!(render_default(&ColumnType::Int32, "null"), "NULL");
+        assert_eq!(
+            render_default(&ColumnType::Int32, "1; DROP TABLE x"),
+            "'1; DROP TABLE x'"
+        );
+        assert_eq!(render_default(&ColumnType::Varchar(10), "it's"), "'it''s'");
+        assert_eq!(
+            render_default(&ColumnType::Varchar(10), "'quoted'"),
+            "'quoted'"
+        );
+        assert_eq!(
+            render_default(&ColumnType::Time, "CURRENT_TIME"),
+            "CURRENT_TIME"
+        );
+        assert_eq!(render_default(&ColumnType::Bit(3), "b'101'"), "b'101'");
+        assert_eq!(render_default(&ColumnType::Bit(3), "5"), "5");
+        assert_eq!(parse_bit_literal("B'0110'"), Some(6));
+        assert_eq!(parse_bit_literal("b'12'"), None);
+    }
+
+    fn column(column_name: &str, column_type: ColumnType) -> Column {
+        Column {
+            column_name: column_name.to_string(),
+            column_type,
+            default: None,
+            auto_increment: false,
+            not_null: true,
+            generator: None,
+            constraints: None,
+        }
+    }
+
+    fn orders_table() -> Table {
+        let mut id = column("id", ColumnType::Uint32);
+        id.auto_increment = true;
+        let mut status = column(
+            "status",
+            ColumnType::Enum(vec!["new".to_string(), "done".to_string()]),
+        );
+        status.default = Some("new".to_string());
+
+        Table {
+            table_name: "orders".to_string(),
+            columns: vec![
+                id,
+                column("customer_id", ColumnType::Int32),
+                column("code", ColumnType::Varchar(20)),
+                status,
+            ],
+            primary_keys: vec!["id".to_string()],
+            foreign_keys: vec![ForeignKey {
+                fk_column: "customer_id".to_string(),
+                origin_table: "customers".to_string(),
+                origin_column: "id".to_string(),
+            }],
+            uniques: vec!["code".to_string()],
+            indices: vec![Index {
+                columns: vec!["customer_id".to_string(), "status".to_string()],
+            }],
+            datasets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn renders_create_table() {
+        assert_eq!(
+            MySQLConnector::render_create_table(&orders_table()),
+            vec![
+                "CREATE TABLE `orders` (\
+             `id` INT UNSIGNED NOT NULL AUTO_INCREMENT, \
+             `customer_id` INT NOT NULL, \
+             `code` VARCHAR(20) NOT NULL, \
+             `status` ENUM('new', 'done') NOT NULL DEFAULT 'new', \
+             PRIMARY KEY (`id`), \
+             FOREIGN KEY (`customer_id`) REFERENCES `customers`(`id`), \
+             INDEX (`customer_id`, `status`), \
+             UNIQUE (`code`));"
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_create_user() {
+        let user = User {
+            user_name: "mocker".to_string(),
+            password: "it's secret".to_string(),
+            host: "%".to_string(),
+            user_type: UserType::CRUD,
+        };
+
+        assert_eq!(
+            MySQLConnector::render_create_user(&user, "shop"),
+            vec![
+                "CREATE USER 'mocker'@'%' IDENTIFIED BY 'it''s secret';",
+                "GRANT INSERT, SELECT, UPDATE, DELETE ON `shop`.* TO 'mocker'@'%';",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_database_with_referenced_tables_first() {
+        let mut customers = orders_table();
+        customers.table_name = "customers".to_string();
+        customers.foreign_keys.clear();
+
+        let vdb = VirtualDatabase {
+            database_name: "shop".to_string(),
+            users: Vec::new(),
+            tables: vec![orders_table(), customers],
+        };
+
+        let statements = MySQLConnector::render_database(&vdb).unwrap();
+        assert_eq!(statements[0], "CREATE DATABASE `shop`;");
+        assert_eq!(statements[1], "USE `shop`;");
+        assert!(statements[2].starts_with("CREATE TABLE `customers`"));
+        assert!(statements[3].starts_with("CREATE TABLE `orders`"));
+    }
+
+    #[test]
+    fn renders_insert_datasets() {
+        let mut table = orders_table();
+        table.datasets = vec![
+            vec![
+                Value::Uint32(1),
+                Value::Int32(7),
+                Value::Varchar("it's".to_string()),
+                Value::Enum("new".to_string()),
+            ],
+            vec![
+                Value::Uint32(2),
+                Value::Int32(8),
+                Value::Varchar("B-2".to_string()),
+                Value::Enum("done".to_string()),
+            ],
+        ];
+
+        assert_eq!(
+            MySQLConnector::render_insert_datasets(&table).unwrap(),
+            vec![
+                "INSERT INTO `orders` (`id`, `customer_id`, `code`, `status`) VALUES \
+                 (1, 7, 'it''s', 'new'), (2, 8, 'B-2', 'done');"
+            ]
+        );
+
+        // A dataset has to have a value for every column
+        table.datasets[1].pop();
+        assert!(matches!(
+            MySQLConnector::render_insert_datasets(&table),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_column_types() {
+        assert!(matches!(
+            parse_column_type("multipoint"),
+            Err(Error::Unsupported { dbms, .. }) if dbms == "MySQL"
+        ));
+    }
+
+    #[test]
+    fn rejects_decimals_that_arent_numbers() {
+        let mut table = orders_table();
+        table.columns = vec![column("total", ColumnType::Decimal(10, 2))];
+        table.datasets = vec![vec![Value::Decimal("-12.50".to_string())]];
+        assert!(MySQLConnector::render_insert_datasets(&table).is_ok());
+
+        table.datasets = vec![vec![Value::Decimal("1); DROP TABLE users; --".to_string())]];
+        assert!(matches!(
+            MySQLConnector::render_insert_datasets(&table),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_floats_that_arent_finite() {
+        let mut table = orders_table();
+        table.columns = vec![column("weight", ColumnType::Double)];
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            table.datasets = vec![vec![Value::Double(value)]];
+            assert!(matches!(
+                MySQLConnector::render_insert_datasets(&table),
+                Err(Error::Validation(_))
+            ));
+        }
     }
 }
@@ -0,0 +1,1280 @@
+// Thoughts about this code
+
+// PostgreSQL has no unsigned integers, no 8 bit integer and no YEAR type.
+// These are widened to the next bigger type and get a CHECK constraint that keeps the values in their original range.
+// Reading the schema back returns the widened types, because the CHECK constraints aren't mapped back.
+//     Int8, Uint8, Year -> SMALLINT
+//     Uint16 -> INTEGER
+//     Uint32 -> BIGINT
+//     Uint64 -> NUMERIC(20, 0), or BIGINT for auto increment columns because identities have to be integers
+// Enums need their own type, which is named after table and column, e.g. "orders_status".
+// Sets are stored as TEXT[] with a CHECK constraint that only allows the members of the set.
+// DateTime is stored as TIMESTAMPTZ, which keeps the point in time but not the name of the timezone.
+// Bit columns are BIT VARYING, because BIT only accepts values with exactly as many bits as the column has.
+// Spatial types need the PostGIS extension, e.g. Point becomes geometry(Point).
+
+// PostgreSQL has roles instead of users bound to a host.
+// The host of a User is ignored, which hosts may connect is configured in pg_hba.conf.
+
+// PostgreSQL can't read the catalog of another database over the same connection.
+// So reading the schema or users only works for the database the connection points to.
+
+use async_trait::async_trait;
+use chrono_tz::Tz;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions, PgSslMode};
+use sqlx::{ConnectOptions, Connection, Executor, PgConnection, PgPool};
+use tracing::warn;
+
+use super::my_sql::parse_value_list;
+use super::{check_datasets, hex_digits, join_insert_rows};
+use super::{ConnectionSettings, DbReader, DbWriter, SqlRenderer, TlsMode};
+use super::{Table, User};
+use crate::db_mocker::error::{Error, Result};
+use crate::db_mocker::{parse_bit_literal, Column, ColumnType, ForeignKey, Index, UserType, Value};
+
+pub struct PostgreSQLConnector {}
+
+impl PostgreSQLConnector {
+    // Builds the options to connect to the passed database.
+    // Without a database the maintenance database "postgres" is used, because PostgreSQL always connects to a database.
+    pub fn connect_options(
+        connection_settings: &ConnectionSettings,
+        database_name: Option<&str>,
+    ) -> PgConnectOptions {
+        let mut options = PgConnectOptions::new()
+            .host(&connection_settings.address)
+            .username(&connection_settings.user_name)
+            .password(&connection_settings.password)
+            .database(database_name.unwrap_or("postgres"))
+            .ssl_mode(match connection_settings.tls_mode {
+                TlsMode::Disabled => PgSslMode::Disable,
+                TlsMode::Preferred => PgSslMode::Prefer,
+                TlsMode::Required => PgSslMode::Require,
+                TlsMode::VerifyCa => PgSslMode::VerifyCa,
+                TlsMode::VerifyIdentity => PgSslMode::VerifyFull,
+            });
+
+        if let Some(port) = connection_settings.port {
+            options = options.port(port);
+        }
+        // For PostgreSQL the socket is the directory containing the socket file
+        if let Some(socket) = &connection_settings.socket {
+            options = options.socket(socket);
+        }
+
+        options
+    }
+
+    async fn connect_pool(
+        connection_settings: &ConnectionSettings,
+        database_name: Option<&str>,
+    ) -> Result<PgPool> {
+        PgPoolOptions::new()
+            .max_connections(connection_settings.max_connections)
+            .connect_with(Self::connect_options(connection_settings, database_name))
+            .await
+            .map_err(Error::connection(&connection_settings.address))
+    }
+}
+
+#[async_trait]
+impl DbWriter for PostgreSQLConnector {
+    type C = PgPool;
+
+    // Connects to the database of the ConnectionSettings.
+    async fn connect(connection_settings: &ConnectionSettings) -> Result<Self::C> {
+        Self::connect_pool(connection_settings, connection_settings.database.as_deref()).await
+    }
+
+    // Creates a new database from the passed name on the connected DBMS.
+    // Returns a new pool pointing to the database.
+    async fn create_database(
+        connection_settings: &ConnectionSettings,
+        database_name: &str,
+    ) -> Result<Self::C> {
+        // Create connection to the maintenance database that exists on every PostgreSQL server
+        let mut connection = Self::connect_options(connection_settings, None)
+            .connect()
+            .await
+            .map_err(Error::connection(&connection_settings.address))?;
+
+        // CREATE DATABASE can't run inside a transaction block, so it is sent as a simple query
+        let sql_statement = Self::render_create_database(database_name);
+        connection
+            .execute(sql_statement.as_str())
+            .await
+            .map_err(Error::sql(&sql_statement))?;
+
+        connection
+            .close()
+            .await
+            .map_err(Error::connection(&connection_settings.address))?;
+
+        Self::connect_pool(connection_settings, Some(database_name)).await
+    }
+
+    // Creates the types, the table and the indices for the passed Table in the connected database.
+    async fn create_table(pool: &Self::C, table: &Table) -> Result<()> {
+        for sql_statement in Self::render_create_table(table) {
+            sqlx::query(&sql_statement)
+                .execute(pool)
+                .await
+                .map_err(Error::sql(&sql_statement))?;
+        }
+
+        Ok(())
+    }
+
+    // Creates a role from the passed User and grants it privileges on the connected database.
+    // The privileges on tables are only granted for tables that already exist, so users should be created after the tables.
+    async fn create_user(pool: &Self::C, user: &User, database_name: &str) -> Result<()> {
+        // The password isn't part of the error
+        let password = quote_literal(&user.password);
+        for sql_statement in Self::render_create_user(user, database_name) {
+            sqlx::query(&sql_statement)
+                .execute(pool)
+                .await
+                .map_err(Error::sql_with_secret(&sql_statement, &password))?;
+        }
+
+        Ok(())
+    }
+
+    // Inserts all datasets of the passed Table into the table of the connected database.
+    // The rendered multi row INSERT statements are executed inside one transaction.
+    // Literals are used instead of placeholders, because values of enum and array columns would need casts otherwise.
+    async fn insert_datasets(pool: &Self::C, table: &Table) -> Result<()> {
+        let mut transaction = pool.begin().await.map_err(Error::sql("BEGIN"))?;
+        for sql_statement in Self::render_insert_datasets(table)? {
+            // Every statement is different, so caching them as prepared statements would only waste memory
+            sqlx::query(&sql_statement)
+                .persistent(false)
+                .execute(&mut *transaction)
+                .await
+                .map_err(Error::sql(&sql_statement))?;
+        }
+        transaction.commit().await.map_err(Error::sql("COMMIT"))?;
+
+        Ok(())
+    }
+}
+
+impl SqlRenderer for PostgreSQLConnector {
+    // Renders the statement creating a database with the passed name.
+    fn render_create_database(database_name: &str) -> String {
+        format!("CREATE DATABASE {};", quote_identifier(database_name))
+    }
+
+    // Renders the psql command switching to the database with the passed name.
+    // PostgreSQL has no USE statement, a connection always stays on its database.
+    fn render_use_database(database_name: &str) -> String {
+        format!("\\connect {}", quote_identifier(database_name))
+    }
+
+    // Renders the statements creating the passed Table.
+    // Enum columns need their type created before the table and indices are created after it.
+    fn render_create_table(table: &Table) -> Vec<String> {
+        /*
+            Structure of the statements
+            Every word beginnig with !!! is data given by the user
+            Identifiers are quoted with double quotes and values are quoted as string literals with quote_identifier() and quote_literal()
+            The other words are fixed
+
+            CREATE TYPE "!!!tbl_name_!!!col_name" AS ENUM ('!!!value', ...);
+            ...
+            CREATE TABLE "!!!tbl_name" (
+                "!!!col_name" datatype(!!!size) NOT NULL DEFAULT '!!!def_value' GENERATED BY DEFAULT AS IDENTITY CHECK (...),
+                ...
+                PRIMARY KEY ("!!!col_name_1", ...),
+                FOREIGN KEY ("!!!col_name") REFERENCES "!!!foreign_tbl_name"("!!!foreign_col_name"),
+                ...
+                UNIQUE ("!!!col_name"),
+                ...
+            );
+            CREATE INDEX ON "!!!tbl_name" ("!!!col_name_1", ...);
+            ...
+        */
+
+        let mut statements = Vec::new();
+
+        // Create a type for each enum column
+        for column in table.columns.iter() {
+            if let ColumnType::Enum(values) = &column.column_type {
+                statements.push(format!(
+                    "CREATE TYPE {} AS ENUM ({});",
+                    quote_identifier(&enum_type_name(table, column)),
+                    quote_literal_list(values)
+                ));
+            }
+        }
+
+        let mut sections = Vec::new();
+
+        // One section per column
+        // "col_name" datatype NOT NULL DEFAULT def_value GENERATED BY DEFAULT AS IDENTITY CHECK (...)
+        for column in table.columns.iter() {
+            let column_name = quote_identifier(&column.column_name);
+            let mut section = format!("{} {}", column_name, render_column_type(table, column));
+
+            if column.not_null {
+                section += " NOT NULL";
+            }
+
+            if let Some(value) = &column.default {
+                section += " DEFAULT ";
+                section += &render_default(&column.column_type, value);
+            }
+
+            if column.auto_increment {
+                section += " GENERATED BY DEFAULT AS IDENTITY";
+            }
+
+            // Keep widened types in the range of their original type
+            if let Some((min, max)) = value_range(column) {
+                section += &format!(" CHECK ({} BETWEEN {} AND {})", column_name, min, max);
+            }
+
+            // Only allow the members of a set in the array
+            if let ColumnType::Set(values) = &column.column_type {
+                section += &format!(" CHECK ({} <@ {})", column_name, render_array(values));
+            }
+
+            sections.push(section);
+        }
+
+        if !table.primary_keys.is_empty() {
+            sections.push(format!(
+                "PRIMARY KEY ({})",
+                quote_identifier_list(&table.primary_keys)
+            ));
+        }
+
+        for fk in table.foreign_keys.iter() {
+            sections.push(format!(
+                "FOREIGN KEY ({}) REFERENCES {}({})",
+                quote_identifier(&fk.fk_column),
+                quote_identifier(&fk.origin_table),
+                quote_identifier(&fk.origin_column)
+            ));
+        }
+
+        for unique in table.uniques.iter() {
+            sections.push(format!("UNIQUE ({})", quote_identifier(unique)));
+        }
+
+        statements.push(format!(
+            "CREATE TABLE {} ({});",
+            quote_identifier(&table.table_name),
+            sections.join(", ")
+        ));
+
+        // PostgreSQL has no INDEX section inside CREATE TABLE
+        for index in table.indices.iter() {
+            statements.push(format!(
+                "CREATE INDEX ON {} ({});",
+                quote_identifier(&table.table_name),
+                quote_identifier_list(&index.columns)
+            ));
+        }
+
+        statements
+    }
+
+    // Renders the statements creating a role for the passed User and granting it privileges on the database.
+    // The privileges are granted on the tables and sequences of the public schema, sequences are needed to insert into identity columns.
+    fn render_create_user(user: &User, database_name: &str) -> Vec<String> {
+        let role = quote_identifier(&user.user_name);
+        let database_name = quote_identifier(database_name);
+
+        let create_statement = format!(
+            "CREATE ROLE {} WITH LOGIN PASSWORD {};",
+            role,
+            quote_literal(&user.password)
+        );
+
+        match user.user_type {
+            // Admin role gets all privileges
+            UserType::Admin => vec![
+                create_statement,
+                format!(
+                    "GRANT ALL PRIVILEGES ON DATABASE {} TO {} WITH GRANT OPTION;",
+                    database_name, role
+                ),
+                format!(
+                    "GRANT ALL PRIVILEGES ON ALL TABLES IN SCHEMA public TO {} WITH GRANT OPTION;",
+                    role
+                ),
+                format!(
+                    "GRANT ALL PRIVILEGES ON ALL SEQUENCES IN SCHEMA public TO {} WITH GRANT OPTION;",
+                    role
+                ),
+            ],
+            // Non admin only gets rights to execute CRUD statements
+            UserType::CRUD => vec![
+                create_statement,
+                format!("GRANT CONNECT ON DATABASE {} TO {};", database_name, role),
+                format!(
+                    "GRANT SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA public TO {};",
+                    role
+                ),
+                format!(
+                    "GRANT USAGE ON ALL SEQUENCES IN SCHEMA public TO {};",
+                    role
+                ),
+            ],
+        }
+    }
+
+    // Renders multi row INSERT statements with all datasets of the passed Table.
+    // The values are part of the statements as literals, so the statements can be executed without binding anything.
+    // PostgreSQL has no packet limit, statements are kept below 1 MiB to keep them manageable.
+    fn render_insert_datasets(table: &Table) -> Result<Vec<String>> {
+        const MAX_STATEMENT_SIZE: usize = 1024 * 1024;
+
+        // Every dataset needs exactly one value per column
+        check_datasets(table)?;
+
+        // Statement start shared by all statements
+        // INSERT INTO "tbl_name" ("col_name_1", ...) VALUES
+        let column_names: Vec<String> = table
+            .columns
+            .iter()
+            .map(|column| column.column_name.clone())
+            .collect();
+        let statement_start = format!(
+            "INSERT INTO {} ({}) VALUES ",
+            quote_identifier(&table.table_name),
+            quote_identifier_list(&column_names)
+        );
+
+        let rows: Vec<String> = table
+            .datasets
+            .iter()
+            .map(|dataset| {
+                let values: Vec<String> = dataset.iter().map(render_value).collect();
+                format!("({})", values.join(", "))
+            })
+            .collect();
+
+        Ok(join_insert_rows(
+            &statement_start,
+            rows,
+            MAX_STATEMENT_SIZE,
+            usize::MAX,
+        ))
+    }
+}
+
+// Name of the type created for an enum column.
+fn enum_type_name(table: &Table, column: &Column) -> String {
+    format!("{}_{}", table.table_name, column.column_name)
+}
+
+// Renders the PostgreSQL type of a column.
+fn render_column_type(table: &Table, column: &Column) -> String {
+    match &column.column_type {
+        ColumnType::Int8 | ColumnType::Uint8 | ColumnType::Int16 | ColumnType::Year => {
+            "SMALLINT".to_string()
+        }
+        ColumnType::Uint16 | ColumnType::Int32 => "INTEGER".to_string(),
+        ColumnType::Uint32 | ColumnType::Int64 => "BIGINT".to_string(),
+        ColumnType::Uint64 if column.auto_increment => "BIGINT".to_string(),
+        ColumnType::Uint64 => "NUMERIC(20, 0)".to_string(),
+        ColumnType::Float => "REAL".to_string(),
+        ColumnType::Double => "DOUBLE PRECISION".to_string(),
+        ColumnType::Decimal(precision, scale) => format!("NUMERIC({}, {})", precision, scale),
+        ColumnType::Boolean => "BOOLEAN".to_string(),
+        ColumnType::Bit(size) => format!("BIT VARYING({})", size),
+        ColumnType::Char(size) => format!("CHAR({})", size),
+        ColumnType::Varchar(size) => format!("VARCHAR({})", size),
+        // TEXT and BYTEA have no size limit, so every text and binary type becomes one of them
+        ColumnType::Text | ColumnType::MediumText | ColumnType::LongText => "TEXT".to_string(),
+        ColumnType::Binary(_) | ColumnType::Varbinary(_) | ColumnType::Blob => "BYTEA".to_string(),
+        ColumnType::Json => "JSONB".to_string(),
+        ColumnType::Date => "DATE".to_string(),
+        ColumnType::Time => "TIME".to_string(),
+        ColumnType::DateTime(_) => "TIMESTAMPTZ".to_string(),
+        // Timestamps are always UTC, so they don't need the offset of TIMESTAMPTZ
+        ColumnType::Timestamp => "TIMESTAMP".to_string(),
+        ColumnType::Uuid => "UUID".to_string(),
+        ColumnType::Enum(_) => quote_identifier(&enum_type_name(table, column)),
+        ColumnType::Set(_) => "TEXT[]".to_string(),
+        ColumnType::Geometry => "geometry".to_string(),
+        ColumnType::Point => "geometry(Point)".to_string(),
+        ColumnType::LineString => "geometry(LineString)".to_string(),
+        ColumnType::Polygon => "geometry(Polygon)".to_string(),
+    }
+}
+
+// Range of the values of a column whose type had to be widened.
+// Returns None if the PostgreSQL type has the same range as the ColumnType.
+fn value_range(column: &Column) -> Option<(String, String)> {
+    let (min, max) = match column.column_type {
+        ColumnType::Int8 => (i8::MIN.to_string(), i8::MAX.to_string()),
+        ColumnType::Uint8 => ("0".to_string(), u8::MAX.to_string()),
+        ColumnType::Uint16 => ("0".to_string(), u16::MAX.to_string()),
+        ColumnType::Uint32 => ("0".to_string(), u32::MAX.to_string()),
+        // An auto increment column is a BIGINT and can't hold the upper half anyway
+        ColumnType::Uint64 if column.auto_increment => ("0".to_string(), i64::MAX.to_string()),
+        ColumnType::Uint64 => ("0".to_string(), u64::MAX.to_string()),
+        ColumnType::Year => ("1901".to_string(), "2155".to_string()),
+        _ => return None,
+    };
+
+    Some((min, max))
+}
+
+// Renders a Value as a SQL literal.
+// DateTime keeps its offset, so TIMESTAMPTZ stores the right point in time.
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::Int8(value) => value.to_string(),
+        Value::Uint8(value) => value.to_string(),
+        Value::Int16(value) => value.to_string(),
+        Value::Uint16(value) => value.to_string(),
+        Value::Int32(value) => value.to_string(),
+        Value::Uint32(value) => value.to_string(),
+        Value::Int64(value) => value.to_string(),
+        Value::Uint64(value) => value.to_string(),
+        Value::Float(value) => render_float(*value as f64),
+        Value::Double(value) => render_float(*value),
+        Value::Decimal(value) => value.clone(),
+        Value::Boolean(value) => value.to_string().to_uppercase(),
+        Value::Bit(value) => format!("B'{:b}'", value),
+        Value::Char(value) | Value::Varchar(value) | Value::Text(value) => quote_literal(value),
+        Value::Binary(value) => format!("'\\x{}'::BYTEA", hex_digits(value)),
+        Value::Json(value) => quote_literal(&value.to_string()),
+        Value::Date(value) => quote_literal(&value.format("%Y-%m-%d").to_string()),
+        Value::Time(value) => quote_literal(&value.format("%H:%M:%S%.f").to_string()),
+        Value::DateTime(value) => quote_literal(&value.to_rfc3339()),
+        Value::Timestamp(value) => quote_literal(&value.format("%Y-%m-%d %H:%M:%S%.f").to_string()),
+        Value::Year(value) => value.to_string(),
+        Value::Uuid(value) => quote_literal(&value.to_string()),
+        Value::Enum(value) => quote_literal(value),
+        Value::Set(members) => render_array(members),
+        Value::Geometry(geometry) => {
+            format!("ST_GeomFromText({})", quote_literal(&geometry.to_wkt()))
+        }
+    }
+}
+
+// Renders a floating point number, PostgreSQL only knows NaN and infinity as quoted literals.
+fn render_float(value: f64) -> String {
+    if value.is_nan() {
+        quote_literal("NaN")
+    } else if value.is_infinite() && value > 0.0 {
+        quote_literal("Infinity")
+    } else if value.is_infinite() {
+        quote_literal("-Infinity")
+    } else {
+        value.to_string()
+    }
+}
+
+// Renders the values as a text array.
+fn render_array(values: &[String]) -> String {
+    format!("ARRAY[{}]::TEXT[]", quote_literal_list(values))
+}
+
+// Quotes an identifier like a table or column name with double quotes.
+// Double quotes inside the identifier are escaped by doubling them.
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+// Quotes and joins identifiers for a list like the columns of a key.
+fn quote_identifier_list(identifiers: &[String]) -> String {
+    let quoted: Vec<String> = identifiers
+        .iter()
+        .map(|identifier| quote_identifier(identifier))
+        .collect();
+    quoted.join(", ")
+}
+
+// Quotes a value as a string literal with single quotes.
+// Single quotes are escaped by doubling them.
+// Backslashes don't need escaping because standard_conforming_strings is on since PostgreSQL 9.1.
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+// Quotes and joins values for a list like the members of an enum.
+fn quote_literal_list(values: &[String]) -> String {
+    let quoted: Vec<String> = values.iter().map(|value| quote_literal(value)).collect();
+    quoted.join(", ")
+}
+
+// Renders the default value of a column for a CREATE TABLE statement.
+// Numbers, NULL and datetime functions like CURRENT_TIMESTAMP are kept as they are.
+// MySQL specific defaults are translated, e.g. 1 for a boolean becomes TRUE and CURDATE() becomes CURRENT_DATE.
+// Everything else is quoted as a string literal, a set default like 'a,b' becomes an array.
+fn render_default(column_type: &ColumnType, default: &str) -> String {
+    let default = default.trim();
+    let uppercase = default.to_uppercase();
+
+    if uppercase == "NULL" {
+        return uppercase;
+    }
+
+    match column_type {
+        ColumnType::Int8
+        | ColumnType::Uint8
+        | ColumnType::Int16
+        | ColumnType::Uint16
+        | ColumnType::Int32
+        | ColumnType::Uint32
+        | ColumnType::Int64
+        | ColumnType::Uint64
+        | ColumnType::Float
+        | ColumnType::Double
+        | ColumnType::Decimal(_, _)
+        | ColumnType::Year
+            if default.parse::<f64>().is_ok() =>
+        {
+            return default.to_string()
+        }
+        ColumnType::Boolean => match uppercase.as_str() {
+            "TRUE" | "1" => return "TRUE".to_string(),
+            "FALSE" | "0" => return "FALSE".to_string(),
+            _ => {}
+        },
+        // MySQL bit defaults are numbers or literals like b'101'
+        ColumnType::Bit(_) => {
+            if let Some(value) = parse_bit_literal(default) {
+                return format!("B'{:b}'", value);
+            }
+        }
+        ColumnType::Date | ColumnType::Time | ColumnType::DateTime(_) | ColumnType::Timestamp => {
+            match uppercase.split('(').next().unwrap_or_default() {
+                "CURRENT_TIMESTAMP" | "NOW" => return "CURRENT_TIMESTAMP".to_string(),
+                "CURRENT_DATE" | "CURDATE" => return "CURRENT_DATE".to_string(),
+                "CURRENT_TIME" | "CURTIME" => return "CURRENT_TIME".to_string(),
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+
+    let unquoted = default
+        .strip_prefix('\'')
+        .and_then(|default| default.strip_suffix('\''))
+        .map(|default| default.replace("''", "'"))
+        .unwrap_or_else(|| default.to_string());
+
+    match column_type {
+        ColumnType::Set(_) if unquoted.is_empty() => render_array(&[]),
+        ColumnType::Set(_) => {
+            let members: Vec<String> = unquoted.split(',').map(str::to_string).collect();
+            render_array(&members)
+        }
+        _ => quote_literal(&unquoted),
+    }
+}
+
+#[async_trait]
+impl DbReader for PostgreSQLConnector {
+    type C = PgConnection;
+
+    // Returns the names of all databases on the connected DBMS.
+    // Templates and the maintenance database postgres are left out because they aren't something to mock.
+    async fn get_databases(connection: &mut Self::C) -> Result<Vec<String>> {
+        let sql_statement = "SELECT datname::text FROM pg_catalog.pg_database \
+             WHERE NOT datistemplate AND datname <> 'postgres' \
+             ORDER BY datname;";
+        let databases = sqlx::query_scalar::<_, String>(sql_statement)
+            .fetch_all(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        Ok(databases)
+    }
+
+    // Returns the name of the database the connection points to.
+    async fn get_current_database(connection: &mut Self::C) -> Result<String> {
+        let sql_statement = "SELECT current_database()::text;";
+        let database_name = sqlx::query_scalar::<_, String>(sql_statement)
+            .fetch_one(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        Ok(database_name)
+    }
+
+    // Returns every table of the passed database with its columns, keys and indices.
+    // The database has to be the one the connection points to.
+    async fn get_database_schema(
+        connection: &mut Self::C,
+        database_name: &str,
+    ) -> Result<Vec<Table>> {
+        check_current_database(connection, database_name).await?;
+
+        let mut tables = Vec::new();
+
+        for table_name in Self::get_tables(connection).await? {
+            tables.push(read_table(connection, &table_name).await?);
+        }
+
+        Ok(tables)
+    }
+
+    // Returns the names of all tables in the current schema of the database the connection points to.
+    async fn get_tables(connection: &mut Self::C) -> Result<Vec<String>> {
+        // Partitioned tables are included, their partitions are tables of their own
+        let sql_statement = "SELECT c.relname::text FROM pg_catalog.pg_class c \
+             JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+             WHERE c.relkind IN ('r', 'p') AND n.nspname = current_schema() \
+             ORDER BY c.relname;";
+        let table_names = sqlx::query_scalar::<_, String>(sql_statement)
+            .fetch_all(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        Ok(table_names)
+    }
+
+    // Returns the passed table of the database the connection points to.
+    async fn get_table_schema(connection: &mut Self::C, table_name: &str) -> Result<Table> {
+        read_table(connection, table_name).await
+    }
+
+    // Returns all roles that have privileges on the tables of the passed database.
+    // The database has to be the one the connection points to.
+    // A role with privileges beyond CRUD statements or who is allowed to grant privileges is read as an admin.
+    // Passwords are only stored as hashes by PostgreSQL, so the returned users have empty passwords.
+    async fn get_users(connection: &mut Self::C, database_name: &str) -> Result<Vec<User>> {
+        check_current_database(connection, database_name).await?;
+
+        // Row layout: role name, privilege type, is grantable
+        // The owner of a table has all privileges implicitly and isn't read as a user
+        let sql_statement = "SELECT r.rolname::text, a.privilege_type, a.is_grantable \
+             FROM pg_catalog.pg_class c \
+             JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+             CROSS JOIN LATERAL aclexplode(c.relacl) a \
+             JOIN pg_catalog.pg_roles r ON r.oid = a.grantee \
+             WHERE c.relkind IN ('r', 'p') AND n.nspname = current_schema() \
+             AND a.grantee <> c.relowner \
+             ORDER BY r.rolname;";
+        let privilege_rows = sqlx::query_as::<_, (String, String, bool)>(sql_statement)
+            .fetch_all(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        let mut users: Vec<User> = Vec::new();
+        for (user_name, privilege, is_grantable) in privilege_rows {
+            let admin = is_grantable
+                || !matches!(
+                    privilege.as_str(),
+                    "SELECT" | "INSERT" | "UPDATE" | "DELETE"
+                );
+
+            // Rows of the same role follow each other because they are ordered by role name
+            match users.last_mut() {
+                Some(user) if user.user_name == user_name => {
+                    if admin {
+                        user.user_type = UserType::Admin;
+                    }
+                }
+                _ => users.push(User {
+                    user_name,
+                    password: String::new(),
+                    // Roles aren't bound to a host
+                    host: "%".to_string(),
+                    user_type: if admin {
+                        UserType::Admin
+                    } else {
+                        UserType::CRUD
+                    },
+                }),
+            }
+        }
+
+        Ok(users)
+    }
+}
+
+// Fails if the passed database isn't the one the connection points to.
+async fn check_current_database(connection: &mut PgConnection, database_name: &str) -> Result<()> {
+    let current_database = PostgreSQLConnector::get_current_database(connection).await?;
+    if current_database != database_name {
+        return Err(Error::unsupported(
+            "PostgreSQL",
+            format!(
+                "Reading database '{}' over a connection to database '{}'",
+                database_name, current_database
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+// Reads the schema of a single table of the current schema and constructs a Table from it.
+// The datasets of the table are not read, so the returned Table has none.
+async fn read_table(connection: &mut PgConnection, table_name: &str) -> Result<Table> {
+    // The table is looked up by casting its quoted name to regclass, which also fails if the table doesn't exist
+    let table_reference = quote_identifier(table_name);
+
+    // Read columns
+    // Row layout: column name, column type, not null, default expression, identity, enum labels
+    // Enum labels are only set if the type of the column is an enum
+    let sql_statement =
+        "SELECT a.attname::text, format_type(a.atttypid, a.atttypmod), a.attnotnull, \
+         pg_get_expr(d.adbin, d.adrelid), a.attidentity::text, \
+         (SELECT array_agg(e.enumlabel::text ORDER BY e.enumsortorder) \
+          FROM pg_catalog.pg_enum e WHERE e.enumtypid = a.atttypid) \
+         FROM pg_catalog.pg_attribute a \
+         LEFT JOIN pg_catalog.pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum \
+         WHERE a.attrelid = $1::regclass AND a.attnum > 0 AND NOT a.attisdropped \
+         ORDER BY a.attnum;";
+    let column_rows = sqlx::query_as::<
+        _,
+        (
+            String,
+            String,
+            bool,
+            Option<String>,
+            String,
+            Option<Vec<String>>,
+        ),
+    >(sql_statement)
+    .bind(&table_reference)
+    .fetch_all(&mut *connection)
+    .await
+    .map_err(Error::sql(sql_statement))?;
+
+    // Definitions of the CHECK constraints are needed for the members of set columns
+    let sql_statement = "SELECT pg_get_constraintdef(oid) FROM pg_catalog.pg_constraint \
+         WHERE conrelid = $1::regclass AND contype = 'c';";
+    let check_definitions = sqlx::query_scalar::<_, String>(sql_statement)
+        .bind(&table_reference)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(Error::sql(sql_statement))?;
+
+    let mut columns = Vec::new();
+    for (column_name, column_type, not_null, default, identity, enum_labels) in column_rows {
+        let parsed_type = match enum_labels {
+            Some(labels) => ColumnType::Enum(labels),
+            None if column_type == "text[]" => ColumnType::Set(
+                parse_set_members(&check_definitions, &column_name).ok_or_else(|| {
+                    Error::unsupported(
+                        "PostgreSQL",
+                        format!(
+                            "Reading array column '{}.{}' without a CHECK constraint listing its members",
+                            table_name, column_name
+                        ),
+                    )
+                })?,
+            ),
+            None => parse_column_type(&column_type)
+                .map_err(|error| error.in_column(table_name, &column_name))?,
+        };
+
+        // Serial columns get their values from a sequence instead of being an identity
+        let serial = default
+            .as_deref()
+            .is_some_and(|default| default.starts_with("nextval("));
+
+        columns.push(Column {
+            column_name,
+            column_type: parsed_type,
+            default: if serial {
+                None
+            } else {
+                default.as_deref().map(parse_default)
+            },
+            auto_increment: !identity.is_empty() || serial,
+            not_null,
+            generator: None,
+            constraints: None,
+        });
+    }
+
+    // Read primary keys, foreign keys and unique constraints
+    // Row layout: constraint name, constraint type, column name, referenced table, referenced column
+    // Only foreign keys have a referenced table and column
+    let sql_statement = "SELECT c.conname::text, c.contype::text, a.attname::text, ft.relname::text, fa.attname::text \
+         FROM pg_catalog.pg_constraint c \
+         CROSS JOIN LATERAL unnest(c.conkey, c.confkey) WITH ORDINALITY AS k(attnum, fattnum, ord) \
+         JOIN pg_catalog.pg_attribute a ON a.attrelid = c.conrelid AND a.attnum = k.attnum \
+         LEFT JOIN pg_catalog.pg_class ft ON ft.oid = c.confrelid \
+         LEFT JOIN pg_catalog.pg_attribute fa ON fa.attrelid = c.confrelid AND fa.attnum = k.fattnum \
+         WHERE c.conrelid = $1::regclass AND c.contype IN ('p', 'f', 'u') \
+         ORDER BY c.conname, k.ord;";
+    let key_rows = sqlx::query_as::<_, (String, String, String, Option<String>, Option<String>)>(
+        sql_statement,
+    )
+    .bind(&table_reference)
+    .fetch_all(&mut *connection)
+    .await
+    .map_err(Error::sql(sql_statement))?;
+
+    let mut primary_keys = Vec::new();
+    let mut foreign_keys = Vec::new();
+    // Unique constraints grouped by constraint name
+    let mut unique_constraints: Vec<(String, Vec<String>)> = Vec::new();
+    for (constraint_name, constraint_type, column_name, origin_table, origin_column) in key_rows {
+        match (constraint_type.as_str(), origin_table, origin_column) {
+            ("f", Some(origin_table), Some(origin_column)) => foreign_keys.push(ForeignKey {
+                fk_column: column_name,
+                origin_table,
+                origin_column,
+            }),
+            ("p", _, _) => primary_keys.push(column_name),
+            ("u", _, _) => match unique_constraints.last_mut() {
+                Some((name, columns)) if *name == constraint_name => columns.push(column_name),
+                _ => unique_constraints.push((constraint_name, vec![column_name])),
+            },
+            _ => {}
+        }
+    }
+
+    // Read indices that don't belong to a constraint
+    // Row layout: index name, is unique, column name
+    // Indices on expressions have no column and are left out
+    let sql_statement = "SELECT i.relname::text, x.indisunique, a.attname::text \
+         FROM pg_catalog.pg_index x \
+         JOIN pg_catalog.pg_class i ON i.oid = x.indexrelid \
+         CROSS JOIN LATERAL unnest(x.indkey::int2[]) WITH ORDINALITY AS k(attnum, ord) \
+         JOIN pg_catalog.pg_attribute a ON a.attrelid = x.indrelid AND a.attnum = k.attnum \
+         WHERE x.indrelid = $1::regclass AND NOT x.indisprimary \
+         AND NOT EXISTS (SELECT 1 FROM pg_catalog.pg_constraint c WHERE c.conindid = x.indexrelid) \
+         ORDER BY i.relname, k.ord;";
+    let index_rows = sqlx::query_as::<_, (String, bool, String)>(sql_statement)
+        .bind(&table_reference)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(Error::sql(sql_statement))?;
+
+    // Group the rows by index name
+    let mut grouped_indices: Vec<(String, bool, Vec<String>)> = unique_constraints
+        .into_iter()
+        .map(|(name, columns)| (name, true, columns))
+        .collect();
+    for (index_name, unique, column_name) in index_rows {
+        match grouped_indices.last_mut() {
+            Some((name, _, columns)) if *name == index_name => columns.push(column_name),
+            _ => grouped_indices.push((index_name, unique, vec![column_name])),
+        }
+    }
+
+    let mut uniques = Vec::new();
+    let mut indices = Vec::new();
+    for (index_name, unique, mut index_columns) in grouped_indices {
+        if unique && index_columns.len() == 1 {
+            uniques.append(&mut index_columns);
+        } else {
+            // Table can only store single column unique constraints
+            // So a unique constraint over multiple columns is kept as a normal index
+            if unique {
+                warn!(
+                    "Unique index '{}' of table '{}' spans multiple columns and is read as a non unique index",
+                    index_name, table_name
+                );
+            }
+            indices.push(Index {
+                columns: index_columns,
+            });
+        }
+    }
+
+    Ok(Table {
+        table_name: table_name.to_string(),
+        columns,
+        primary_keys,
+        foreign_keys,
+        uniques,
+        indices,
+        datasets: Vec::new(),
+    })
+}
+
+// Maps a PostgreSQL column type like it is returned by format_type() to a ColumnType.
+// E.g. "integer", "character varying(255)" or "timestamp(3) with time zone"
+// Enums and sets aren't covered, because their members are read from the catalog.
+fn parse_column_type(column_type: &str) -> Result<ColumnType> {
+    let lowercase = column_type.trim().to_lowercase();
+
+    // Split column type into its name and its arguments between the parentheses
+    // The arguments can be in the middle of the name like in "time(3) without time zone"
+    let (name, arguments) = match (lowercase.find('('), lowercase.find(')')) {
+        (Some(start), Some(end)) if start < end => (
+            format!("{}{}", lowercase[..start].trim_end(), &lowercase[end + 1..]),
+            &lowercase[start + 1..end],
+        ),
+        _ => (lowercase.clone(), ""),
+    };
+
+    let parsed_type = match (name.as_str(), arguments) {
+        ("smallint", _) => ColumnType::Int16,
+        ("integer", _) => ColumnType::Int32,
+        ("bigint", _) => ColumnType::Int64,
+        // NUMERIC(20, 0) is how unsigned 64 bit integers are stored
+        ("numeric", "20,0") => ColumnType::Uint64,
+        ("numeric", arguments) if !arguments.is_empty() => {
+            let (precision, scale) = arguments.split_once(',').unwrap_or((arguments, "0"));
+            let parse = |digits: &str| {
+                digits.trim().parse().map_err(|_| {
+                    Error::Parse(format!("Invalid numeric precision in '{}'", column_type))
+                })
+            };
+            ColumnType::Decimal(parse(precision)?, parse(scale)?)
+        }
+        ("real", _) => ColumnType::Float,
+        ("double precision", _) => ColumnType::Double,
+        ("boolean", _) => ColumnType::Boolean,
+        ("bit" | "bit varying", size) if !size.is_empty() => ColumnType::Bit(
+            size.trim()
+                .parse()
+                .map_err(|_| Error::Parse(format!("Invalid bit size in '{}'", column_type)))?,
+        ),
+        ("character varying", size) if !size.is_empty() => ColumnType::Varchar(
+            size.trim()
+                .parse()
+                .map_err(|_| Error::Parse(format!("Invalid varchar size in '{}'", column_type)))?,
+        ),
+        ("character", size) if !size.is_empty() => ColumnType::Char(
+            size.trim()
+                .parse()
+                .map_err(|_| Error::Parse(format!("Invalid char size in '{}'", column_type)))?,
+        ),
+        ("text", _) => ColumnType::Text,
+        ("bytea", _) => ColumnType::Blob,
+        ("json" | "jsonb", _) => ColumnType::Json,
+        ("uuid", _) => ColumnType::Uuid,
+        ("date", _) => ColumnType::Date,
+        ("time without time zone", _) => ColumnType::Time,
+        ("timestamp with time zone", _) => ColumnType::DateTime(Tz::UTC),
+        ("timestamp without time zone", _) => ColumnType::Timestamp,
+        // PostGIS adds the SRID after the subtype, e.g. "geometry(point,4326)"
+        ("geometry", arguments) => match arguments.split(',').next().unwrap_or_default().trim() {
+            "" | "geometry" => ColumnType::Geometry,
+            "point" => ColumnType::Point,
+            "linestring" => ColumnType::LineString,
+            "polygon" => ColumnType::Polygon,
+            _ => {
+                return Err(Error::unsupported(
+                    "PostgreSQL",
+                    format!("Column type '{}'", column_type),
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::unsupported(
+                "PostgreSQL",
+                format!("Column type '{}'", column_type),
+            ))
+        }
+    };
+
+    Ok(parsed_type)
+}
+
+// Finds the members of a set column in the definitions of the CHECK constraints of its table.
+// The constraint rendered for a set looks like "CHECK ((tags <@ ARRAY['a'::text, 'b'::text]))".
+fn parse_set_members(check_definitions: &[String], column_name: &str) -> Option<Vec<String>> {
+    check_definitions.iter().find_map(|definition| {
+        let (column, array) = definition.split_once(" <@ ARRAY[")?;
+
+        // Quotes are only kept around identifiers that need them
+        let column = column.strip_prefix("CHECK")?.trim_start_matches([' ', '(']);
+        let column = match column.strip_prefix('"').and_then(|c| c.strip_suffix('"')) {
+            Some(quoted) => quoted.replace("\"\"", "\""),
+            None => column.to_string(),
+        };
+        if column != column_name {
+            return None;
+        }
+
+        // The casts between the literals are skipped by parse_value_list
+        let (members, _) = array.split_once(']')?;
+        Some(parse_value_list(members))
+    })
+}
+
+// Converts a default expression like it is returned by pg_get_expr() into the default of a Column.
+// Casts of literals are removed and the literal is unquoted, e.g. "'new'::orders_status" becomes "new".
+fn parse_default(expression: &str) -> String {
+    let expression = expression.trim();
+
+    if let Some(rest) = expression.strip_prefix('\'') {
+        // Find the closing quote, doubled quotes are escaped quotes inside the literal
+        let mut literal = String::new();
+        let mut chars = rest.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' if chars.peek() == Some(&'\'') => {
+                    literal.push('\'');
+                    chars.next();
+                }
+                '\'' => return literal,
+                _ => literal.push(c),
+            }
+        }
+    }
+
+    expression.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::db_mocker::VirtualDatabase;
+
+    fn column(column_name: &str, column_type: ColumnType) -> Column {
+        Column {
+            column_name: column_name.to_string(),
+            column_type,
+            default: None,
+            auto_increment: false,
+            not_null: true,
+            generator: None,
+            constraints: None,
+        }
+    }
+
+    fn orders_table() -> Table {
+        let mut id = column("id", ColumnType::Uint32);
+        id.auto_increment = true;
+        let mut status = column(
+            "status",
+            ColumnType::Enum(vec!["new".to_string(), "done".to_string()]),
+        );
+        status.default = Some("new".to_string());
+        let mut tags = column(
+            "tags",
+            ColumnType::Set(vec!["gift".to_string(), "express".to_string()]),
+        );
+        tags.not_null = false;
+
+        Table {
+            table_name: "orders".to_string(),
+            columns: vec![
+                id,
+                column("customer_id", ColumnType::Int32),
+                column("code", ColumnType::Varchar(20)),
+                status,
+                tags,
+            ],
+            primary_keys: vec!["id".to_string()],
+            foreign_keys: vec![ForeignKey {
+                fk_column: "customer_id".to_string(),
+                origin_table: "customers".to_string(),
+                origin_column: "id".to_string(),
+            }],
+            uniques: vec!["code".to_string()],
+            indices: vec![Index {
+                columns: vec!["customer_id".to_string(), "status".to_string()],
+            }],
+            datasets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn renders_create_table() {
+        assert_eq!(
+            PostgreSQLConnector::render_create_table(&orders_table()),
+            vec![
+                "CREATE TYPE \"orders_status\" AS ENUM ('new', 'done');",
+                "CREATE TABLE \"orders\" (\
+                 \"id\" BIGINT NOT NULL GENERATED BY DEFAULT AS IDENTITY CHECK (\"id\" BETWEEN 0 AND 4294967295), \
+                 \"customer_id\" INTEGER NOT NULL, \
+                 \"code\" VARCHAR(20) NOT NULL, \
+                 \"status\" \"orders_status\" NOT NULL DEFAULT 'new', \
+                 \"tags\" TEXT[] CHECK (\"tags\" <@ ARRAY['gift', 'express']::TEXT[]), \
+                 PRIMARY KEY (\"id\"), \
+                 FOREIGN KEY (\"customer_id\") REFERENCES \"customers\"(\"id\"), \
+                 UNIQUE (\"code\"));",
+                "CREATE INDEX ON \"orders\" (\"customer_id\", \"status\");",
+            ]
+        );
+    }
+
+    #[test]
+    fn widens_types_without_postgres_equivalent() {
+        let table = orders_table();
+        for (column_type, expected) in [
+            (ColumnType::Int8, "SMALLINT"),
+            (ColumnType::Uint8, "SMALLINT"),
+            (ColumnType::Uint16, "INTEGER"),
+            (ColumnType::Uint64, "NUMERIC(20, 0)"),
+            (ColumnType::Year, "SMALLINT"),
+        ] {
+            let column = column("value", column_type);
+            assert_eq!(render_column_type(&table, &column), expected);
+            assert!(value_range(&column).is_some());
+        }
+
+        assert_eq!(
+            value_range(&column("value", ColumnType::Uint8)),
+            Some(("0".to_string(), "255".to_string()))
+        );
+        assert_eq!(value_range(&column("value", ColumnType::Int32)), None);
+    }
+
+    #[test]
+    fn renders_create_role() {
+        let user = User {
+            user_name: "mocker".to_string(),
+            password: "it's secret".to_string(),
+            host: "%".to_string(),
+            user_type: UserType::CRUD,
+        };
+
+        assert_eq!(
+            PostgreSQLConnector::render_create_user(&user, "shop"),
+            vec![
+                "CREATE ROLE \"mocker\" WITH LOGIN PASSWORD 'it''s secret';",
+                "GRANT CONNECT ON DATABASE \"shop\" TO \"mocker\";",
+                "GRANT SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA public TO \"mocker\";",
+                "GRANT USAGE ON ALL SEQUENCES IN SCHEMA public TO \"mocker\";",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_database_with_referenced_tables_first() {
+        let mut customers = orders_table();
+        customers.table_name = "customers".to_string();
+        customers.foreign_keys.clear();
+
+        let vdb = VirtualDatabase {
+            database_name: "shop".to_string(),
+            users: Vec::new(),
+            tables: vec![orders_table(), customers],
+        };
+
+        let statements = PostgreSQLConnector::render_database(&vdb).unwrap();
+        assert_eq!(statements[0], "CREATE DATABASE \"shop\";");
+        assert_eq!(statements[1], "\\connect \"shop\"");
+        assert_eq!(
+            statements[2],
+            "CREATE TYPE \"customers_status\" AS ENUM ('new', 'done');"
+        );
+        assert!(statements[3].starts_with("CREATE TABLE \"customers\""));
+        assert!(statements[6].starts_with("CREATE TABLE \"orders\""));
+    }
+
+    #[test]
+    fn renders_insert_datasets() {
+        let mut table = orders_table();
+        let created_at = Tz::Europe__Berlin
+            .with_ymd_and_hms(2023, 10, 29, 2, 30, 0)
+            .earliest()
+            .unwrap();
+        table.columns.push(column(
+            "created_at",
+            ColumnType::DateTime(Tz::Europe__Berlin),
+        ));
+        table.datasets = vec![vec![
+            Value::Uint32(1),
+            Value::Int32(7),
+            Value::Varchar(r"it's \n".to_string()),
+            Value::Enum("new".to_string()),
+            Value::Set(vec!["gift".to_string(), "express".to_string()]),
+            Value::DateTime(created_at),
+        ]];
+
+        assert_eq!(
+            PostgreSQLConnector::render_insert_datasets(&table).unwrap(),
+            vec![
+                "INSERT INTO \"orders\" (\"id\", \"customer_id\", \"code\", \"status\", \"tags\", \"created_at\") VALUES \
+                 (1, 7, 'it''s \\n', 'new', ARRAY['gift', 'express']::TEXT[], '2023-10-29T02:30:00+02:00');"
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_defaults() {
+        assert_eq!(render_default(&ColumnType::Boolean, "1"), "TRUE");
+        assert_eq!(render_default(&ColumnType::Int32, "42"), "42");
+        assert_eq!(
+            render_default(&ColumnType::Int32, "1; DROP TABLE x"),
+            "'1; DROP TABLE x'"
+        );
+        assert_eq!(
+            render_default(&ColumnType::Date, "CURDATE()"),
+            "CURRENT_DATE"
+        );
+        assert_eq!(
+            render_default(&ColumnType::Set(Vec::new()), "'a,b'"),
+            "ARRAY['a', 'b']::TEXT[]"
+        );
+        assert_eq!(render_default(&ColumnType::Bit(4), "b'0101'"), "B'101'");
+    }
+
+    #[test]
+    fn parses_column_types() {
+        assert!(matches!(
+            parse_column_type("character varying(255)").unwrap(),
+            ColumnType::Varchar(255)
+        ));
+        assert!(matches!(
+            parse_column_type("timestamp(3) with time zone").unwrap(),
+            ColumnType::DateTime(_)
+        ));
+        assert!(matches!(
+            parse_column_type("numeric(20,0)").unwrap(),
+            ColumnType::Uint64
+        ));
+        assert_eq!(
+            parse_column_type("numeric(10,2)").unwrap(),
+            ColumnType::Decimal(10, 2)
+        );
+        assert_eq!(
+            parse_column_type("character(2)").unwrap(),
+            ColumnType::Char(2)
+        );
+        assert_eq!(
+            parse_column_type("timestamp without time zone").unwrap(),
+            ColumnType::Timestamp
+        );
+        assert_eq!(parse_column_type("jsonb").unwrap(), ColumnType::Json);
+        assert_eq!(parse_column_type("uuid").unwrap(), ColumnType::Uuid);
+        assert_eq!(
+            parse_column_type("bit varying(12)").unwrap(),
+            ColumnType::Bit(12)
+        );
+        assert_eq!(
+            parse_column_type("geometry(Point,4326)").unwrap(),
+            ColumnType::Point
+        );
+        assert_eq!(parse_column_type("geometry").unwrap(), ColumnType::Geometry);
+        assert!(parse_column_type("geometry(MultiPoint)").is_err());
+        assert!(parse_column_type("numeric").is_err());
+        assert!(parse_column_type("character varying").is_err());
+    }
+
+    #[test]
+    fn parses_set_members_and_defaults() {
+        let definitions = vec![
+            "CHECK (((id >= 0) AND (id <= 255)))".to_string(),
+            "CHECK ((\"Tags\" <@ ARRAY['gift'::text, 'it''s'::text]))".to_string(),
+        ];
+        assert_eq!(
+            parse_set_members(&definitions, "Tags"),
+            Some(vec!["gift".to_string(), "it's".to_string()])
+        );
+        assert_eq!(parse_set_members(&definitions, "id"), None);
+
+        assert_eq!(parse_default("'it''s'::character varying"), "it's");
+        assert_eq!(parse_default("CURRENT_TIMESTAMP"), "CURRENT_TIMESTAMP");
+    }
+
+    #[test]
+    fn rejects_decimals_that_arent_numbers() {
+        let mut table = orders_table();
+        table.columns = vec![column("total", ColumnType::Decimal(10, 2))];
+        table.datasets = vec![vec![Value::Decimal("-12.50".to_string())]];
+        assert!(PostgreSQLConnector::render_insert_datasets(&table).is_ok());
+
+        table.datasets = vec![vec![Value::Decimal("1); DROP TABLE users; --".to_string())]];
+        assert!(matches!(
+            PostgreSQLConnector::render_insert_datasets(&table),
+            Err(Error::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,1150 @@
+// Thoughts about this code
+
+// SQLite has no server, a database is a single file.
+// create_database() creates the file "database_name.db" inside the directory the address of the ConnectionSettings points to.
+// connect() opens the file of the database of the ConnectionSettings the same way, or the address itself if no database is set.
+// User name and password of the ConnectionSettings aren't used.
+
+// SQLite has no users either.
+// create_user() does nothing and render_create_user() renders no statements, reading users always returns none.
+
+// SQLite only knows the storage classes INTEGER, REAL, TEXT and BLOB.
+// A column gets the affinity of the storage class its declared type maps to, any name is allowed as declared type.
+// So columns are declared with the MySQL type names, which map to the right affinity and keep the ColumnType readable:
+//     TINYINT, SMALLINT UNSIGNED, ... -> INTEGER (contains "INT")
+//     FLOAT, DOUBLE -> REAL
+//     VARCHAR(n) -> TEXT (contains "CHAR")
+//     BOOLEAN, DATE, TIME, DATETIME, YEAR -> NUMERIC
+// Sizes aren't enforced by SQLite, so integer ranges are kept with CHECK constraints.
+// Dates and times are stored as ISO 8601 text, DateTime with the offset of its timezone.
+// Enums and sets are TEXT columns with a CHECK constraint that only allows their members.
+// A set value stores its members separated by commas like in MySQL.
+// Bit values are stored as integers and spatial values as WKT text, e.g. 'POINT(13.4 52.5)'.
+// INTEGER is a signed 64 bit integer and bigger numbers become REAL, which loses digits.
+// So Uint64 and Bit(64) columns only allow values up to 9223372036854775807 and bigger values are rejected.
+// AUTOINCREMENT is only possible on a single INTEGER PRIMARY KEY, other auto increment columns are created without it.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{SqliteConnection, SqlitePool};
+use tracing::warn;
+
+use super::my_sql::{parse_column_type as parse_mysql_column_type, parse_value_list};
+use super::{check_datasets, check_finite_floats, hex_digits, join_insert_rows};
+use super::{ConnectionSettings, DbReader, DbWriter, SqlRenderer};
+use super::{Table, User};
+use crate::db_mocker::error::{Error, Result};
+use crate::db_mocker::validation::table_path;
+use crate::db_mocker::{parse_bit_literal, Column, ColumnType, ForeignKey, Index, Value};
+
+pub struct SQLiteConnector {}
+
+impl SQLiteConnector {
+    // Returns the file of the passed database.
+    // Without a database the address itself is the database file.
+    pub fn database_file(
+        connection_settings: &ConnectionSettings,
+        database_name: Option<&str>,
+    ) -> PathBuf {
+        match database_name {
+            Some(database_name) => {
+                Path::new(&connection_settings.address).join(format!("{}.db", database_name))
+            }
+            None => PathBuf::from(&connection_settings.address),
+        }
+    }
+
+    async fn connect_pool(
+        connection_settings: &ConnectionSettings,
+        file_path: &Path,
+        create: bool,
+    ) -> Result<SqlitePool> {
+        // SQLite only checks foreign keys if they are turned on for the connection
+        let options = SqliteConnectOptions::new()
+            .filename(file_path)
+            .create_if_missing(create)
+            .foreign_keys(true);
+
+        SqlitePoolOptions::new()
+            .max_connections(connection_settings.max_connections)
+            .connect_with(options)
+            .await
+            .map_err(Error::connection(&file_path.display().to_string()))
+    }
+}
+
+#[async_trait]
+impl DbWriter for SQLiteConnector {
+    type C = SqlitePool;
+
+    // Opens the database file of the ConnectionSettings.
+    async fn connect(connection_settings: &ConnectionSettings) -> Result<Self::C> {
+        let file_path =
+            Self::database_file(connection_settings, connection_settings.database.as_deref());
+        Self::connect_pool(connection_settings, &file_path, false).await
+    }
+
+    // Creates a new database file from the passed name in the directory of the ConnectionSettings address.
+    // Returns a new pool pointing to the database.
+    // Fails if the file already exists, like CREATE DATABASE fails for an existing database.
+    async fn create_database(
+        connection_settings: &ConnectionSettings,
+        database_name: &str,
+    ) -> Result<Self::C> {
+        let file_path = Self::database_file(connection_settings, Some(database_name));
+        if file_path.exists() {
+            let file_path = file_path.display().to_string();
+            return Err(Error::Io {
+                source: std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    format!("Database file '{}' already exists", file_path),
+                ),
+                path: file_path,
+            });
+        }
+
+        Self::connect_pool(connection_settings, &file_path, true).await
+    }
+
+    // Creates the table and its indices for the passed Table in the connected database.
+    async fn create_table(pool: &Self::C, table: &Table) -> Result<()> {
+        for sql_statement in Self::render_create_table(table) {
+            sqlx::query(&sql_statement)
+                .execute(pool)
+                .await
+                .map_err(Error::sql(&sql_statement))?;
+        }
+
+        Ok(())
+    }
+
+    // SQLite has no users, so nothing is created.
+    async fn create_user(_pool: &Self::C, user: &User, _database_name: &str) -> Result<()> {
+        warn!(
+            "SQLite has no users, user '{}' is not created",
+            user.user_name
+        );
+
+        Ok(())
+    }
+
+    // Inserts all datasets of the passed Table into the table of the connected database.
+    // The rendered multi row INSERT statements are executed inside one transaction.
+    // SQLite only allows one writer at a time, concurrent inserts wait for each other.
+    async fn insert_datasets(pool: &Self::C, table: &Table) -> Result<()> {
+        let mut transaction = pool.begin().await.map_err(Error::sql("BEGIN"))?;
+        for sql_statement in Self::render_insert_datasets(table)? {
+            // Every statement is different, so caching them as prepared statements would only waste memory
+            sqlx::query(&sql_statement)
+                .persistent(false)
+                .execute(&mut *transaction)
+                .await
+                .map_err(Error::sql(&sql_statement))?;
+        }
+        transaction.commit().await.map_err(Error::sql("COMMIT"))?;
+
+        Ok(())
+    }
+}
+
+impl SqlRenderer for SQLiteConnector {
+    // SQLite has no CREATE DATABASE, the database is the file the statements are executed on.
+    // So only a comment naming the database is rendered.
+    fn render_create_database(database_name: &str) -> String {
+        format!(
+            "-- Database {} is the file these statements are executed on",
+            quote_identifier(database_name)
+        )
+    }
+
+    // SQLite has no USE statement, a connection always stays on its file.
+    // Instead foreign keys are turned on, SQLite only checks them if they are turned on for the connection.
+    fn render_use_database(_database_name: &str) -> String {
+        "PRAGMA foreign_keys = ON;".to_string()
+    }
+
+    // Renders the statements creating the passed Table.
+    // Indices are created after the table, because SQLite has no INDEX section inside CREATE TABLE.
+    fn render_create_table(table: &Table) -> Vec<String> {
+        /*
+            Structure of the statements
+            Every word beginnig with !!! is data given by the user
+            Identifiers are quoted with double quotes and values are quoted as string literals with quote_identifier() and quote_literal()
+            The other words are fixed
+
+            CREATE TABLE "!!!tbl_name" (
+                "!!!col_name" datatype(!!!size) NOT NULL DEFAULT '!!!def_value' CHECK (...),
+                "!!!col_name" INTEGER PRIMARY KEY AUTOINCREMENT,
+                ...
+                PRIMARY KEY ("!!!col_name_1", ...),
+                FOREIGN KEY ("!!!col_name") REFERENCES "!!!foreign_tbl_name"("!!!foreign_col_name"),
+                ...
+                UNIQUE ("!!!col_name"),
+                ...
+            );
+            CREATE INDEX "!!!tbl_name_!!!col_name_1_..._index" ON "!!!tbl_name" ("!!!col_name_1", ...);
+            ...
+        */
+
+        // AUTOINCREMENT only works on a primary key that is the only column of the primary key
+        let rowid_column = match table.primary_keys.as_slice() {
+            [primary_key] => table
+                .columns
+                .iter()
+                .find(|column| column.column_name == *primary_key && column.auto_increment),
+            _ => None,
+        };
+
+        let mut sections = Vec::new();
+
+        // One section per column
+        // "col_name" datatype NOT NULL DEFAULT def_value CHECK (...)
+        for column in table.columns.iter() {
+            let column_name = quote_identifier(&column.column_name);
+
+            let is_rowid =
+                rowid_column.is_some_and(|rowid| rowid.column_name == column.column_name);
+            let mut section = if is_rowid {
+                // The type has to be exactly INTEGER for the column to become the rowid
+                format!("{} INTEGER PRIMARY KEY AUTOINCREMENT", column_name)
+            } else {
+                if column.auto_increment {
+                    warn!(
+                        "Column '{}.{}' can't be AUTOINCREMENT because it isn't the only primary key",
+                        table.table_name, column.column_name
+                    );
+                }
+                format!(
+                    "{} {}",
+                    column_name,
+                    render_column_type(&column.column_type)
+                )
+            };
+
+            if column.not_null {
+                section += " NOT NULL";
+            }
+
+            if let Some(value) = &column.default {
+                section += " DEFAULT ";
+                section += &render_default(&column.column_type, value);
+            }
+
+            if let Some(check) = render_check(column) {
+                section += &format!(" CHECK ({})", check);
+            }
+
+            sections.push(section);
+        }
+
+        if !table.primary_keys.is_empty() && rowid_column.is_none() {
+            sections.push(format!(
+                "PRIMARY KEY ({})",
+                quote_identifier_list(&table.primary_keys)
+            ));
+        }
+
+        for fk in table.foreign_keys.iter() {
+            sections.push(format!(
+                "FOREIGN KEY ({}) REFERENCES {}({})",
+                quote_identifier(&fk.fk_column),
+                quote_identifier(&fk.origin_table),
+                quote_identifier(&fk.origin_column)
+            ));
+        }
+
+        for unique in table.uniques.iter() {
+            sections.push(format!("UNIQUE ({})", quote_identifier(unique)));
+        }
+
+        let mut statements = vec![format!(
+            "CREATE TABLE {} ({});",
+            quote_identifier(&table.table_name),
+            sections.join(", ")
+        )];
+
+        // SQLite needs a name for every index
+        for index in table.indices.iter() {
+            let index_name = format!("{}_{}_index", table.table_name, index.columns.join("_"));
+            statements.push(format!(
+                "CREATE INDEX {} ON {} ({});",
+                quote_identifier(&index_name),
+                quote_identifier(&table.table_name),
+                quote_identifier_list(&index.columns)
+            ));
+        }
+
+        statements
+    }
+
+    // SQLite has no users, so no statements are rendered.
+    fn render_create_user(_user: &User, _database_name: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    // Renders multi row INSERT statements with all datasets of the passed Table.
+    // The values are part of the statements as literals, so the statements can be executed without binding anything.
+    // Each statement is kept below 1 MiB, which is the maximum statement length of older SQLite versions.
+    fn render_insert_datasets(table: &Table) -> Result<Vec<String>> {
+        const MAX_STATEMENT_SIZE: usize = 1000 * 1000;
+
+        // Every dataset needs exactly one value per column
+        check_datasets(table)?;
+        // SQLite has no literal for NaN and would store it as NULL
+        check_finite_floats(table, "SQLite", true)?;
+        check_integer_range(table)?;
+
+        // Statement start shared by all statements
+        // INSERT INTO "tbl_name" ("col_name_1", ...) VALUES
+        let column_names: Vec<String> = table
+            .columns
+            .iter()
+            .map(|column| column.column_name.clone())
+            .collect();
+        let statement_start = format!(
+            "INSERT INTO {} ({}) VALUES ",
+            quote_identifier(&table.table_name),
+            quote_identifier_list(&column_names)
+        );
+
+        let rows: Vec<String> = table
+            .datasets
+            .iter()
+            .map(|dataset| {
+                let values: Vec<String> = dataset.iter().map(render_value).collect();
+                format!("({})", values.join(", "))
+            })
+            .collect();
+
+        Ok(join_insert_rows(
+            &statement_start,
+            rows,
+            MAX_STATEMENT_SIZE,
+            usize::MAX,
+        ))
+    }
+}
+
+// Renders the declared type of a column.
+// The MySQL names are used, SQLite derives the affinity of the column from them.
+fn render_column_type(column_type: &ColumnType) -> String {
+    match column_type {
+        ColumnType::Int8 => "TINYINT".to_string(),
+        ColumnType::Uint8 => "TINYINT UNSIGNED".to_string(),
+        ColumnType::Int16 => "SMALLINT".to_string(),
+        ColumnType::Uint16 => "SMALLINT UNSIGNED".to_string(),
+        ColumnType::Int32 => "INT".to_string(),
+        ColumnType::Uint32 => "INT UNSIGNED".to_string(),
+        ColumnType::Int64 => "BIGINT".to_string(),
+        ColumnType::Uint64 => "BIGINT UNSIGNED".to_string(),
+        ColumnType::Float => "FLOAT".to_string(),
+        ColumnType::Double => "DOUBLE".to_string(),
+        ColumnType::Decimal(precision, scale) => format!("DECIMAL({}, {})", precision, scale),
+        ColumnType::Boolean => "BOOLEAN".to_string(),
+        ColumnType::Bit(size) => format!("BIT({})", size),
+        ColumnType::Char(size) => format!("CHAR({})", size),
+        ColumnType::Varchar(size) => format!("VARCHAR({})", size),
+        ColumnType::Text => "TEXT".to_string(),
+        ColumnType::MediumText => "MEDIUMTEXT".to_string(),
+        ColumnType::LongText => "LONGTEXT".to_string(),
+        ColumnType::Binary(size) => format!("BINARY({})", size),
+        ColumnType::Varbinary(size) => format!("VARBINARY({})", size),
+        ColumnType::Blob => "BLOB".to_string(),
+        ColumnType::Json => "JSON".to_string(),
+        ColumnType::Date => "DATE".to_string(),
+        ColumnType::Time => "TIME".to_string(),
+        ColumnType::DateTime(_) => "DATETIME".to_string(),
+        ColumnType::Timestamp => "TIMESTAMP".to_string(),
+        ColumnType::Year => "YEAR".to_string(),
+        // Like in MySQL the text form of a UUID is stored
+        ColumnType::Uuid => "CHAR(36)".to_string(),
+        ColumnType::Enum(_) | ColumnType::Set(_) => "TEXT".to_string(),
+        ColumnType::Geometry => "GEOMETRY".to_string(),
+        ColumnType::Point => "POINT".to_string(),
+        ColumnType::LineString => "LINESTRING".to_string(),
+        ColumnType::Polygon => "POLYGON".to_string(),
+    }
+}
+
+// Renders the condition of the CHECK constraint that keeps the values of a column in the range of its ColumnType.
+// Returns None if SQLite enforces the range itself.
+fn render_check(column: &Column) -> Option<String> {
+    let column_name = quote_identifier(&column.column_name);
+
+    let (min, max) = match &column.column_type {
+        ColumnType::Int8 => (i8::MIN.to_string(), i8::MAX.to_string()),
+        ColumnType::Uint8 => ("0".to_string(), u8::MAX.to_string()),
+        ColumnType::Int16 => (i16::MIN.to_string(), i16::MAX.to_string()),
+        ColumnType::Uint16 => ("0".to_string(), u16::MAX.to_string()),
+        ColumnType::Int32 => (i32::MIN.to_string(), i32::MAX.to_string()),
+        ColumnType::Uint32 => ("0".to_string(), u32::MAX.to_string()),
+        // INTEGER is a signed 64 bit integer, bigger values would become REAL
+        ColumnType::Uint64 => ("0".to_string(), i64::MAX.to_string()),
+        ColumnType::Boolean => return Some(format!("{} IN (0, 1)", column_name)),
+        ColumnType::Year => ("1901".to_string(), "2155".to_string()),
+        ColumnType::Bit(size) if *size >= 64 => ("0".to_string(), i64::MAX.to_string()),
+        ColumnType::Bit(size) => ("0".to_string(), ((1u64 << size) - 1).to_string()),
+        ColumnType::Enum(values) => {
+            return Some(format!(
+                "{} IN ({})",
+                column_name,
+                quote_literal_list(values)
+            ))
+        }
+        // Every member surrounded by commas is removed from the value surrounded by commas
+        // The commas between the members are doubled first, so every member has its own commas
+        // If only members of the set were in the value nothing remains
+        ColumnType::Set(values) => {
+            let mut remaining = format!("',' || replace({}, ',', ',,') || ','", column_name);
+            for value in values {
+                remaining = format!(
+                    "replace({}, {}, '')",
+                    remaining,
+                    quote_literal(&format!(",{},", value))
+                );
+            }
+            return Some(format!("{} = '' OR {} = ''", column_name, remaining));
+        }
+        _ => return None,
+    };
+
+    Some(format!("{} BETWEEN {} AND {}", column_name, min, max))
+}
+
+// Renders a Value as a SQL literal.
+// Booleans are stored as 0 and 1, dates and times as ISO 8601 text.
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::Int8(value) => value.to_string(),
+        Value::Uint8(value) => value.to_string(),
+        Value::Int16(value) => value.to_string(),
+        Value::Uint16(value) => value.to_string(),
+        Value::Int32(value) => value.to_string(),
+        Value::Uint32(value) => value.to_string(),
+        Value::Int64(value) => value.to_string(),
+        Value::Uint64(value) => value.to_string(),
+        Value::Float(value) => render_float(*value as f64),
+        Value::Double(value) => render_float(*value),
+        Value::Decimal(value) => value.clone(),
+        Value::Boolean(value) => (*value as u8).to_string(),
+        Value::Bit(value) => value.to_string(),
+        Value::Char(value) | Value::Varchar(value) | Value::Text(value) => quote_literal(value),
+        Value::Binary(value) => format!("X'{}'", hex_digits(value)),
+        Value::Json(value) => quote_literal(&value.to_string()),
+        Value::Date(value) => quote_literal(&value.format("%Y-%m-%d").to_string()),
+        Value::Time(value) => quote_literal(&value.format("%H:%M:%S%.f").to_string()),
+        Value::DateTime(value) => quote_literal(&value.to_rfc3339()),
+        Value::Timestamp(value) => quote_literal(&value.format("%Y-%m-%d %H:%M:%S%.f").to_string()),
+        Value::Year(value) => value.to_string(),
+        Value::Uuid(value) => quote_literal(&value.to_string()),
+        Value::Enum(value) => quote_literal(value),
+        // The members of a set are separated by commas like in MySQL
+        Value::Set(members) => quote_literal(&members.join(",")),
+        Value::Geometry(geometry) => quote_literal(&geometry.to_wkt()),
+    }
+}
+
+// Fails if an unsigned value of the datasets doesn't fit into INTEGER.
+// SQLite would store it as REAL and round it.
+fn check_integer_range(table: &Table) -> Result<()> {
+    for (dataset_index, dataset) in table.datasets.iter().enumerate() {
+        for (column, value) in table.columns.iter().zip(dataset.iter()) {
+            let (Value::Uint64(value) | Value::Bit(value)) = value else {
+                continue;
+            };
+            if *value > i64::MAX as u64 {
+                return Err(Error::validation(
+                    format!(
+                        "{}.datasets[{}]",
+                        table_path(&table.table_name),
+                        dataset_index
+                    ),
+                    format!(
+                        "{} of column '{}' is bigger than the maximum integer of SQLite",
+                        value, column.column_name
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Renders a float as a SQL literal.
+// SQLite has no literal for infinity but reads numbers beyond the REAL range as infinite.
+fn render_float(value: f64) -> String {
+    if value.is_infinite() && value > 0.0 {
+        "9e999".to_string()
+    } else if value.is_infinite() {
+        "-9e999".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+// Quotes an identifier like a table or column name with double quotes.
+// Double quotes inside the identifier are escaped by doubling them.
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+// Quotes and joins identifiers for a list like the columns of a key.
+fn quote_identifier_list(identifiers: &[String]) -> String {
+    let quoted: Vec<String> = identifiers
+        .iter()
+        .map(|identifier| quote_identifier(identifier))
+        .collect();
+    quoted.join(", ")
+}
+
+// Quotes a value as a string literal with single quotes.
+// Single quotes are escaped by doubling them, SQLite has no other escape sequences.
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+// Quotes and joins values for a list like the members of an enum.
+fn quote_literal_list(values: &[String]) -> String {
+    let quoted: Vec<String> = values.iter().map(|value| quote_literal(value)).collect();
+    quoted.join(", ")
+}
+
+// Renders the default value of a column for a CREATE TABLE statement.
+// Numbers, NULL and the datetime keywords like CURRENT_TIMESTAMP are kept as they are.
+// MySQL specific defaults are translated, e.g. TRUE for a boolean becomes 1 and CURDATE() becomes CURRENT_DATE.
+// Everything else is quoted as a string literal.
+fn render_default(column_type: &ColumnType, default: &str) -> String {
+    let default = default.trim();
+    let uppercase = default.to_uppercase();
+
+    if uppercase == "NULL" {
+        return uppercase;
+    }
+
+    match column_type {
+        ColumnType::Int8
+        | ColumnType::Uint8
+        | ColumnType::Int16
+        | ColumnType::Uint16
+        | ColumnType::Int32
+        | ColumnType::Uint32
+        | ColumnType::Int64
+        | ColumnType::Uint64
+        | ColumnType::Float
+        | ColumnType::Double
+        | ColumnType::Decimal(_, _)
+        | ColumnType::Year
+            if default.parse::<f64>().is_ok() =>
+        {
+            return default.to_string()
+        }
+        ColumnType::Boolean => match uppercase.as_str() {
+            "TRUE" | "1" => return "1".to_string(),
+            "FALSE" | "0" => return "0".to_string(),
+            _ => {}
+        },
+        // MySQL bit defaults are numbers or literals like b'101'
+        ColumnType::Bit(_) => {
+            if let Some(value) = parse_bit_literal(default) {
+                return value.to_string();
+            }
+        }
+        ColumnType::Date | ColumnType::Time | ColumnType::DateTime(_) | ColumnType::Timestamp => {
+            // Functions aren't allowed as defaults without parentheses, the keywords are
+            match uppercase.split('(').next().unwrap_or_default() {
+                "CURRENT_TIMESTAMP" | "NOW" => return "CURRENT_TIMESTAMP".to_string(),
+                "CURRENT_DATE" | "CURDATE" => return "CURRENT_DATE".to_string(),
+                "CURRENT_TIME" | "CURTIME" => return "CURRENT_TIME".to_string(),
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+
+    quote_literal(&unquote_default(default))
+}
+
+// Removes the quotes around a default that is a string literal.
+// Other defaults are returned as they are.
+fn unquote_default(default: &str) -> String {
+    default
+        .strip_prefix('\'')
+        .and_then(|default| default.strip_suffix('\''))
+        .map(|default| default.replace("''", "'"))
+        .unwrap_or_else(|| default.to_string())
+}
+
+#[async_trait]
+impl DbReader for SQLiteConnector {
+    type C = SqliteConnection;
+
+    // Returns the names of all databases attached to the connection.
+    // The name of a database is the name of its file without extension.
+    async fn get_databases(connection: &mut Self::C) -> Result<Vec<String>> {
+        // Row layout: schema name, file path
+        // The file path is empty for in memory databases
+        let sql_statement =
+            "SELECT name, file FROM pragma_database_list WHERE name <> 'temp' ORDER BY seq;";
+        let database_rows = sqlx::query_as::<_, (String, String)>(sql_statement)
+            .fetch_all(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        Ok(database_rows
+            .into_iter()
+            .map(|(name, file)| attached_database_name(&name, &file))
+            .collect())
+    }
+
+    // Returns the name of the database the connection points to.
+    async fn get_current_database(connection: &mut Self::C) -> Result<String> {
+        let sql_statement = "SELECT file FROM pragma_database_list WHERE name = 'main';";
+        let file = sqlx::query_scalar::<_, String>(sql_statement)
+            .fetch_one(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        Ok(attached_database_name("main", &file))
+    }
+
+    // Returns every table of the passed database with its columns, keys and indices.
+    // The database has to be the one the connection points to.
+    async fn get_database_schema(
+        connection: &mut Self::C,
+        database_name: &str,
+    ) -> Result<Vec<Table>> {
+        let current_database = Self::get_current_database(connection).await?;
+        if current_database != database_name {
+            return Err(Error::unsupported(
+                "SQLite",
+                format!(
+                    "Reading database '{}' over a connection to database '{}'",
+                    database_name, current_database
+                ),
+            ));
+        }
+
+        let mut tables = Vec::new();
+
+        for table_name in Self::get_tables(connection).await? {
+            tables.push(read_table(connection, &table_name).await?);
+        }
+
+        Ok(tables)
+    }
+
+    // Returns the names of all tables of the database the connection points to.
+    // The internal tables of SQLite like sqlite_sequence are left out.
+    async fn get_tables(connection: &mut Self::C) -> Result<Vec<String>> {
+        let sql_statement = "SELECT name FROM sqlite_schema \
+             WHERE type = 'table' AND name NOT LIKE 'sqlite\\_%' ESCAPE '\\' \
+             ORDER BY name;";
+        let table_names = sqlx::query_scalar::<_, String>(sql_statement)
+            .fetch_all(connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+        Ok(table_names)
+    }
+
+    // Returns the passed table of the database the connection points to.
+    async fn get_table_schema(connection: &mut Self::C, table_name: &str) -> Result<Table> {
+        read_table(connection, table_name).await
+    }
+
+    // SQLite has no users, so none are returned.
+    async fn get_users(_connection: &mut Self::C, _database_name: &str) -> Result<Vec<User>> {
+        Ok(Vec::new())
+    }
+}
+
+// Name of a database attached to a connection.
+// The file name without extension is used if the database has a file.
+fn attached_database_name(schema_name: &str, file: &str) -> String {
+    Path::new(file)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| schema_name.to_string())
+}
+
+// Reads the schema of a single table and constructs a Table from it.
+// The datasets of the table are not read, so the returned Table has none.
+async fn read_table(connection: &mut SqliteConnection, table_name: &str) -> Result<Table> {
+    // The CREATE TABLE statement is needed for the CHECK constraints and AUTOINCREMENT
+    // SQLite doesn't offer them in any other way
+    let sql_statement = "SELECT sql FROM sqlite_schema WHERE type = 'table' AND name = ?;";
+    let table_sql = sqlx::query_scalar::<_, String>(sql_statement)
+        .bind(table_name)
+        .fetch_optional(&mut *connection)
+        .await
+        .map_err(Error::sql(sql_statement))?
+        .ok_or_else(|| Error::NotFound(format!("Table '{}' doesn't exist", table_name)))?;
+
+    // Read columns
+    // Row layout: column name, declared type, not null, default, position in primary key
+    // The position in the primary key is 0 for columns that aren't part of it
+    let sql_statement =
+        "SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?) ORDER BY cid;";
+    let column_rows =
+        sqlx::query_as::<_, (String, String, bool, Option<String>, i64)>(sql_statement)
+            .bind(table_name)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(Error::sql(sql_statement))?;
+
+    let mut columns = Vec::new();
+    let mut primary_keys: Vec<(i64, String)> = Vec::new();
+    for (column_name, column_type, not_null, default, primary_key_position) in column_rows {
+        if primary_key_position > 0 {
+            primary_keys.push((primary_key_position, column_name.clone()));
+        }
+
+        columns.push(Column {
+            column_type: parse_column_type(&column_type, &table_sql, &column_name)
+                .map_err(|error| error.in_column(table_name, &column_name))?,
+            default: default.as_deref().map(unquote_default),
+            auto_increment: false,
+            // A rowid column can't be NULL even if it isn't declared NOT NULL
+            not_null: not_null
+                || (column_type.eq_ignore_ascii_case("INTEGER") && primary_key_position > 0),
+            generator: None,
+            constraints: None,
+            column_name,
+        });
+    }
+    primary_keys.sort();
+    let primary_keys: Vec<String> = primary_keys.into_iter().map(|(_, name)| name).collect();
+
+    // Only a single INTEGER PRIMARY KEY can be AUTOINCREMENT
+    if let [primary_key] = primary_keys.as_slice() {
+        if table_sql.to_uppercase().contains("AUTOINCREMENT") {
+            if let Some(column) = columns
+                .iter_mut()
+                .find(|column| column.column_name == *primary_key)
+            {
+                column.auto_increment = true;
+            }
+        }
+    }
+
+    // Read foreign keys
+    // Row layout: column name, referenced table, referenced column
+    // The referenced column is NULL if the foreign key references the primary key implicitly
+    let sql_statement =
+        "SELECT \"from\", \"table\", \"to\" FROM pragma_foreign_key_list(?) ORDER BY id, seq;";
+    let key_rows = sqlx::query_as::<_, (String, String, Option<String>)>(sql_statement)
+        .bind(table_name)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(Error::sql(sql_statement))?;
+
+    let mut foreign_keys = Vec::new();
+    for (fk_column, origin_table, origin_column) in key_rows {
+        foreign_keys.push(ForeignKey {
+            origin_column: origin_column.ok_or_else(|| {
+                Error::unsupported(
+                    "SQLite",
+                    format!(
+                        "Reading foreign key '{}.{}' that doesn't name the column it references",
+                        table_name, fk_column
+                    ),
+                )
+            })?,
+            fk_column,
+            origin_table,
+        });
+    }
+
+    // Read indices
+    // Row layout: index name, is unique, origin
+    // The origin is "pk" for the primary key, "u" for unique constraints and "c" for CREATE INDEX
+    let sql_statement = "SELECT name, \"unique\", origin FROM pragma_index_list(?) ORDER BY name;";
+    let index_rows = sqlx::query_as::<_, (String, bool, String)>(sql_statement)
+        .bind(table_name)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(Error::sql(sql_statement))?;
+
+    let mut uniques = Vec::new();
+    let mut indices = Vec::new();
+    for (index_name, unique, origin) in index_rows {
+        // The primary key is already covered by primary_keys
+        if origin == "pk" {
+            continue;
+        }
+
+        // Indices on expressions have no column name and are left out
+        let sql_statement = "SELECT name FROM pragma_index_info(?) ORDER BY seqno;";
+        let mut index_columns: Vec<String> = sqlx::query_scalar::<_, Option<String>>(sql_statement)
+            .bind(&index_name)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(Error::sql(sql_statement))?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        if unique && index_columns.len() == 1 {
+            uniques.append(&mut index_columns);
+        } else if !index_columns.is_empty() {
+            // Table can only store single column unique constraints
+            // So a unique constraint over multiple columns is kept as a normal index
+            if unique {
+                warn!(
+                    "Unique index '{}' of table '{}' spans multiple columns and is read as a non unique index",
+                    index_name, table_name
+                );
+            }
+            indices.push(Index {
+                columns: index_columns,
+            });
+        }
+    }
+
+    Ok(Table {
+        table_name: table_name.to_string(),
+        columns,
+        primary_keys,
+        foreign_keys,
+        uniques,
+        indices,
+        datasets: Vec::new(),
+    })
+}
+
+// Maps the declared type of a column to a ColumnType.
+// Declared types are the MySQL type names, except for enums and sets which are TEXT columns.
+// Their members are read from the CHECK constraint of the column in the CREATE TABLE statement.
+// A TEXT column without such a constraint is a Text column.
+fn parse_column_type(column_type: &str, table_sql: &str, column_name: &str) -> Result<ColumnType> {
+    // A rowid column has to be declared as INTEGER, which MySQL would read as INT
+    if column_type.eq_ignore_ascii_case("INTEGER") {
+        return Ok(ColumnType::Int64);
+    }
+
+    if !column_type.eq_ignore_ascii_case("TEXT") {
+        return parse_mysql_column_type(column_type).map_err(|error| match error {
+            Error::Unsupported { feature, .. } => Error::unsupported("SQLite", feature),
+            error => error,
+        });
+    }
+
+    let column_name = quote_identifier(column_name);
+    let check = parse_check_literals(table_sql, &format!("CHECK ({} IN (", column_name));
+    if let Some(members) = check {
+        return Ok(ColumnType::Enum(members));
+    }
+
+    // Only the literals with the members surrounded by commas belong to the members of a set
+    let check = parse_check_literals(table_sql, &format!("CHECK ({} = '' OR ", column_name));
+    if let Some(literals) = check {
+        let members = literals
+            .iter()
+            .filter_map(|literal| literal.strip_prefix(',')?.strip_suffix(','))
+            .filter(|member| !member.is_empty())
+            .map(str::to_string)
+            .collect();
+        return Ok(ColumnType::Set(members));
+    }
+
+    Ok(ColumnType::Text)
+}
+
+// Finds the CHECK constraint starting with check_start in the CREATE TABLE statement.
+// Returns the string literals inside the constraint, or None if there is no such constraint.
+fn parse_check_literals(table_sql: &str, check_start: &str) -> Option<Vec<String>> {
+    let start = table_sql.find(check_start)?;
+
+    // The constraint ends with the parenthesis closing "CHECK (", parentheses inside literals don't count
+    let mut depth = 0;
+    let mut in_quotes = false;
+    let mut end = table_sql.len();
+    for (i, c) in table_sql[start..].char_indices() {
+        match c {
+            '\'' => in_quotes = !in_quotes,
+            '(' if !in_quotes => depth += 1,
+            ')' if !in_quotes => {
+                depth -= 1;
+                if depth == 0 {
+                    end = start + i;
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // The literals of the column name are skipped because it is quoted with double quotes
+    Some(parse_value_list(&table_sql[start + check_start.len()..end]))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone};
+    use chrono_tz::Tz;
+
+    use super::*;
+
+    fn column(column_name: &str, column_type: ColumnType) -> Column {
+        Column {
+            column_name: column_name.to_string(),
+            column_type,
+            default: None,
+            auto_increment: false,
+            not_null: true,
+            generator: None,
+            constraints: None,
+        }
+    }
+
+    fn orders_table() -> Table {
+        let mut id = column("id", ColumnType::Uint32);
+        id.auto_increment = true;
+        let mut status = column(
+            "status",
+            ColumnType::Enum(vec!["new".to_string(), "it's done".to_string()]),
+        );
+        status.default = Some("new".to_string());
+        let mut tags = column(
+            "tags",
+            ColumnType::Set(vec!["gift".to_string(), "express".to_string()]),
+        );
+        tags.not_null = false;
+
+        Table {
+            table_name: "orders".to_string(),
+            columns: vec![
+                id,
+                column("code", ColumnType::Varchar(20)),
+                status,
+                tags,
+                column("ordered_on", ColumnType::Date),
+            ],
+            primary_keys: vec!["id".to_string()],
+            foreign_keys: Vec::new(),
+            uniques: vec!["code".to_string()],
+            indices: vec![Index {
+                columns: vec!["status".to_string(), "ordered_on".to_string()],
+            }],
+            datasets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn renders_create_table() {
+        assert_eq!(
+            SQLiteConnector::render_create_table(&orders_table()),
+            vec![
+                "CREATE TABLE \"orders\" (\
+                 \"id\" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL CHECK (\"id\" BETWEEN 0 AND 4294967295), \
+                 \"code\" VARCHAR(20) NOT NULL, \
+                 \"status\" TEXT NOT NULL DEFAULT 'new' CHECK (\"status\" IN ('new', 'it''s done')), \
+                 \"tags\" TEXT CHECK (\"tags\" = '' OR replace(replace(',' || replace(\"tags\", ',', ',,') || ',', ',gift,', ''), ',express,', '') = ''), \
+                 \"ordered_on\" DATE NOT NULL, \
+                 UNIQUE (\"code\"));",
+                "CREATE INDEX \"orders_status_ordered_on_index\" ON \"orders\" (\"status\", \"ordered_on\");",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_no_statements_for_users() {
+        let vdb = crate::db_mocker::VirtualDatabase {
+            database_name: "shop".to_string(),
+            users: vec![User {
+                user_name: "mocker".to_string(),
+                password: "secret".to_string(),
+                host: "%".to_string(),
+                user_type: crate::db_mocker::UserType::CRUD,
+            }],
+            tables: vec![orders_table()],
+        };
+
+        let statements = SQLiteConnector::render_database(&vdb).unwrap();
+        assert_eq!(statements.len(), 4);
+        assert_eq!(statements[1], "PRAGMA foreign_keys = ON;");
+    }
+
+    #[test]
+    fn parses_enum_and_set_members_from_checks() {
+        let table_sql = &SQLiteConnector::render_create_table(&orders_table())[0];
+
+        match parse_column_type("TEXT", table_sql, "status").unwrap() {
+            ColumnType::Enum(values) => assert_eq!(values, vec!["new", "it's done"]),
+            _ => panic!("Expected enum"),
+        }
+        match parse_column_type("TEXT", table_sql, "tags").unwrap() {
+            ColumnType::Set(values) => assert_eq!(values, vec!["gift", "express"]),
+            _ => panic!("Expected set"),
+        }
+        assert_eq!(
+            parse_column_type("TEXT", table_sql, "code").unwrap(),
+            ColumnType::Text
+        );
+    }
+
+    // SQLite only needs a file, so the whole round trip can be tested without a server
+    #[tokio::test]
+    async fn writes_and_reads_back_tables() {
+        let mut table = orders_table();
+        table.datasets = vec![
+            vec![
+                Value::Uint32(1),
+                Value::Varchar("A-1".to_string()),
+                Value::Enum("it's done".to_string()),
+                Value::Set(vec!["gift".to_string(), "express".to_string()]),
+                Value::Date(NaiveDate::from_ymd_opt(2023, 5, 1).unwrap()),
+            ],
+            vec![
+                Value::Uint32(2),
+                Value::Varchar("A-2".to_string()),
+                Value::Enum("new".to_string()),
+                Value::Set(Vec::new()),
+                Value::Date(NaiveDate::from_ymd_opt(2023, 5, 2).unwrap()),
+            ],
+        ];
+
+        let directory = std::env::temp_dir().join(format!("db_mocker_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let connection_settings = ConnectionSettings::new(
+            super::super::DbmsType::SQLite,
+            &directory.display().to_string(),
+            "",
+            "",
+        );
+        let vdb = crate::db_mocker::VirtualDatabase {
+            database_name: "shop".to_string(),
+            users: Vec::new(),
+            tables: vec![table],
+        };
+
+        let pool = SQLiteConnector::write_database(&connection_settings, &vdb)
+            .await
+            .unwrap();
+        let mut connection = pool.acquire().await.unwrap();
+
+        // The database file exists now, so it can't be created again
+        assert!(matches!(
+            SQLiteConnector::create_database(&connection_settings, "shop").await,
+            Err(Error::Io { .. })
+        ));
+
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM orders;")
+            .fetch_one(&mut *connection)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+
+        // Values that aren't members of the set or enum violate the CHECK constraints
+        for statement in [
+            "INSERT INTO orders (code, status, ordered_on) VALUES ('A-3', 'lost', '2023-05-03');",
+            "INSERT INTO orders (code, status, tags, ordered_on) VALUES ('A-3', 'new', 'gift,fragile', '2023-05-03');",
+        ] {
+            assert!(sqlx::query(statement)
+                .execute(&mut *connection)
+                .await
+                .is_err());
+        }
+
+        let read_table = SQLiteConnector::get_table_schema(&mut connection, "orders")
+            .await
+            .unwrap();
+        assert_eq!(read_table.primary_keys, vec!["id"]);
+        assert!(read_table.columns[0].auto_increment);
+        assert!(matches!(
+            read_table.columns[1].column_type,
+            ColumnType::Varchar(20)
+        ));
+        assert!(matches!(
+            read_table.columns[2].column_type,
+            ColumnType::Enum(_)
+        ));
+        assert_eq!(read_table.columns[2].default.as_deref(), Some("new"));
+        assert!(matches!(
+            read_table.columns[3].column_type,
+            ColumnType::Set(_)
+        ));
+        assert_eq!(read_table.uniques, vec!["code"]);
+        assert_eq!(read_table.indices[0].columns, vec!["status", "ordered_on"]);
+        assert_eq!(
+            SQLiteConnector::get_tables(&mut connection).await.unwrap(),
+            vec!["orders"]
+        );
+        assert!(matches!(
+            SQLiteConnector::get_table_schema(&mut connection, "missing").await,
+            Err(Error::NotFound(_))
+        ));
+
+        // A DateTime keeps its offset
+        let datetime = Tz::Europe__Berlin
+            .with_ymd_and_hms(2023, 7, 1, 12, 0, 0)
+            .unwrap();
+        assert_eq!(
+            render_value(&Value::DateTime(datetime)),
+            "'2023-07-01T12:00:00+02:00'"
+        );
+
+        drop(connection);
+        pool.close().await;
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn rejects_decimals_that_arent_numbers() {
+        let mut table = orders_table();
+        table.columns = vec![column("total", ColumnType::Decimal(10, 2))];
+        table.datasets = vec![vec![Value::Decimal("-12.50".to_string())]];
+        assert!(SQLiteConnector::render_insert_datasets(&table).is_ok());
+
+        table.datasets = vec![vec![Value::Decimal("1); DROP TABLE users; --".to_string())]];
+        assert!(matches!(
+            SQLiteConnector::render_insert_datasets(&table),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn renders_infinity_and_rejects_nan() {
+        let mut table = orders_table();
+        table.columns = vec![column("weight", ColumnType::Double)];
+        table.datasets = vec![
+            vec![Value::Double(f64::INFINITY)],
+            vec![Value::Double(f64::NEG_INFINITY)],
+        ];
+        let statements = SQLiteConnector::render_insert_datasets(&table).unwrap();
+        assert!(statements[0].contains("(9e999)"));
+        assert!(statements[0].contains("(-9e999)"));
+
+        table.datasets = vec![vec![Value::Double(f64::NAN)]];
+        assert!(matches!(
+            SQLiteConnector::render_insert_datasets(&table),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_unsigned_values_beyond_integer() {
+        let mut table = orders_table();
+        table.columns = vec![column("amount", ColumnType::Uint64)];
+        table.datasets = vec![vec![Value::Uint64(i64::MAX as u64)]];
+        assert!(SQLiteConnector::render_insert_datasets(&table).is_ok());
+
+        table.datasets = vec![vec![Value::Uint64(u64::MAX)]];
+        assert!(matches!(
+            SQLiteConnector::render_insert_datasets(&table),
+            Err(Error::Validation(_))
+        ));
+
+        table.columns = vec![column("flags", ColumnType::Bit(64))];
+        table.datasets = vec![vec![Value::Bit(u64::MAX)]];
+        assert!(SQLiteConnector::render_insert_datasets(&table).is_err());
+        assert_eq!(
+            render_check(&table.columns[0]).as_deref(),
+            Some("\"flags\" BETWEEN 0 AND 9223372036854775807")
+        );
+    }
+}
@@ -0,0 +1,228 @@
+// Thoughts about this code
+
+// Every fallible function of the library returns this Error, so callers can react to the kind of failure.
+// E.g. a UI can ask for other credentials on a Connection error and show the ErrorList on a Validation error.
+// Errors of sqlx are kept as source, so details like the error code of the DBMS are still available.
+
+// Statements in SqlExecution end up in logs, so they never contain passwords and long statements are cut.
+// E.g. a failed INSERT of a whole table would otherwise repeat all of its datasets.
+
+use thiserror::Error;
+
+use super::validation::ErrorList;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+// Characters of a statement that are kept in a SqlExecution error.
+const MAX_STATEMENT_LENGTH: usize = 1000;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    // The connection to the DBMS couldn't be established
+    #[error("Failed to connect to '{address}'")]
+    Connection {
+        address: String,
+        #[source]
+        source: sqlx::Error,
+    },
+
+    // The DBMS rejected a statement or the connection broke while executing it
+    #[error("Failed to execute statement: {statement}")]
+    SqlExecution {
+        statement: String,
+        #[source]
+        source: sqlx::Error,
+    },
+
+    // A file couldn't be read or written
+    #[error("Failed to access file '{path}'")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    // The content of a file isn't a valid virtual database or the virtual database can't be written in the format
+    #[error("Failed to read or write the virtual database in '{path}'")]
+    Format {
+        path: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    // Text like a .sql file or a column type couldn't be parsed
+    #[error("{0}")]
+    Parse(String),
+
+    // Something the operation needs doesn't exist, e.g. a table in the DBMS
+    #[error("{0}")]
+    NotFound(String),
+
+    // The virtual database breaks rules of the DBMS or of the library
+    #[error("Validation failed: {}", summarize(.0))]
+    Validation(ErrorList),
+
+    // The DBMS or its implementation in this library doesn't support a feature
+    #[error("{feature} is not supported by {dbms}")]
+    Unsupported { dbms: String, feature: String },
+
+    // Dummy data couldn't be generated for a table
+    #[error("{0}")]
+    Generation(String),
+}
+
+impl Error {
+    // Returns a function wrapping the error of sqlx for the statement, meant for map_err().
+    pub(crate) fn sql(statement: &str) -> impl FnOnce(sqlx::Error) -> Error + '_ {
+        move |source| Error::SqlExecution {
+            statement: shorten(statement),
+            source,
+        }
+    }
+
+    // Same as sql() but replaces the secret in the statement with '***'.
+    // The secret is the literal as it appears in the statement, e.g. the quoted password of a CREATE USER statement.
+    pub(crate) fn sql_with_secret<'a>(
+        statement: &'a str,
+        secret: &'a str,
+    ) -> impl FnOnce(sqlx::Error) -> Error + 'a {
+        move |source| Error::SqlExecution {
+            statement: shorten(&statement.replace(secret, "'***'")),
+            source,
+        }
+    }
+
+    // Returns a function wrapping the error of sqlx for the address, meant for map_err().
+    pub(crate) fn connection(address: &str) -> impl FnOnce(sqlx::Error) -> Error + '_ {
+        move |source| Error::Connection {
+            address: address.to_string(),
+            source,
+        }
+    }
+
+    // Returns a function wrapping the IO error for the file, meant for map_err().
+    pub(crate) fn io(path: &str) -> impl FnOnce(std::io::Error) -> Error + '_ {
+        move |source| Error::Io {
+            path: path.to_string(),
+            source,
+        }
+    }
+
+    // Returns a function wrapping the error of serde for the file, meant for map_err().
+    pub(crate) fn format<E>(path: &str) -> impl FnOnce(E) -> Error + '_
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        move |source| Error::Format {
+            path: path.to_string(),
+            source: Box::new(source),
+        }
+    }
+
+    // Creates a validation error with a single message.
+    pub(crate) fn validation(path: impl Into<String>, message: impl Into<String>) -> Error {
+        let mut errors = ErrorList::default();
+        errors.push(path, message);
+        Error::Validation(errors)
+    }
+
+    pub(crate) fn unsupported(dbms: &str, feature: impl Into<String>) -> Error {
+        Error::Unsupported {
+            dbms: dbms.to_string(),
+            feature: feature.into(),
+        }
+    }
+
+    // Adds the column to the message, e.g. for a column type read from a DBMS that couldn't be parsed.
+    pub(crate) fn in_column(self, table_name: &str, column_name: &str) -> Error {
+        match self {
+            Error::Unsupported { dbms, feature } => Error::Unsupported {
+                dbms,
+                feature: format!("{} of column '{}.{}'", feature, table_name, column_name),
+            },
+            Error::Parse(message) => Error::Parse(format!(
+                "{} in column '{}.{}'",
+                message, table_name, column_name
+            )),
+            error => error,
+        }
+    }
+}
+
+// Cuts the statement after MAX_STATEMENT_LENGTH characters.
+fn shorten(statement: &str) -> String {
+    match statement.char_indices().nth(MAX_STATEMENT_LENGTH) {
+        Some((end, _)) => format!(
+            "{}... ({} more characters)",
+            &statement[..end],
+            statement[end..].chars().count()
+        ),
+        None => statement.to_string(),
+    }
+}
+
+// Joins all messages of the ErrorList into one line.
+fn summarize(errors: &ErrorList) -> String {
+    let messages: Vec<String> = errors
+        .errors
+        .iter()
+        .map(|error| error.to_string())
+        .collect();
+    messages.join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_every_validation_message() {
+        let mut errors = ErrorList::default();
+        errors.push("tables[orders]", "Table has no columns");
+        errors.push(
+            "tables[items].columns[id]",
+            "Column name is a reserved word",
+        );
+
+        assert_eq!(
+            Error::Validation(errors).to_string(),
+            "Validation failed: tables[orders]: Table has no columns; \
+             tables[items].columns[id]: Column name is a reserved word"
+        );
+    }
+
+    #[test]
+    fn hides_secrets_and_shortens_statements() {
+        let error = Error::sql_with_secret(
+            "CREATE USER 'mocker'@'%' IDENTIFIED BY 'it''s secret';",
+            "'it''s secret'",
+        )(sqlx::Error::RowNotFound);
+        assert_eq!(
+            error.to_string(),
+            "Failed to execute statement: CREATE USER 'mocker'@'%' IDENTIFIED BY '***';"
+        );
+
+        let statement = format!("INSERT INTO t VALUES {}", "(1),".repeat(500));
+        let error = Error::sql(&statement)(sqlx::Error::RowNotFound);
+        assert!(error
+            .to_string()
+            .ends_with(&format!("... ({} more characters)", statement.len() - 1000)));
+        assert!(error.to_string().len() < 1100);
+    }
+
+    #[test]
+    fn adds_column_to_message() {
+        let error =
+            Error::unsupported("MySQL", "Column type 'multipoint'").in_column("shops", "area");
+        assert_eq!(
+            error.to_string(),
+            "Column type 'multipoint' of column 'shops.area' is not supported by MySQL"
+        );
+
+        let error = Error::Parse("Invalid varchar size".to_string()).in_column("shops", "name");
+        assert_eq!(
+            error.to_string(),
+            "Invalid varchar size in column 'shops.name'"
+        );
+    }
+}
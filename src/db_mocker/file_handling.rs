@@ -1,14 +1,22 @@
+use std::fs;
+
+use serde_json::json;
+
+use super::db_handling::{render_database_dump, DbmsType};
+use super::error::{Error, Result};
+use super::VirtualDatabase;
+
 pub trait FileReader {
-    fn load_vdb_from_file(file_path: &str);
+    fn load_vdb_from_file(file_path: &str) -> Result<VirtualDatabase>;
 }
 
 pub trait FileWriter {
-    fn write_vdb_to_file(file_path: &str);
+    fn write_vdb_to_file(vdb: &VirtualDatabase, file_path: &str) -> Result<()>;
 }
 
 pub enum FileTypeVdb {
     Yaml,
-    // Json,
+    Json,
 }
 
 pub enum FileTypeDatasets {
@@ -28,5 +36,813 @@ pub struct FileSystemSettings {
 pub struct YamlReader {}
 
 impl FileReader for YamlReader {
-    fn load_vdb_from_file(file_path: &str) {}
+    // Reads a .yaml file and parses a VirtualDatabase from it.
+    fn load_vdb_from_file(file_path: &str) -> Result<VirtualDatabase> {
+        let file_content = fs::read_to_string(file_path).map_err(Error::io(file_path))?;
+
+        let vdb = serde_yaml::from_str(&file_content).map_err(Error::format(file_path))?;
+
+        Ok(vdb)
+    }
+}
+
+pub struct YamlWriter {}
+
+impl FileWriter for YamlWriter {
+    // Writes the VirtualDatabase to a .yaml file.
+    // An existing file gets overwritten.
+    fn write_vdb_to_file(vdb: &VirtualDatabase, file_path: &str) -> Result<()> {
+        let file_content = serde_yaml::to_string(vdb).map_err(Error::format(file_path))?;
+
+        fs::write(file_path, file_content).map_err(Error::io(file_path))?;
+
+        Ok(())
+    }
+}
+
+pub struct JsonReader {}
+
+impl FileReader for JsonReader {
+    // Reads a .json file and parses a VirtualDatabase from it.
+    fn load_vdb_from_file(file_path: &str) -> Result<VirtualDatabase> {
+        let file_content = fs::read_to_string(file_path).map_err(Error::io(file_path))?;
+
+        let vdb = serde_json::from_str(&file_content).map_err(Error::format(file_path))?;
+
+        Ok(vdb)
+    }
+}
+
+pub struct JsonWriter {}
+
+impl FileWriter for JsonWriter {
+    // Writes the VirtualDatabase to a .json file.
+    // An existing file gets overwritten.
+    fn write_vdb_to_file(vdb: &VirtualDatabase, file_path: &str) -> Result<()> {
+        let file_content = serde_json::to_string_pretty(vdb).map_err(Error::format(file_path))?;
+
+        fs::write(file_path, file_content).map_err(Error::io(file_path))?;
+
+        Ok(())
+    }
+}
+
+// Writes a .sql file that creates the VirtualDatabase with its tables, datasets and users on the passed DBMS.
+// The file doesn't need this library to be used, e.g. it can be imported with `mysql < dump.sql`.
+// An existing file gets overwritten.
+pub fn export_vdb_to_sql_file(
+    vdb: &VirtualDatabase,
+    dbms_type: &DbmsType,
+    file_path: &str,
+) -> Result<()> {
+    let statements = render_database_dump(vdb, dbms_type)?;
+
+    // A line break in the name would end the comment, the rest of the name would be executed as SQL
+    let database_name = vdb.database_name.replace(['\r', '\n'], " ");
+    let mut file_content = format!(
+        "-- Virtual database `{}` exported by db_mocker\n\n",
+        database_name
+    );
+    for statement in statements {
+        file_content += &statement;
+        file_content += "\n\n";
+    }
+
+    fs::write(file_path, file_content).map_err(Error::io(file_path))?;
+
+    Ok(())
+}
+
+// Writes the JSON Schema of the vdb file format to a file.
+// Editors pick it up when a vdb file references it with a "$schema" key.
+pub fn write_vdb_json_schema_to_file(file_path: &str) -> Result<()> {
+    let file_content =
+        serde_json::to_string_pretty(&vdb_json_schema()).map_err(Error::format(file_path))?;
+
+    fs::write(file_path, file_content).map_err(Error::io(file_path))?;
+
+    Ok(())
+}
+
+// Returns the JSON Schema describing the .json files written by JsonWriter.
+// It has to be kept in sync with the serde representation of VirtualDatabase and its component structs.
+pub fn vdb_json_schema() -> serde_json::Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "VirtualDatabase",
+        "description": "Virtual database of db_mocker",
+        "type": "object",
+        "required": ["database_name", "tables"],
+        "additionalProperties": false,
+        "properties": {
+            "$schema": { "type": "string" },
+            "database_name": { "type": "string" },
+            "users": { "type": "array", "items": { "$ref": "#/$defs/User" } },
+            "tables": { "type": "array", "items": { "$ref": "#/$defs/Table" } }
+        },
+        "$defs": {
+            "User": {
+                "type": "object",
+                "required": ["user_name", "password", "host", "user_type"],
+                "additionalProperties": false,
+                "properties": {
+                    "user_name": { "type": "string" },
+                    "password": { "type": "string" },
+                    "host": { "type": "string" },
+                    "user_type": { "enum": ["Admin", "CRUD"] }
+                }
+            },
+            "Table": {
+                "type": "object",
+                "required": ["table_name", "columns"],
+                "additionalProperties": false,
+                "properties": {
+                    "table_name": { "type": "string" },
+                    "columns": { "type": "array", "items": { "$ref": "#/$defs/Column" } },
+                    "primary_keys": { "type": "array", "items": { "type": "string" } },
+                    "foreign_keys": { "type": "array", "items": { "$ref": "#/$defs/ForeignKey" } },
+                    "uniques": { "type": "array", "items": { "type": "string" } },
+                    "indices": { "type": "array", "items": { "$ref": "#/$defs/Index" } },
+                    "datasets": {
+                        "type": "array",
+                        "items": { "type": "array", "items": { "$ref": "#/$defs/Value" } }
+                    }
+                }
+            },
+            "Column": {
+                "type": "object",
+                "required": ["column_name", "column_type"],
+                "additionalProperties": false,
+                "properties": {
+                    "column_name": { "type": "string" },
+                    "column_type": { "$ref": "#/$defs/ColumnType" },
+                    "default": { "type": ["string", "null"] },
+                    "auto_increment": { "type": "boolean" },
+                    "not_null": { "type": "boolean" },
+                    "generator": { "$ref": "#/$defs/GeneratorKind" },
+                    "constraints": { "$ref": "#/$defs/GenerationConstraints" }
+                }
+            },
+            "GenerationConstraints": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "min": { "type": "number" },
+                    "max": { "type": "number" },
+                    "earliest": {
+                        "type": "string",
+                        "description": "Datetime in UTC, e.g. \"2024-01-31T23:59:59\""
+                    },
+                    "latest": {
+                        "type": "string",
+                        "description": "Datetime in UTC, e.g. \"2024-01-31T23:59:59\""
+                    },
+                    "null_probability": { "type": "number", "minimum": 0, "maximum": 1 },
+                    "pattern": { "type": "string", "format": "regex" },
+                    "weights": {
+                        "type": "object",
+                        "description": "Probability of each enum value",
+                        "additionalProperties": { "type": "number", "minimum": 0, "maximum": 1 }
+                    },
+                    "values": { "type": "array", "items": { "$ref": "#/$defs/Value" } },
+                    "unique": { "type": "boolean" }
+                }
+            },
+            "GeneratorKind": {
+                "enum": [
+                    "Email", "Username", "Password", "FirstName", "LastName", "FullName",
+                    "CompanyName", "Street", "City", "ZipCode", "Country", "CountryCode",
+                    "Phone", "Iban", "Bic", "CreditCardNumber", "CurrencyCode", "IpAddress",
+                    "Sentence", "PastDateTime"
+                ]
+            },
+            "ForeignKey": {
+                "type": "object",
+                "required": ["fk_column", "origin_table", "origin_column"],
+                "additionalProperties": false,
+                "properties": {
+                    "fk_column": { "type": "string" },
+                    "origin_table": { "type": "string" },
+                    "origin_column": { "type": "string" }
+                }
+            },
+            "Index": {
+                "type": "object",
+                "required": ["columns"],
+                "additionalProperties": false,
+                "properties": {
+                    "columns": { "type": "array", "items": { "type": "string" }, "minItems": 1 }
+                }
+            },
+            // Variants without parameters are strings, the others are objects with the variant name as their only key
+            "ColumnType": {
+                "oneOf": [
+                    {
+                        "enum": [
+                            "Int8", "Uint8", "Int16", "Uint16", "Int32", "Uint32", "Int64", "Uint64",
+                            "Float", "Double", "Boolean", "Text", "MediumText", "LongText", "Blob",
+                            "Json", "Date", "Time", "Timestamp", "Year", "Uuid", "Geometry", "Point",
+                            "LineString", "Polygon"
+                        ]
+                    },
+                    variant_schema("Decimal", json!({
+                        "type": "array",
+                        "description": "Precision and scale",
+                        "prefixItems": [
+                            { "type": "integer", "minimum": u8::MIN, "maximum": u8::MAX },
+                            { "type": "integer", "minimum": u8::MIN, "maximum": u8::MAX }
+                        ],
+                        "items": false,
+                        "minItems": 2
+                    })),
+                    variant_schema("Bit", json!({
+                        "type": "integer",
+                        "description": "Number of bits",
+                        "minimum": 1,
+                        "maximum": 64
+                    })),
+                    variant_schema("Char", json!({
+                        "type": "integer",
+                        "description": "Number of characters",
+                        "minimum": u8::MIN,
+                        "maximum": u8::MAX
+                    })),
+                    variant_schema("Varchar", json!({
+                        "type": "integer",
+                        "description": "Maximum number of characters",
+                        "minimum": u16::MIN,
+                        "maximum": u16::MAX
+                    })),
+                    variant_schema("Binary", json!({
+                        "type": "integer",
+                        "description": "Number of bytes",
+                        "minimum": u8::MIN,
+                        "maximum": u8::MAX
+                    })),
+                    variant_schema("Varbinary", json!({
+                        "type": "integer",
+                        "description": "Maximum number of bytes",
+                        "minimum": u16::MIN,
+                        "maximum": u16::MAX
+                    })),
+                    variant_schema("DateTime", json!({
+                        "type": "string",
+                        "description": "IANA timezone, e.g. \"Europe/Berlin\""
+                    })),
+                    variant_schema("Enum", json!({ "type": "array", "items": { "type": "string" } })),
+                    variant_schema("Set", json!({ "type": "array", "items": { "type": "string" } }))
+                ]
+            },
+            // NULL is the string "Null", every other value is an object with the variant name as its only key
+            "Value": {
+                "oneOf": [
+                    { "const": "Null" },
+                    variant_schema("Int8", json!({ "type": "integer", "minimum": i8::MIN, "maximum": i8::MAX })),
+                    variant_schema("Uint8", json!({ "type": "integer", "minimum": u8::MIN, "maximum": u8::MAX })),
+                    variant_schema("Int16", json!({ "type": "integer", "minimum": i16::MIN, "maximum": i16::MAX })),
+                    variant_schema("Uint16", json!({ "type": "integer", "minimum": u16::MIN, "maximum": u16::MAX })),
+                    variant_schema("Int32", json!({ "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX })),
+                    variant_schema("Uint32", json!({ "type": "integer", "minimum": u32::MIN, "maximum": u32::MAX })),
+                    variant_schema("Int64", json!({ "type": "integer", "minimum": i64::MIN, "maximum": i64::MAX })),
+                    variant_schema("Uint64", json!({ "type": "integer", "minimum": u64::MIN, "maximum": u64::MAX })),
+                    variant_schema("Float", json!({ "type": "number" })),
+                    variant_schema("Double", json!({ "type": "number" })),
+                    variant_schema("Decimal", json!({ "type": "string", "pattern": "^-?\\d+(\\.\\d+)?$" })),
+                    variant_schema("Boolean", json!({ "type": "boolean" })),
+                    variant_schema("Bit", json!({ "type": "integer", "minimum": u64::MIN, "maximum": u64::MAX })),
+                    variant_schema("Char", json!({ "type": "string" })),
+                    variant_schema("Varchar", json!({ "type": "string" })),
+                    variant_schema("Text", json!({ "type": "string" })),
+                    variant_schema("Binary", json!({
+                        "type": "array",
+                        "items": { "type": "integer", "minimum": u8::MIN, "maximum": u8::MAX }
+                    })),
+                    // Any JSON value
+                    variant_schema("Json", json!({})),
+                    variant_schema("Date", json!({ "type": "string", "format": "date" })),
+                    variant_schema("Time", json!({
+                        "type": "string",
+                        "pattern": "^\\d{2}:\\d{2}:\\d{2}(\\.\\d+)?$"
+                    })),
+                    variant_schema("DateTime", json!({
+                        "type": "string",
+                        "description": "RFC 3339 datetime followed by an IANA timezone, e.g. \"2023-10-29T02:30:00+01:00 Europe/Berlin\"",
+                        "pattern": "^\\S+ \\S+$"
+                    })),
+                    variant_schema("Timestamp", json!({ "type": "string", "format": "date-time" })),
+                    variant_schema("Year", json!({ "type": "integer", "minimum": u16::MIN, "maximum": u16::MAX })),
+                    variant_schema("Uuid", json!({ "type": "string", "format": "uuid" })),
+                    variant_schema("Enum", json!({ "type": "string" })),
+                    variant_schema("Set", json!({ "type": "array", "items": { "type": "string" } })),
+                    variant_schema("Geometry", json!({
+                        "type": "string",
+                        "description": "WKT of a point, line string or polygon, e.g. \"POINT(13.4 52.5)\"",
+                        "pattern": "^(POINT|LINESTRING|POLYGON)\\("
+                    }))
+                ]
+            }
+        }
+    })
+}
+
+// Schema of a single enum variant with data in serde's externally tagged enum representation.
+fn variant_schema(variant: &str, value_schema: serde_json::Value) -> serde_json::Value {
+    json!({
+        "type": "object",
+        "required": [variant],
+        "additionalProperties": false,
+        "properties": { variant: value_schema }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::TimeZone;
+    use chrono_tz::Tz;
+
+    use super::*;
+    use crate::db_mocker::data_generation::constraints::GenerationConstraints;
+    use crate::db_mocker::data_generation::semantic::GeneratorKind;
+    use crate::db_mocker::geometry::Geometry;
+    use crate::db_mocker::{Column, ColumnType, ForeignKey, Index, Table, User, UserType, Value};
+
+    fn example_vdb() -> VirtualDatabase {
+        VirtualDatabase {
+            database_name: "shop".to_string(),
+            users: vec![User {
+                user_name: "mocker".to_string(),
+                password: "secret".to_string(),
+                host: "%".to_string(),
+                user_type: UserType::CRUD,
+            }],
+            tables: vec![Table {
+                table_name: "orders".to_string(),
+                columns: vec![
+                    Column {
+                        column_name: "id".to_string(),
+                        column_type: ColumnType::Uint32,
+                        default: None,
+                        auto_increment: true,
+                        not_null: true,
+                        generator: None,
+                        constraints: None,
+                    },
+                    Column {
+                        column_name: "created_at".to_string(),
+                        column_type: ColumnType::DateTime(Tz::Europe__Berlin),
+                        default: None,
+                        auto_increment: false,
+                        not_null: false,
+                        generator: Some(GeneratorKind::PastDateTime),
+                        constraints: Some(GenerationConstraints {
+                            null_probability: Some(0.25),
+                            ..Default::default()
+                        }),
+                    },
+                    Column {
+                        column_name: "total".to_string(),
+                        column_type: ColumnType::Decimal(10, 2),
+                        default: Some("0.00".to_string()),
+                        auto_increment: false,
+                        not_null: true,
+                        generator: None,
+                        constraints: Some(GenerationConstraints {
+                            min: Some(5.0),
+                            max: Some(2500.0),
+                            ..Default::default()
+                        }),
+                    },
+                    Column {
+                        column_name: "status".to_string(),
+                        column_type: ColumnType::Enum(vec![
+                            "active".to_string(),
+                            "paused".to_string(),
+                            "closed".to_string(),
+                        ]),
+                        default: None,
+                        auto_increment: false,
+                        not_null: true,
+                        generator: None,
+                        constraints: Some(GenerationConstraints {
+                            weights: BTreeMap::from([("active".to_string(), 0.9)]),
+                            ..Default::default()
+                        }),
+                    },
+                    Column {
+                        column_name: "details".to_string(),
+                        column_type: ColumnType::Json,
+                        default: None,
+                        auto_increment: false,
+                        not_null: false,
+                        generator: None,
+                        constraints: None,
+                    },
+                    Column {
+                        column_name: "delivery_point".to_string(),
+                        column_type: ColumnType::Point,
+                        default: None,
+                        auto_increment: false,
+                        not_null: false,
+                        generator: None,
+                        constraints: None,
+                    },
+                ],
+                primary_keys: vec!["id".to_string()],
+                foreign_keys: vec![ForeignKey {
+                    fk_column: "customer_id".to_string(),
+                    origin_table: "customers".to_string(),
+                    origin_column: "id".to_string(),
+                }],
+                uniques: Vec::new(),
+                indices: vec![Index {
+                    columns: vec!["created_at".to_string()],
+                }],
+                datasets: vec![
+                    vec![
+                        Value::Uint32(1),
+                        Value::DateTime(
+                            Tz::Europe__Berlin
+                                .with_ymd_and_hms(2023, 10, 29, 2, 30, 0)
+                                .latest()
+                                .unwrap(),
+                        ),
+                        Value::Decimal("1234.50".to_string()),
+                        Value::Enum("active".to_string()),
+                        Value::Json(serde_json::json!({ "gift": true, "notes": ["ring twice"] })),
+                        Value::Geometry(Geometry::Point(13.404954, 52.520008)),
+                    ],
+                    vec![
+                        Value::Uint32(u32::MAX),
+                        Value::Null,
+                        Value::Decimal("-0.99".to_string()),
+                        Value::Enum("closed".to_string()),
+                        Value::Null,
+                        Value::Null,
+                    ],
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn yaml_round_trip() {
+        let vdb = example_vdb();
+
+        let file_path = std::env::temp_dir().join("db_mocker_yaml_round_trip.yaml");
+        let file_path = file_path.to_str().unwrap();
+
+        vdb.to_file::<YamlWriter>(file_path).unwrap();
+        let loaded = VirtualDatabase::from_file::<YamlReader>(file_path).unwrap();
+        fs::remove_file(file_path).unwrap();
+
+        assert_eq!(vdb, loaded);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let vdb = example_vdb();
+
+        let file_path = std::env::temp_dir().join("db_mocker_json_round_trip.json");
+        let file_path = file_path.to_str().unwrap();
+
+        vdb.to_file::<JsonWriter>(file_path).unwrap();
+        let loaded = VirtualDatabase::from_file::<JsonReader>(file_path).unwrap();
+        fs::remove_file(file_path).unwrap();
+
+        assert_eq!(vdb, loaded);
+    }
+
+    // Checks if the value is valid for the schema.
+    // Only understands the keywords vdb_json_schema() uses, pattern and format aren't checked.
+    // References are resolved in the $defs of the root schema.
+    fn is_valid(
+        root: &serde_json::Value,
+        schema: &serde_json::Value,
+        value: &serde_json::Value,
+    ) -> bool {
+        let mut schema = schema;
+        while let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.trim_start_matches("#/$defs/");
+            schema = root["$defs"].get(name).unwrap();
+        }
+
+        let has_type = |name: &str| match name {
+            "string" => value.is_string(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "boolean" => value.is_boolean(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            "null" => value.is_null(),
+            _ => false,
+        };
+        let type_matches = match &schema["type"] {
+            serde_json::Value::String(name) => has_type(name),
+            serde_json::Value::Array(names) => {
+                names.iter().any(|name| has_type(name.as_str().unwrap()))
+            }
+            _ => true,
+        };
+        if !type_matches {
+            return false;
+        }
+
+        if let Some(variants) = schema["oneOf"].as_array() {
+            let matching = variants
+                .iter()
+                .filter(|variant| is_valid(root, variant, value));
+            if matching.count() != 1 {
+                return false;
+            }
+        }
+        if let Some(names) = schema["enum"].as_array() {
+            if !names.contains(value) {
+                return false;
+            }
+        }
+        if let Some(constant) = schema.get("const") {
+            if constant != value {
+                return false;
+            }
+        }
+        if let Some(number) = value.as_f64() {
+            let below = schema["minimum"].as_f64().is_some_and(|min| number < min);
+            let above = schema["maximum"].as_f64().is_some_and(|max| number > max);
+            if below || above {
+                return false;
+            }
+        }
+
+        if let Some(items) = value.as_array() {
+            let prefix = schema["prefixItems"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+            let min_items = schema["minItems"].as_u64().unwrap_or(0) as usize;
+            for (i, item) in items.iter().enumerate() {
+                let item_schema = prefix.get(i).unwrap_or(&schema["items"]);
+                let valid = match item_schema {
+                    serde_json::Value::Bool(allowed) => *allowed,
+                    serde_json::Value::Null => true,
+                    item_schema => is_valid(root, item_schema, item),
+                };
+                if !valid {
+                    return false;
+                }
+            }
+            if items.len() < min_items {
+                return false;
+            }
+        }
+
+        if let Some(object) = value.as_object() {
+            if let Some(required) = schema["required"].as_array() {
+                if !required
+                    .iter()
+                    .all(|key| object.contains_key(key.as_str().unwrap()))
+                {
+                    return false;
+                }
+            }
+            for (key, property) in object {
+                let property_schema = match schema["properties"].get(key) {
+                    Some(property_schema) => property_schema,
+                    None => &schema["additionalProperties"],
+                };
+                let valid = match property_schema {
+                    serde_json::Value::Bool(allowed) => *allowed,
+                    serde_json::Value::Null => true,
+                    property_schema => is_valid(root, property_schema, property),
+                };
+                if !valid {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn every_column_type() -> Vec<ColumnType> {
+        vec![
+            ColumnType::Int8,
+            ColumnType::Uint8,
+            ColumnType::Int16,
+            ColumnType::Uint16,
+            ColumnType::Int32,
+            ColumnType::Uint32,
+            ColumnType::Int64,
+            ColumnType::Uint64,
+            ColumnType::Float,
+            ColumnType::Double,
+            ColumnType::Decimal(10, 2),
+            ColumnType::Boolean,
+            ColumnType::Bit(64),
+            ColumnType::Char(36),
+            ColumnType::Varchar(u16::MAX),
+            ColumnType::Text,
+            ColumnType::MediumText,
+            ColumnType::LongText,
+            ColumnType::Binary(16),
+            ColumnType::Varbinary(255),
+            ColumnType::Blob,
+            ColumnType::Json,
+            ColumnType::Date,
+            ColumnType::Time,
+            ColumnType::DateTime(Tz::Europe__Berlin),
+            ColumnType::Timestamp,
+            ColumnType::Year,
+            ColumnType::Uuid,
+            ColumnType::Enum(vec!["new".to_string()]),
+            ColumnType::Set(vec!["a".to_string(), "b".to_string()]),
+            ColumnType::Geometry,
+            ColumnType::Point,
+            ColumnType::LineString,
+            ColumnType::Polygon,
+        ]
+    }
+
+    fn every_value() -> Vec<Value> {
+        let datetime = Tz::Europe__Berlin
+            .with_ymd_and_hms(2023, 10, 29, 2, 30, 0)
+            .earliest()
+            .unwrap();
+        vec![
+            Value::Null,
+            Value::Int8(i8::MIN),
+            Value::Uint8(u8::MAX),
+            Value::Int16(i16::MIN),
+            Value::Uint16(u16::MAX),
+            Value::Int32(i32::MIN),
+            Value::Uint32(u32::MAX),
+            Value::Int64(i64::MIN),
+            Value::Uint64(u64::MAX),
+            Value::Float(1.5),
+            Value::Double(-2.5e300),
+            Value::Decimal("-12.50".to_string()),
+            Value::Boolean(true),
+            Value::Bit(u64::MAX),
+            Value::Char("a".to_string()),
+            Value::Varchar("it's".to_string()),
+            Value::Text("text".to_string()),
+            Value::Binary(vec![0, 255]),
+            Value::Json(serde_json::json!({ "a": [1, null] })),
+            Value::Date(datetime.date_naive()),
+            Value::Time(datetime.time()),
+            Value::DateTime(datetime),
+            Value::Timestamp(datetime.to_utc()),
+            Value::Year(2024),
+            Value::Uuid(uuid::Uuid::nil()),
+            Value::Enum("new".to_string()),
+            Value::Set(vec!["a".to_string()]),
+            Value::Geometry(Geometry::Point(13.4, 52.5)),
+            Value::Geometry(Geometry::LineString(vec![(0.0, 0.0), (1.0, 1.0)])),
+            Value::Geometry(Geometry::Polygon(vec![vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (0.0, 1.0),
+                (0.0, 0.0),
+            ]])),
+        ]
+    }
+
+    #[test]
+    fn json_schema_covers_every_column_type_and_value_variant() {
+        // Doesn't compile if a variant is missing in every_column_type() or every_value()
+        let _ = |column_type: ColumnType| match column_type {
+            ColumnType::Int8
+            | ColumnType::Uint8
+            | ColumnType::Int16
+            | ColumnType::Uint16
+            | ColumnType::Int32
+            | ColumnType::Uint32
+            | ColumnType::Int64
+            | ColumnType::Uint64
+            | ColumnType::Float
+            | ColumnType::Double
+            | ColumnType::Decimal(_, _)
+            | ColumnType::Boolean
+            | ColumnType::Bit(_)
+            | ColumnType::Char(_)
+            | ColumnType::Varchar(_)
+            | ColumnType::Text
+            | ColumnType::MediumText
+            | ColumnType::LongText
+            | ColumnType::Binary(_)
+            | ColumnType::Varbinary(_)
+            | ColumnType::Blob
+            | ColumnType::Json
+            | ColumnType::Date
+            | ColumnType::Time
+            | ColumnType::DateTime(_)
+            | ColumnType::Timestamp
+            | ColumnType::Year
+            | ColumnType::Uuid
+            | ColumnType::Enum(_)
+            | ColumnType::Set(_)
+            | ColumnType::Geometry
+            | ColumnType::Point
+            | ColumnType::LineString
+            | ColumnType::Polygon => (),
+        };
+        let _ = |value: Value| match value {
+            Value::Null
+            | Value::Int8(_)
+            | Value::Uint8(_)
+            | Value::Int16(_)
+            | Value::Uint16(_)
+            | Value::Int32(_)
+            | Value::Uint32(_)
+            | Value::Int64(_)
+            | Value::Uint64(_)
+            | Value::Float(_)
+            | Value::Double(_)
+            | Value::Decimal(_)
+            | Value::Boolean(_)
+            | Value::Bit(_)
+            | Value::Char(_)
+            | Value::Varchar(_)
+            | Value::Text(_)
+            | Value::Binary(_)
+            | Value::Json(_)
+            | Value::Date(_)
+            | Value::Time(_)
+            | Value::DateTime(_)
+            | Value::Timestamp(_)
+            | Value::Year(_)
+            | Value::Uuid(_)
+            | Value::Enum(_)
+            | Value::Set(_)
+            | Value::Geometry(_) => (),
+        };
+
+        let schema = vdb_json_schema();
+        for column_type in every_column_type() {
+            let serialized = serde_json::to_value(&column_type).unwrap();
+            assert!(
+                is_valid(&schema, &schema["$defs"]["ColumnType"], &serialized),
+                "{:?} isn't described by the schema",
+                column_type
+            );
+        }
+        for value in every_value() {
+            let serialized = serde_json::to_value(&value).unwrap();
+            assert!(
+                is_valid(&schema, &schema["$defs"]["Value"], &serialized),
+                "{:?} isn't described by the schema",
+                value
+            );
+        }
+
+        // The example uses the other structs like constraints and users
+        let vdb = serde_json::to_value(example_vdb()).unwrap();
+        assert!(is_valid(&schema, &schema, &vdb));
+        assert!(!is_valid(
+            &schema,
+            &schema["$defs"]["Value"],
+            &serde_json::json!({ "Uint8": 256 })
+        ));
+    }
+
+    #[test]
+    fn sql_export_contains_every_statement() {
+        // The example references a table that isn't part of it
+        let mut vdb = example_vdb();
+        vdb.tables[0].foreign_keys.clear();
+
+        let file_path = std::env::temp_dir().join("db_mocker_sql_export.sql");
+        let file_path = file_path.to_str().unwrap();
+
+        export_vdb_to_sql_file(&vdb, &DbmsType::MySQL, file_path).unwrap();
+        let file_content = fs::read_to_string(file_path).unwrap();
+        fs::remove_file(file_path).unwrap();
+
+        assert!(file_content.contains("CREATE DATABASE `shop`;"));
+        assert!(file_content.contains("CREATE TABLE `orders`"));
+        assert!(file_content.contains("INSERT INTO `orders`"));
+        assert!(file_content.contains("CREATE USER 'mocker'@'%'"));
+    }
+
+    #[test]
+    fn sql_export_header_keeps_the_name_in_the_comment() {
+        let mut vdb = example_vdb();
+        vdb.tables.clear();
+        vdb.users.clear();
+        vdb.database_name = "shop\r\nDROP DATABASE mysql;".to_string();
+
+        let file_path = std::env::temp_dir().join("db_mocker_sql_export_header.sql");
+        let file_path = file_path.to_str().unwrap();
+
+        export_vdb_to_sql_file(&vdb, &DbmsType::MySQL, file_path).unwrap();
+        let file_content = fs::read_to_string(file_path).unwrap();
+        fs::remove_file(file_path).unwrap();
+
+        assert!(file_content.starts_with(
+            "-- Virtual database `shop  DROP DATABASE mysql;` exported by db_mocker\n"
+        ));
+        // The header is a single comment line followed by an empty line
+        assert_eq!(file_content.lines().nth(1), Some(""));
+    }
 }
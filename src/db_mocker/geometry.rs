@@ -0,0 +1,233 @@
+// Thoughts about this code
+
+// Spatial values are kept as their coordinates, so every DBMS can get them in the format it needs.
+// MySQL and PostGIS read WKT (well-known text) with ST_GeomFromText() and MySQL accepts WKB (well-known binary) for bound parameters.
+// Coordinates are plain x and y values without a spatial reference system (SRID 0).
+// For geographic data x is the longitude and y the latitude.
+
+// Files store a Geometry as its WKT, e.g. "POINT(13.4 52.5)".
+// This keeps files readable and YAML can't store an enum inside the enum Value anyway.
+// Rust formats every f64 with the shortest text that parses to the same number, so no precision is lost.
+
+use serde::{Deserialize, Serialize};
+
+use super::error::{Error, Result};
+
+// Spatial value of a Geometry, Point, LineString or Polygon column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Geometry {
+    // x and y
+    Point(f64, f64),
+    // At least two points
+    LineString(Vec<(f64, f64)>),
+    // Rings of at least four points each, the last point of a ring repeats the first one
+    // The first ring is the outer boundary, the others are holes in it
+    Polygon(Vec<Vec<(f64, f64)>>),
+}
+
+// WKB type codes
+const WKB_POINT: u32 = 1;
+const WKB_LINE_STRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+
+impl Geometry {
+    // Checks if the geometry is well-formed, e.g. that the rings of a polygon are closed.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Geometry::Point(x, y) => x.is_finite() && y.is_finite(),
+            Geometry::LineString(points) => points.len() >= 2 && all_finite(points),
+            Geometry::Polygon(rings) => {
+                !rings.is_empty()
+                    && rings.iter().all(|ring| {
+                        ring.len() >= 4 && all_finite(ring) && ring.first() == ring.last()
+                    })
+            }
+        }
+    }
+
+    // Formats the geometry as WKT, e.g. "POINT(13.4 52.5)".
+    pub fn to_wkt(&self) -> String {
+        match self {
+            Geometry::Point(x, y) => format!("POINT({} {})", x, y),
+            Geometry::LineString(points) => format!("LINESTRING({})", wkt_points(points)),
+            Geometry::Polygon(rings) => {
+                let rings: Vec<String> = rings
+                    .iter()
+                    .map(|ring| format!("({})", wkt_points(ring)))
+                    .collect();
+                format!("POLYGON({})", rings.join(", "))
+            }
+        }
+    }
+
+    // Parses WKT like it is written by to_wkt(), the name of the geometry is case insensitive.
+    pub fn from_wkt(wkt: &str) -> Result<Self> {
+        let invalid = || Error::Parse(format!("Invalid WKT '{}'", wkt));
+
+        let (name, body) = wkt.trim().split_once('(').ok_or_else(invalid)?;
+        let body = body.strip_suffix(')').ok_or_else(invalid)?;
+
+        let geometry = match name.trim().to_uppercase().as_str() {
+            "POINT" => {
+                let (x, y) = parse_point(body).ok_or_else(invalid)?;
+                Geometry::Point(x, y)
+            }
+            "LINESTRING" => Geometry::LineString(parse_points(body).ok_or_else(invalid)?),
+            "POLYGON" => {
+                // Every ring is surrounded by parentheses, e.g. "(0 0, 1 0, 1 1, 0 0), (...)"
+                let mut rings = Vec::new();
+                for ring in body.split(')') {
+                    let ring = ring.trim().trim_start_matches(',').trim();
+                    if ring.is_empty() {
+                        continue;
+                    }
+                    let points = ring.strip_prefix('(').ok_or_else(invalid)?;
+                    rings.push(parse_points(points).ok_or_else(invalid)?);
+                }
+                Geometry::Polygon(rings)
+            }
+            _ => return Err(invalid()),
+        };
+
+        if !geometry.is_valid() {
+            return Err(invalid());
+        }
+
+        Ok(geometry)
+    }
+
+    // Encodes the geometry as little endian WKB.
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let mut wkb = vec![1];
+        match self {
+            Geometry::Point(x, y) => {
+                wkb.extend(WKB_POINT.to_le_bytes());
+                wkb_point(&mut wkb, (*x, *y));
+            }
+            Geometry::LineString(points) => {
+                wkb.extend(WKB_LINE_STRING.to_le_bytes());
+                wkb_points(&mut wkb, points);
+            }
+            Geometry::Polygon(rings) => {
+                wkb.extend(WKB_POLYGON.to_le_bytes());
+                wkb.extend((rings.len() as u32).to_le_bytes());
+                for ring in rings {
+                    wkb_points(&mut wkb, ring);
+                }
+            }
+        }
+        wkb
+    }
+}
+
+impl TryFrom<String> for Geometry {
+    type Error = Error;
+
+    fn try_from(wkt: String) -> Result<Self> {
+        Geometry::from_wkt(&wkt)
+    }
+}
+
+impl From<Geometry> for String {
+    fn from(geometry: Geometry) -> Self {
+        geometry.to_wkt()
+    }
+}
+
+// Parses a point like "13.4 52.5".
+fn parse_point(point: &str) -> Option<(f64, f64)> {
+    let mut coordinates = point.split_whitespace().map(str::parse::<f64>);
+    match (coordinates.next(), coordinates.next(), coordinates.next()) {
+        (Some(Ok(x)), Some(Ok(y)), None) => Some((x, y)),
+        _ => None,
+    }
+}
+
+// Parses points separated by commas like "0 0, 1.5 -2".
+fn parse_points(points: &str) -> Option<Vec<(f64, f64)>> {
+    points.split(',').map(parse_point).collect()
+}
+
+fn all_finite(points: &[(f64, f64)]) -> bool {
+    points.iter().all(|(x, y)| x.is_finite() && y.is_finite())
+}
+
+fn wkt_points(points: &[(f64, f64)]) -> String {
+    let points: Vec<String> = points.iter().map(|(x, y)| format!("{} {}", x, y)).collect();
+    points.join(", ")
+}
+
+fn wkb_point(wkb: &mut Vec<u8>, (x, y): (f64, f64)) {
+    wkb.extend(x.to_le_bytes());
+    wkb.extend(y.to_le_bytes());
+}
+
+// Writes the number of points followed by the points.
+fn wkb_points(wkb: &mut Vec<u8>, points: &[(f64, f64)]) {
+    wkb.extend((points.len() as u32).to_le_bytes());
+    for point in points {
+        wkb_point(wkb, *point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Geometry {
+        Geometry::Polygon(vec![vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]])
+    }
+
+    #[test]
+    fn formats_wkt() {
+        assert_eq!(Geometry::Point(13.4, 52.5).to_wkt(), "POINT(13.4 52.5)");
+        assert_eq!(
+            Geometry::LineString(vec![(0.0, 0.0), (1.5, -2.0)]).to_wkt(),
+            "LINESTRING(0 0, 1.5 -2)"
+        );
+        assert_eq!(square().to_wkt(), "POLYGON((0 0, 1 0, 1 1, 0 0))");
+    }
+
+    #[test]
+    fn parses_wkt() {
+        for geometry in [
+            Geometry::Point(13.404954, -52.520008),
+            Geometry::LineString(vec![(0.0, 0.0), (1.5, -2.0), (0.1, 0.2)]),
+            square(),
+        ] {
+            assert_eq!(Geometry::from_wkt(&geometry.to_wkt()).unwrap(), geometry);
+        }
+
+        assert_eq!(
+            Geometry::from_wkt(" point ( 1 2 ) ").unwrap(),
+            Geometry::Point(1.0, 2.0)
+        );
+        assert!(Geometry::from_wkt("POINT(1)").is_err());
+        assert!(Geometry::from_wkt("POLYGON((0 0, 1 0, 1 1, 0 1))").is_err());
+        assert!(Geometry::from_wkt("MULTIPOINT((0 0))").is_err());
+    }
+
+    #[test]
+    fn encodes_wkb() {
+        let wkb = Geometry::Point(1.0, 2.0).to_wkb();
+        let mut expected = vec![1, 1, 0, 0, 0];
+        expected.extend(1.0f64.to_le_bytes());
+        expected.extend(2.0f64.to_le_bytes());
+        assert_eq!(wkb, expected);
+
+        // Byte order, type, ring count, point count and four points
+        assert_eq!(square().to_wkb().len(), 1 + 4 + 4 + 4 + 4 * 16);
+    }
+
+    #[test]
+    fn rejects_open_rings_and_short_line_strings() {
+        assert!(square().is_valid());
+        assert!(
+            !Geometry::Polygon(vec![vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]])
+                .is_valid()
+        );
+        assert!(!Geometry::LineString(vec![(0.0, 0.0)]).is_valid());
+        assert!(!Geometry::Point(f64::NAN, 0.0).is_valid());
+    }
+}
@@ -1,44 +1,163 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, NaiveDate, NaiveTime};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use chrono_tz::Tz;
-use sqlx::Connection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
+use data_generation::constraints::GenerationConstraints;
+use data_generation::semantic::GeneratorKind;
+use db_handling::DbReader;
+use error::{Error, Result};
+use file_handling::{FileReader, FileWriter};
+use geometry::Geometry;
+use validation::{ErrorList, Validator, WarningList};
+
+pub mod data_generation;
 pub mod db_handling;
+pub mod error;
 pub mod file_handling;
+pub mod geometry;
+pub mod sql_import;
+pub mod validation;
 
 pub enum Target {
     Database(db_handling::ConnectionSettings),
     File(file_handling::FileSystemSettings),
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VirtualDatabase {
     pub database_name: String,
+    #[serde(default)]
     pub users: Vec<User>,
     pub tables: Vec<Table>,
 }
 
 impl VirtualDatabase {
-    // pub fn from_database(connection_settings: &ConnectionSettings, database_name: &str) -> Self {
-    pub fn from_database<T: Connection>(connection: &mut T) {
-        // Get schema of the database
+    // Constructs a VirtualDatabase from the database the connection points to.
+    // Users are only read if include_users is set, since reading them requires the privileges to see the grants of the database.
+    // Passwords can't be read from a DBMS, so the users have empty passwords.
+    pub async fn from_database<R: DbReader>(
+        connection: &mut R::C,
+        include_users: bool,
+    ) -> Result<Self> {
+        // Get name of the database
+        let database_name = R::get_current_database(connection).await?;
+
+        // Get every table with it's schema
+        let tables = R::get_database_schema(connection, &database_name).await?;
+
+        // Get users that have privileges on the database
+        let users = if include_users {
+            R::get_users(connection, &database_name).await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(VirtualDatabase {
+            database_name,
+            users,
+            tables,
+        })
+    }
+
+    // Constructs a VirtualDatabase from a file written by the matching FileWriter.
+    pub fn from_file<R: FileReader>(file_path: &str) -> Result<Self> {
+        R::load_vdb_from_file(file_path)
+    }
 
-        // Get list of tables
+    // Stores the VirtualDatabase in a file that can be loaded again with the matching FileReader.
+    pub fn to_file<W: FileWriter>(&self, file_path: &str) -> Result<()> {
+        W::write_vdb_to_file(self, file_path)
+    }
 
-        // For each table get it's schema
+    // Checks the VirtualDatabase against the rules of the Validator's DBMS.
+    // Returns every error and warning found instead of stopping at the first one.
+    pub fn validate<V: Validator>(&self) -> (ErrorList, WarningList) {
+        let mut errors = ErrorList::default();
+        let mut warnings = WarningList::default();
+        V::validate(self, &mut errors, &mut warnings);
 
-        // Construct VirtualDatabase instance from the schemas
+        (errors, warnings)
     }
 
-    // pub fn from_database(connection_settings: &ConnectionSettings, database_name: &str) -> Self {
-    pub fn from_file(file_path: &str) {
-        // Get content of the database
-        // let file_content = file_handling::YamlReader::read_vdb_from_file();
+    // Sorts the tables so that every table comes after the tables its foreign keys reference.
+    // Returns the indices of the tables in that order.
+    // Tables without dependencies between them keep their order from the VirtualDatabase.
+    // Fails if the foreign keys form a cycle or reference a table that doesn't exist.
+    pub fn table_dependency_order(&self) -> Result<Vec<usize>> {
+        let tables = &self.tables;
+
+        // For each table the indices of the other tables it references
+        let mut dependencies = Vec::with_capacity(tables.len());
+        for table in tables {
+            let mut table_dependencies = Vec::new();
+            for fk in table.foreign_keys.iter() {
+                // References to the table itself are resolved while generating the table
+                if fk.origin_table == table.table_name {
+                    continue;
+                }
+
+                let origin_index = tables
+                    .iter()
+                    .position(|origin| origin.table_name == fk.origin_table)
+                    .ok_or_else(|| {
+                        Error::validation(
+                            format!(
+                                "{}.foreign_keys[{}]",
+                                validation::table_path(&table.table_name),
+                                fk.fk_column
+                            ),
+                            format!(
+                                "Foreign key '{}.{}' references table '{}' which doesn't exist",
+                                table.table_name, fk.fk_column, fk.origin_table
+                            ),
+                        )
+                    })?;
+                table_dependencies.push(origin_index);
+            }
+            dependencies.push(table_dependencies);
+        }
+
+        // Repeatedly take the first table whose referenced tables are all already in the order
+        let mut order = Vec::with_capacity(tables.len());
+        let mut ordered = vec![false; tables.len()];
+        while order.len() < tables.len() {
+            let next = (0..tables.len()).find(|&i| {
+                !ordered[i]
+                    && dependencies[i]
+                        .iter()
+                        .all(|&dependency| ordered[dependency])
+            });
+
+            match next {
+                Some(i) => {
+                    ordered[i] = true;
+                    order.push(i);
+                }
+                // Every remaining table waits on another remaining table, so they contain a cycle
+                None => {
+                    let remaining: Vec<&str> = (0..tables.len())
+                        .filter(|&i| !ordered[i])
+                        .map(|i| tables[i].table_name.as_str())
+                        .collect();
+                    return Err(Error::validation(
+                        "tables",
+                        format!(
+                            "Foreign keys form a cycle between the tables: {}",
+                            remaining.join(", ")
+                        ),
+                    ));
+                }
+            }
+        }
 
-        // Parse VirtualDatabase instance from content
+        Ok(order)
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct User {
     pub user_name: String,
     pub password: String,
@@ -46,56 +165,292 @@ pub struct User {
     pub user_type: UserType,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum UserType {
     Admin,
     CRUD,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Table {
     pub table_name: String,
     pub columns: Vec<Column>,
-    pub primary_keys: Vec<String>,     //
-    pub foreign_keys: Vec<ForeignKey>, //
-    pub uniques: Vec<String>,          //
-    pub indices: Vec<Index>,           //
-    pub datasets: Vec<Vec<DataType>>,
+    #[serde(default)]
+    pub primary_keys: Vec<String>,
+    #[serde(default)]
+    pub foreign_keys: Vec<ForeignKey>,
+    #[serde(default)]
+    pub uniques: Vec<String>,
+    #[serde(default)]
+    pub indices: Vec<Index>,
+    // Datasets are optional in files, a table without datasets doesn't write them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub datasets: Vec<Vec<Value>>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Column {
     pub column_name: String,
-    pub datatype: DataType,
-    pub default: Option<String>, //
-    pub auto_increment: bool,    //
-    pub not_null: bool,          //
+    pub column_type: ColumnType,
+    #[serde(default)]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub auto_increment: bool,
+    #[serde(default)]
+    pub not_null: bool,
+    // Generator for the dummy data of this column
+    // If not set the generator is chosen by the column name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generator: Option<GeneratorKind>,
+    // Hints for the dummy data of this column, e.g. a range or the distribution of enum values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<GenerationConstraints>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ForeignKey {
     pub fk_column: String,
     pub origin_table: String,
     pub origin_column: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Index {
     pub columns: Vec<String>,
 }
 
-pub enum DataType {
+// Type of a column together with the parameters the DBMS needs to create the column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ColumnType {
+    Int8,
+    Uint8,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Int64,
+    Uint64,
+    Float,
+    Double,
+    // Precision and scale, the total number of digits and the number of digits after the decimal point
+    Decimal(u8, u8),
+    Boolean,
+    // Number of bits, between 1 and 64
+    Bit(u8),
+    // Number of characters, shorter values are padded with spaces
+    Char(u8),
+    // Maximum number of characters
+    Varchar(u16),
+    // Text of up to 64 KiB, 16 MiB and 4 GiB
+    Text,
+    MediumText,
+    LongText,
+    // Number of bytes, shorter values are padded with zero bytes
+    Binary(u8),
+    // Maximum number of bytes
+    Varbinary(u16),
+    // Bytes of up to 64 KiB
+    Blob,
+    Json,
+    Date,
+    Time,
+    // Timezone the values of the column are generated and read in
+    #[serde(with = "timezone")]
+    DateTime(Tz),
+    // Point in time stored in UTC, MySQL only supports the years 1970 to 2038
+    Timestamp,
+    Year,
+    Uuid,
+    // Allowed values
+    Enum(Vec<String>),
+    // Allowed members
+    Set(Vec<String>),
+    // Any of the spatial types below
+    Geometry,
+    Point,
+    LineString,
+    Polygon,
+}
+
+// Value of a single cell of a dataset.
+// Every variant except Null belongs to the ColumnType of the same name.
+// Text values belong to every text type and Binary values to every binary type, their sizes only differ in the maximum length.
+// Geometry values belong to every spatial type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Value {
+    Null,
     Int8(i8),
-    Uint8(i8),
+    Uint8(u8),
     Int16(i16),
-    Uint16(i16),
+    Uint16(u16),
     Int32(i32),
-    Uint32(i32),
+    Uint32(u32),
     Int64(i64),
-    Uint64(i64),
+    Uint64(u64),
     Float(f32),
     Double(f64),
+    // Decimals are kept as text like "-12.50", so no digit is lost
+    Decimal(String),
     Boolean(bool),
-    Varchar(String, u16),
+    Bit(u64),
+    Char(String),
+    Varchar(String),
+    Text(String),
+    Binary(Vec<u8>),
+    Json(serde_json::Value),
     Date(NaiveDate),
     Time(NaiveTime),
+    #[serde(with = "datetime_tz")]
     DateTime(DateTime<Tz>),
+    Timestamp(DateTime<Utc>),
     Year(u16),
-    Enum(Vec<String>),
+    Uuid(Uuid),
+    Enum(String),
     Set(Vec<String>),
+    Geometry(Geometry),
+}
+
+impl Value {
+    // Checks if the value can be stored in a column of the ColumnType.
+    // NULL fits every ColumnType, whether the column allows it is decided by not_null.
+    pub fn fits(&self, column_type: &ColumnType) -> bool {
+        match (self, column_type) {
+            (Value::Null, _) => true,
+            (Value::Int8(_), ColumnType::Int8)
+            | (Value::Uint8(_), ColumnType::Uint8)
+            | (Value::Int16(_), ColumnType::Int16)
+            | (Value::Uint16(_), ColumnType::Uint16)
+            | (Value::Int32(_), ColumnType::Int32)
+            | (Value::Uint32(_), ColumnType::Uint32)
+            | (Value::Int64(_), ColumnType::Int64)
+            | (Value::Uint64(_), ColumnType::Uint64)
+            | (Value::Float(_), ColumnType::Float)
+            | (Value::Double(_), ColumnType::Double)
+            | (Value::Boolean(_), ColumnType::Boolean)
+            | (Value::Json(_), ColumnType::Json)
+            | (Value::Date(_), ColumnType::Date)
+            | (Value::Time(_), ColumnType::Time)
+            | (Value::DateTime(_), ColumnType::DateTime(_))
+            | (Value::Timestamp(_), ColumnType::Timestamp)
+            | (Value::Year(_), ColumnType::Year)
+            | (Value::Uuid(_), ColumnType::Uuid) => true,
+            (Value::Decimal(value), ColumnType::Decimal(precision, scale)) => {
+                decimal_fits(value, *precision, *scale)
+            }
+            (Value::Bit(value), ColumnType::Bit(size)) => *size >= 64 || *value < 1 << *size,
+            (Value::Char(value), ColumnType::Char(size)) => value.chars().count() <= *size as usize,
+            (Value::Varchar(value), ColumnType::Varchar(size)) => {
+                value.chars().count() <= *size as usize
+            }
+            (Value::Text(value), ColumnType::Text) => value.len() <= u16::MAX as usize,
+            (Value::Text(value), ColumnType::MediumText) => value.len() < 1 << 24,
+            (Value::Text(value), ColumnType::LongText) => value.len() <= u32::MAX as usize,
+            (Value::Binary(value), ColumnType::Binary(size)) => value.len() <= *size as usize,
+            (Value::Binary(value), ColumnType::Varbinary(size)) => value.len() <= *size as usize,
+            (Value::Binary(value), ColumnType::Blob) => value.len() <= u16::MAX as usize,
+            (Value::Enum(value), ColumnType::Enum(values)) => values.contains(value),
+            (Value::Set(members), ColumnType::Set(values)) => {
+                members.iter().all(|member| values.contains(member))
+            }
+            (Value::Geometry(geometry), column_type) => {
+                geometry.is_valid()
+                    && matches!(
+                        (geometry, column_type),
+                        (_, ColumnType::Geometry)
+                            | (Geometry::Point(..), ColumnType::Point)
+                            | (Geometry::LineString(_), ColumnType::LineString)
+                            | (Geometry::Polygon(_), ColumnType::Polygon)
+                    )
+            }
+            _ => false,
+        }
+    }
+}
+
+// Checks if the text is a decimal number like "-12.50" with at most precision digits of which at most scale are after the decimal point.
+pub(crate) fn decimal_fits(value: &str, precision: u8, scale: u8) -> bool {
+    if !is_decimal_literal(value) {
+        return false;
+    }
+
+    let unsigned = value.strip_prefix('-').unwrap_or(value);
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+    // Leading zeros of the integer part don't take up digits
+    let integer_digits = integer.trim_start_matches('0').len();
+    integer_digits + (scale as usize) <= precision as usize && fraction.len() <= scale as usize
+}
+
+// Checks if the text is a decimal number like "-12.50", which every DBMS reads as a numeric literal.
+pub(crate) fn is_decimal_literal(value: &str) -> bool {
+    let unsigned = value.strip_prefix('-').unwrap_or(value);
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+    let only_digits = |digits: &str| digits.chars().all(|c| c.is_ascii_digit());
+    !integer.is_empty() && only_digits(integer) && only_digits(fraction)
+}
+
+// Parses a bit value that is either a number or a bit literal like b'101'.
+pub(crate) fn parse_bit_literal(value: &str) -> Option<u64> {
+    let value = value.trim();
+    match value
+        .strip_prefix("b'")
+        .or_else(|| value.strip_prefix("B'"))
+        .and_then(|digits| digits.strip_suffix('\''))
+    {
+        Some(digits) if !digits.is_empty() => u64::from_str_radix(digits, 2).ok(),
+        Some(_) => None,
+        None => value.parse().ok(),
+    }
+}
+
+// Serde only supports DateTime for fixed offsets, so DateTime<Tz> gets its own format.
+// The datetime is stored as RFC 3339 followed by the name of the timezone, e.g. "2023-10-29T02:30:00+01:00 Europe/Berlin".
+// Storing the offset keeps the datetime unambiguous during daylight saving time changes.
+mod datetime_tz {
+    use chrono::DateTime;
+    use chrono_tz::Tz;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        datetime: &DateTime<Tz>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!(
+            "{} {}",
+            datetime.to_rfc3339(),
+            datetime.timezone().name()
+        ))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Tz>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let (datetime, timezone) = value
+            .split_once(' ')
+            .ok_or_else(|| D::Error::custom(format!("Missing timezone in '{}'", value)))?;
+
+        let timezone: Tz = timezone.parse().map_err(D::Error::custom)?;
+        let datetime = DateTime::parse_from_rfc3339(datetime).map_err(D::Error::custom)?;
+
+        Ok(datetime.with_timezone(&timezone))
+    }
+}
+
+// Serde doesn't support Tz, so it is stored by its name, e.g. "Europe/Berlin".
+mod timezone {
+    use chrono_tz::Tz;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(timezone: &Tz, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(timezone.name())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Tz, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
 }
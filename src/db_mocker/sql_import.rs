@@ -0,0 +1,1081 @@
+// Thoughts about this code
+
+// Parses the CREATE TABLE statements of a .sql file in the MySQL dialect into a VirtualDatabase.
+// This is not a full SQL parser. It only understands what a VirtualDatabase can store.
+// Everything else is collected as unsupported, so the caller can show what got lost.
+
+// Supported statements:
+//     CREATE DATABASE / CREATE SCHEMA -> database_name
+//     USE                             -> database_name if there was no CREATE DATABASE
+//     CREATE TABLE                    -> Table
+// Statements that don't describe the schema like SET, DROP, LOCK TABLES are skipped without reporting them.
+
+// Column attributes that can't be stored, like INVISIBLE, SRID 4326 or GENERATED ALWAYS AS (...), are reported with their values.
+// Only their own tokens are skipped, so a NOT NULL or DEFAULT after them is still parsed.
+// Expressions as defaults or index parts, e.g. DEFAULT (uuid()) or INDEX ((lower(name))), are reported as well.
+
+use std::fs;
+use std::iter::Peekable;
+use std::path::Path;
+use std::vec::IntoIter;
+
+use super::db_handling::my_sql::parse_column_type;
+use super::error::{Error, Result};
+use super::{Column, ForeignKey, Index, Table, VirtualDatabase};
+
+// Result of importing a .sql file.
+pub struct SqlImport {
+    pub vdb: VirtualDatabase,
+    // Description of every construct that was found but can't be stored in the VirtualDatabase
+    pub unsupported: Vec<String>,
+}
+
+// Reads a .sql file and parses a VirtualDatabase from its CREATE TABLE statements.
+// If the file doesn't contain a database name the name of the file without its extension is used.
+pub fn import_vdb_from_sql_file(file_path: &str) -> Result<SqlImport> {
+    let file_content = fs::read_to_string(file_path).map_err(Error::io(file_path))?;
+
+    let mut import = parse_sql(&file_content).map_err(Error::format(file_path))?;
+
+    if import.vdb.database_name.is_empty() {
+        import.vdb.database_name = Path::new(file_path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+    }
+
+    Ok(import)
+}
+
+// Parses a VirtualDatabase from the CREATE TABLE statements of the sql.
+// Fails if the sql can't be tokenized or a CREATE TABLE statement is malformed.
+pub fn parse_sql(sql: &str) -> Result<SqlImport> {
+    let mut import = SqlImport {
+        vdb: VirtualDatabase {
+            database_name: String::new(),
+            users: Vec::new(),
+            tables: Vec::new(),
+        },
+        unsupported: Vec::new(),
+    };
+    let mut has_create_database = false;
+
+    for statement in split_statements(tokenize(sql)?) {
+        let mut tokens = statement.into_iter().peekable();
+        let first = match tokens.peek() {
+            Some(Token::Word(word)) => word.to_uppercase(),
+            Some(token) => {
+                return Err(Error::Parse(format!(
+                    "Unexpected {} at the start of a statement",
+                    token
+                )))
+            }
+            None => continue,
+        };
+
+        match first.as_str() {
+            "CREATE" => {
+                tokens.next();
+                let object = expect_word(&mut tokens)?;
+                match object.as_str() {
+                    "DATABASE" | "SCHEMA" => {
+                        skip_if_not_exists(&mut tokens);
+                        import.vdb.database_name = expect_name(&mut tokens)?;
+                        has_create_database = true;
+                    }
+                    "TABLE" => {
+                        let table = parse_create_table(&mut tokens, &mut import.unsupported)?;
+                        import.vdb.tables.push(table);
+                    }
+                    // CREATE TEMPORARY TABLE and similar variants
+                    _ => import
+                        .unsupported
+                        .push(format!("CREATE {} statement", object)),
+                }
+            }
+            "USE" => {
+                tokens.next();
+                if !has_create_database {
+                    import.vdb.database_name = expect_name(&mut tokens)?;
+                }
+            }
+            // Statements which don't describe the schema
+            "SET" | "DROP" | "LOCK" | "UNLOCK" | "START" | "BEGIN" | "COMMIT" => {}
+            _ => import.unsupported.push(format!("{} statement", first)),
+        }
+    }
+
+    Ok(import)
+}
+
+// Parses a CREATE TABLE statement starting after CREATE TABLE.
+fn parse_create_table(tokens: &mut Tokens, unsupported: &mut Vec<String>) -> Result<Table> {
+    skip_if_not_exists(tokens);
+    let table_name = expect_name(tokens)?;
+
+    let mut table = Table {
+        table_name: table_name.clone(),
+        columns: Vec::new(),
+        primary_keys: Vec::new(),
+        foreign_keys: Vec::new(),
+        uniques: Vec::new(),
+        indices: Vec::new(),
+        datasets: Vec::new(),
+    };
+
+    match tokens.next() {
+        Some(Token::Symbol('(')) => {}
+        Some(Token::Word(word)) if word.eq_ignore_ascii_case("LIKE") => {
+            unsupported.push(format!("CREATE TABLE {} LIKE", table_name));
+            return Ok(table);
+        }
+        Some(token) => {
+            return Err(Error::Parse(format!(
+                "Expected '(' after table '{}' but found {}",
+                table_name, token
+            )))
+        }
+        None => {
+            return Err(Error::Parse(format!(
+                "Statement for table '{}' ends early",
+                table_name
+            )))
+        }
+    }
+
+    // Each definition between the parentheses is either a column, a key or a constraint
+    for definition in split_definitions(tokens)? {
+        let mut definition = definition.into_iter().peekable();
+        parse_definition(&mut table, &mut definition, unsupported)?;
+    }
+
+    // Table options like ENGINE=InnoDB
+    let options = render_tokens(tokens);
+    if !options.is_empty() {
+        unsupported.push(format!("Table options of '{}': {}", table_name, options));
+    }
+
+    Ok(table)
+}
+
+// Parses a single definition of a CREATE TABLE statement and adds it to the table.
+fn parse_definition(
+    table: &mut Table,
+    tokens: &mut Tokens,
+    unsupported: &mut Vec<String>,
+) -> Result<()> {
+    let keyword = match tokens.peek() {
+        Some(Token::Word(word)) => word.to_uppercase(),
+        _ => String::new(),
+    };
+
+    // Constraints can be named, the name isn't stored
+    if keyword == "CONSTRAINT" {
+        tokens.next();
+        if !matches!(tokens.peek(), Some(Token::Word(word)) if is_constraint_keyword(word)) {
+            expect_name(tokens)?;
+        }
+        return parse_definition(table, tokens, unsupported);
+    }
+
+    match keyword.as_str() {
+        "PRIMARY" => {
+            tokens.next();
+            expect_keyword(tokens, "KEY")?;
+            table.primary_keys.append(&mut parse_name_list(tokens)?);
+        }
+        "UNIQUE" => {
+            tokens.next();
+            skip_index_name(tokens);
+            let Some(mut columns) = parse_index_parts(tokens)? else {
+                report_functional_index(table, &keyword, tokens, unsupported);
+                return Ok(());
+            };
+            if columns.len() == 1 {
+                table.uniques.append(&mut columns);
+            } else {
+                unsupported.push(format!(
+                    "Unique constraint over multiple columns in table '{}' is imported as a non unique index",
+                    table.table_name
+                ));
+                table.indices.push(Index { columns });
+            }
+        }
+        "KEY" | "INDEX" => {
+            tokens.next();
+            skip_index_name(tokens);
+            let Some(columns) = parse_index_parts(tokens)? else {
+                report_functional_index(table, &keyword, tokens, unsupported);
+                return Ok(());
+            };
+            table.indices.push(Index { columns });
+        }
+        "FOREIGN" => {
+            tokens.next();
+            expect_keyword(tokens, "KEY")?;
+            skip_index_name(tokens);
+            let fk_columns = parse_name_list(tokens)?;
+            expect_keyword(tokens, "REFERENCES")?;
+            let origin_table = expect_name(tokens)?;
+            let origin_columns = parse_name_list(tokens)?;
+
+            if fk_columns.len() != origin_columns.len() {
+                return Err(Error::Parse(format!(
+                    "Foreign key in table '{}' has {} columns but references {} columns",
+                    table.table_name,
+                    fk_columns.len(),
+                    origin_columns.len()
+                )));
+            }
+
+            // A foreign key over multiple columns is stored as one ForeignKey per column
+            for (fk_column, origin_column) in fk_columns.into_iter().zip(origin_columns) {
+                table.foreign_keys.push(ForeignKey {
+                    fk_column,
+                    origin_table: origin_table.clone(),
+                    origin_column,
+                });
+            }
+        }
+        "FULLTEXT" | "SPATIAL" | "CHECK" => {
+            unsupported.push(format!(
+                "{} in table '{}': {}",
+                keyword,
+                table.table_name,
+                render_tokens(tokens)
+            ));
+            return Ok(());
+        }
+        _ => {
+            if let Some(column) = parse_column(table, tokens, unsupported)? {
+                table.columns.push(column);
+            }
+            return Ok(());
+        }
+    }
+
+    // Options after the columns of a key like ON DELETE CASCADE or USING BTREE
+    let options = render_tokens(tokens);
+    if !options.is_empty() {
+        unsupported.push(format!(
+            "Options of {} in table '{}': {}",
+            keyword, table.table_name, options
+        ));
+    }
+
+    Ok(())
+}
+
+// Parses a column definition.
+// Returns None if the column type isn't supported, the column is reported as unsupported instead.
+// Keys defined inline with the column are added to the table.
+fn parse_column(
+    table: &mut Table,
+    tokens: &mut Tokens,
+    unsupported: &mut Vec<String>,
+) -> Result<Option<Column>> {
+    let column_name = expect_name(tokens)?;
+
+    // Column type with its arguments and attributes, e.g. INT(10) UNSIGNED
+    let mut column_type = expect_word(tokens)?;
+    if tokens.peek() == Some(&Token::Symbol('(')) {
+        tokens.next();
+        let mut arguments = Vec::new();
+        loop {
+            match tokens.next() {
+                Some(Token::Symbol(')')) => break,
+                Some(Token::Literal(value)) => {
+                    arguments.push(format!("'{}'", value.replace('\'', "''")))
+                }
+                Some(Token::Number(value)) => arguments.push(value),
+                Some(Token::Symbol(',')) => {}
+                Some(token) => {
+                    return Err(Error::Parse(format!(
+                        "Unexpected {} in the type of column '{}.{}'",
+                        token, table.table_name, column_name
+                    )))
+                }
+                None => {
+                    return Err(Error::Parse(format!(
+                        "Type of column '{}.{}' isn't closed",
+                        table.table_name, column_name
+                    )))
+                }
+            }
+        }
+        column_type += &format!("({})", arguments.join(","));
+    }
+    while let Some(Token::Word(word)) = tokens.peek() {
+        if !matches!(
+            word.to_uppercase().as_str(),
+            "UNSIGNED" | "SIGNED" | "ZEROFILL"
+        ) {
+            break;
+        }
+        column_type += &format!(" {}", word);
+        tokens.next();
+    }
+
+    let parsed_type = match parse_column_type(&column_type) {
+        Ok(parsed_type) => parsed_type,
+        Err(error) => {
+            unsupported.push(format!(
+                "Column '{}.{}' is skipped: {}",
+                table.table_name, column_name, error
+            ));
+            return Ok(None);
+        }
+    };
+
+    let mut column = Column {
+        column_name: column_name.clone(),
+        column_type: parsed_type,
+        default: None,
+        auto_increment: false,
+        not_null: false,
+        generator: None,
+        constraints: None,
+    };
+
+    // Column attributes
+    while let Some(token) = tokens.next() {
+        let word = match token {
+            Token::Word(word) => word.to_uppercase(),
+            token => {
+                return Err(Error::Parse(format!(
+                    "Unexpected {} in the definition of column '{}.{}'",
+                    token, table.table_name, column_name
+                )))
+            }
+        };
+
+        match word.as_str() {
+            "NOT" => {
+                expect_keyword(tokens, "NULL")?;
+                column.not_null = true;
+            }
+            "NULL" => column.not_null = false,
+            // Expressions like DEFAULT (uuid()) can't be stored
+            "DEFAULT" if tokens.peek() == Some(&Token::Symbol('(')) => {
+                unsupported.push(format!(
+                    "DEFAULT {} of column '{}.{}'",
+                    render(&take_value(tokens)),
+                    table.table_name,
+                    column_name
+                ));
+            }
+            "DEFAULT" => column.default = parse_default(tokens)?,
+            "AUTO_INCREMENT" => column.auto_increment = true,
+            "PRIMARY" => {
+                expect_keyword(tokens, "KEY")?;
+                table.primary_keys.push(column_name.clone());
+            }
+            "UNIQUE" => {
+                if matches!(tokens.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case("KEY"))
+                {
+                    tokens.next();
+                }
+                table.uniques.push(column_name.clone());
+            }
+            // Attributes with a value that isn't stored
+            "COMMENT" | "CHARACTER" | "CHARSET" | "COLLATE" | "ON" => {
+                let mut attribute = word;
+                // CHARACTER SET and ON UPDATE have a second keyword
+                if matches!(attribute.as_str(), "CHARACTER" | "ON") {
+                    attribute += &format!(" {}", expect_word(tokens)?);
+                }
+                // The value of ON UPDATE can have arguments like CURRENT_TIMESTAMP(3)
+                let value = render(&take_value(tokens));
+                unsupported.push(format!(
+                    "{} {} of column '{}.{}'",
+                    attribute, value, table.table_name, column_name
+                ));
+            }
+            // Unknown attributes take every value up to the next known attribute
+            _ => {
+                let mut values = Vec::new();
+                while tokens
+                    .peek()
+                    .is_some_and(|token| !is_column_attribute(token))
+                {
+                    values.append(&mut take_value(tokens));
+                }
+                let attribute = if values.is_empty() {
+                    word
+                } else {
+                    format!("{} {}", word, render(&values))
+                };
+                unsupported.push(format!(
+                    "{} of column '{}.{}'",
+                    attribute, table.table_name, column_name
+                ));
+            }
+        }
+    }
+
+    Ok(Some(column))
+}
+
+// Parses the value after DEFAULT.
+// DEFAULT NULL is the same as no default, so it returns None.
+fn parse_default(tokens: &mut Tokens) -> Result<Option<String>> {
+    let default = match tokens.next() {
+        Some(Token::Literal(value)) => value,
+        // Bit literals are kept like b'101', which Bit columns understand
+        Some(token @ Token::BitLiteral(_)) => token.to_string(),
+        // Hexadecimal literals are turned into numbers if they fit
+        Some(Token::HexLiteral(digits)) => hex_default(&digits),
+        Some(Token::Number(value)) => match value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+        {
+            Some(digits) => hex_default(digits),
+            None => value,
+        },
+        // Negative numbers
+        Some(Token::Symbol('-')) => match tokens.next() {
+            Some(Token::Number(value)) => format!("-{}", value),
+            _ => {
+                return Err(Error::Parse(
+                    "Expected a number after '-' in DEFAULT".to_string(),
+                ))
+            }
+        },
+        Some(Token::Word(word)) if word.eq_ignore_ascii_case("NULL") => return Ok(None),
+        // Functions like CURRENT_TIMESTAMP or CURRENT_TIMESTAMP(3)
+        Some(Token::Word(word)) => {
+            let mut default = word;
+            if tokens.peek() == Some(&Token::Symbol('(')) {
+                tokens.next();
+                let mut arguments = Vec::new();
+                loop {
+                    match tokens.next() {
+                        Some(Token::Symbol(')')) => break,
+                        Some(token) => arguments.push(token.to_string()),
+                        None => {
+                            return Err(Error::Parse(format!("Default '{}' isn't closed", default)))
+                        }
+                    }
+                }
+                default += &format!("({})", arguments.join(""));
+            }
+            default
+        }
+        Some(token) => return Err(Error::Parse(format!("Unexpected {} after DEFAULT", token))),
+        None => return Err(Error::Parse("Missing value after DEFAULT".to_string())),
+    };
+
+    Ok(Some(default))
+}
+
+// Turns the digits of a hexadecimal literal into a decimal number.
+// Literals that don't fit into 64 bit are kept like x'ff'.
+fn hex_default(digits: &str) -> String {
+    match u64::from_str_radix(digits, 16) {
+        Ok(value) => value.to_string(),
+        Err(_) => format!("x'{}'", digits),
+    }
+}
+
+// Returns if the token starts a column attribute that parse_column understands.
+fn is_column_attribute(token: &Token) -> bool {
+    match token {
+        Token::Word(word) => matches!(
+            word.to_uppercase().as_str(),
+            "NOT"
+                | "NULL"
+                | "DEFAULT"
+                | "AUTO_INCREMENT"
+                | "PRIMARY"
+                | "UNIQUE"
+                | "COMMENT"
+                | "CHARACTER"
+                | "CHARSET"
+                | "COLLATE"
+                | "ON"
+        ),
+        _ => false,
+    }
+}
+
+// Token of a sql statement.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    // Keywords and unquoted names
+    Word(String),
+    // Names quoted with backticks or double quotes
+    QuotedName(String),
+    // String literal with its escapes resolved
+    Literal(String),
+    // Digits of a bit literal like b'101'
+    BitLiteral(String),
+    // Digits of a hexadecimal literal like x'ff'
+    HexLiteral(String),
+    Number(String),
+    Symbol(char),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "{}", word),
+            Token::QuotedName(name) => write!(f, "`{}`", name.replace('`', "``")),
+            Token::Literal(value) => write!(f, "'{}'", value.replace('\'', "''")),
+            Token::BitLiteral(digits) => write!(f, "b'{}'", digits),
+            Token::HexLiteral(digits) => write!(f, "x'{}'", digits),
+            Token::Number(value) => write!(f, "{}", value),
+            Token::Symbol(symbol) => write!(f, "{}", symbol),
+        }
+    }
+}
+
+type Tokens = Peekable<IntoIter<Token>>;
+
+// Splits the sql into tokens.
+// Comments are dropped, including MySQL's conditional comments /*! ... */.
+fn tokenize(sql: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            _ if c.is_whitespace() => {
+                chars.next();
+            }
+            // Line comments
+            '#' => {
+                chars.by_ref().take_while(|&c| c != '\n').for_each(drop);
+            }
+            '-' if sql_rest_starts_with(&chars, "--") => {
+                chars.by_ref().take_while(|&c| c != '\n').for_each(drop);
+            }
+            // Block comments
+            '/' if sql_rest_starts_with(&chars, "/*") => {
+                chars.next();
+                chars.next();
+                let mut previous = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(c) => previous = c,
+                        None => return Err(Error::Parse("Block comment isn't closed".to_string())),
+                    }
+                }
+            }
+            '`' | '"' => {
+                chars.next();
+                tokens.push(Token::QuotedName(read_quoted(&mut chars, c)?));
+            }
+            '\'' => {
+                chars.next();
+                tokens.push(Token::Literal(read_quoted(&mut chars, c)?));
+            }
+            // Bit and hexadecimal literals like b'101' and x'ff'
+            'b' | 'B' | 'x' | 'X' if sql_rest_starts_with(&chars, &format!("{}'", c)) => {
+                chars.next();
+                chars.next();
+                let digits = read_quoted(&mut chars, '\'')?;
+                match c {
+                    'b' | 'B' => tokens.push(Token::BitLiteral(digits)),
+                    _ => tokens.push(Token::HexLiteral(digits)),
+                }
+            }
+            _ if c.is_ascii_digit() => {
+                let mut number = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '.') {
+                        break;
+                    }
+                    number.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Number(number));
+            }
+            _ if c.is_alphanumeric() || c == '_' || c == '$' || c == '@' => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '$' || c == '@') {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+            _ => {
+                chars.next();
+                tokens.push(Token::Symbol(c));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+// Checks if the remaining characters start with the pattern without consuming them.
+fn sql_rest_starts_with(chars: &Peekable<std::str::Chars>, pattern: &str) -> bool {
+    chars.clone().take(pattern.len()).eq(pattern.chars())
+}
+
+// Reads a quoted name or literal after its opening quote until the closing quote.
+// A doubled quote is an escaped quote. For literals backslash escapes are resolved as well.
+fn read_quoted(chars: &mut Peekable<std::str::Chars>, quote: char) -> Result<String> {
+    let mut value = String::new();
+
+    loop {
+        match chars.next() {
+            Some(c) if c == quote => {
+                if chars.peek() == Some(&quote) {
+                    value.push(quote);
+                    chars.next();
+                } else {
+                    return Ok(value);
+                }
+            }
+            Some('\\') if quote == '\'' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some('0') => value.push('\0'),
+                Some('Z') => value.push('\x1a'),
+                Some(c) => value.push(c),
+                None => return Err(Error::Parse("Literal isn't closed".to_string())),
+            },
+            Some(c) => value.push(c),
+            None => {
+                return Err(Error::Parse(format!(
+                    "Quoted value starting with {} isn't closed",
+                    quote
+                )))
+            }
+        }
+    }
+}
+
+// Splits the tokens into statements at each ';'.
+fn split_statements(tokens: Vec<Token>) -> Vec<Vec<Token>> {
+    tokens
+        .split(|token| *token == Token::Symbol(';'))
+        .filter(|statement| !statement.is_empty())
+        .map(|statement| statement.to_vec())
+        .collect()
+}
+
+// Splits the definitions between the parentheses of a CREATE TABLE statement at the commas outside of nested parentheses.
+// Consumes the tokens up to and including the closing parenthesis.
+fn split_definitions(tokens: &mut Tokens) -> Result<Vec<Vec<Token>>> {
+    let mut definitions = Vec::new();
+    let mut definition = Vec::new();
+    let mut depth = 0;
+
+    loop {
+        match tokens.next() {
+            Some(Token::Symbol(')')) if depth == 0 => break,
+            Some(Token::Symbol(',')) if depth == 0 => {
+                definitions.push(std::mem::take(&mut definition));
+            }
+            Some(token) => {
+                match token {
+                    Token::Symbol('(') => depth += 1,
+                    Token::Symbol(')') => depth -= 1,
+                    _ => {}
+                }
+                definition.push(token);
+            }
+            None => {
+                return Err(Error::Parse(
+                    "Definitions of the table aren't closed".to_string(),
+                ))
+            }
+        }
+    }
+    if !definition.is_empty() {
+        definitions.push(definition);
+    }
+
+    Ok(definitions)
+}
+
+// Renders the remaining tokens as text to describe them, consuming them.
+fn render_tokens(tokens: &mut Tokens) -> String {
+    render(&tokens.collect::<Vec<Token>>())
+}
+
+// Renders tokens as text to describe them.
+fn render(tokens: &[Token]) -> String {
+    let rendered: Vec<String> = tokens.iter().map(|token| token.to_string()).collect();
+    rendered.join(" ")
+}
+
+// Takes a single value like 4326, 'text', a parenthesized expression or a function call like CURRENT_TIMESTAMP(3).
+// Nested parentheses are taken up to their balanced closing parenthesis.
+fn take_value(tokens: &mut Tokens) -> Vec<Token> {
+    let mut value = Vec::new();
+    let mut depth = 0;
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth -= 1,
+            _ => {}
+        }
+        value.push(token);
+
+        // A word directly followed by parentheses is a function call
+        let is_call = depth == 0
+            && matches!(value.last(), Some(Token::Word(_)))
+            && tokens.peek() == Some(&Token::Symbol('('));
+        if depth <= 0 && !is_call {
+            break;
+        }
+    }
+
+    value
+}
+
+// Parses the parts of an index like parse_name_list.
+// Returns None if one of the parts is an expression like ((lower(name))), those indices can't be stored.
+fn parse_index_parts(tokens: &mut Tokens) -> Result<Option<Vec<String>>> {
+    let mut parts = tokens.clone();
+    if parts.next() == Some(Token::Symbol('(')) {
+        while let Some(token) = parts.next() {
+            match token {
+                // Parentheses that don't follow a name start an expression
+                Token::Symbol('(') => return Ok(None),
+                Token::Symbol(')') => break,
+                // Prefix length like `name`(10)
+                Token::Word(_) | Token::QuotedName(_)
+                    if parts.peek() == Some(&Token::Symbol('(')) =>
+                {
+                    parts.next();
+                    parts.next();
+                    parts.next();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    parse_name_list(tokens).map(Some)
+}
+
+// Reports an index with an expression part as unsupported, consuming its remaining tokens.
+fn report_functional_index(
+    table: &Table,
+    keyword: &str,
+    tokens: &mut Tokens,
+    unsupported: &mut Vec<String>,
+) {
+    unsupported.push(format!(
+        "{} over an expression in table '{}': {}",
+        keyword,
+        table.table_name,
+        render_tokens(tokens)
+    ));
+}
+
+// Parses a parenthesized list of names like the columns of a key.
+// Prefix lengths of index columns like `name`(10) are dropped.
+fn parse_name_list(tokens: &mut Tokens) -> Result<Vec<String>> {
+    match tokens.next() {
+        Some(Token::Symbol('(')) => {}
+        Some(token) => return Err(Error::Parse(format!("Expected '(' but found {}", token))),
+        None => {
+            return Err(Error::Parse(
+                "Expected '(' but the statement ended".to_string(),
+            ))
+        }
+    }
+
+    let mut names = Vec::new();
+    loop {
+        match tokens.next() {
+            Some(Token::Symbol(')')) => return Ok(names),
+            Some(Token::Symbol(',')) => {}
+            Some(Token::Word(name)) | Some(Token::QuotedName(name)) => {
+                names.push(name);
+                // Prefix length and sort order of index columns
+                if tokens.peek() == Some(&Token::Symbol('(')) {
+                    tokens.next();
+                    tokens.next();
+                    tokens.next();
+                }
+                if matches!(tokens.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case("ASC") || word.eq_ignore_ascii_case("DESC"))
+                {
+                    tokens.next();
+                }
+            }
+            Some(token) => {
+                return Err(Error::Parse(format!(
+                    "Unexpected {} in a list of names",
+                    token
+                )))
+            }
+            None => return Err(Error::Parse("List of names isn't closed".to_string())),
+        }
+    }
+}
+
+// Reads a name that may be qualified with a database name like `shop`.`orders`.
+// Only the last part is returned.
+fn expect_name(tokens: &mut Tokens) -> Result<String> {
+    let mut name = match tokens.next() {
+        Some(Token::Word(name)) | Some(Token::QuotedName(name)) => name,
+        Some(token) => return Err(Error::Parse(format!("Expected a name but found {}", token))),
+        None => {
+            return Err(Error::Parse(
+                "Expected a name but the statement ended".to_string(),
+            ))
+        }
+    };
+
+    while tokens.peek() == Some(&Token::Symbol('.')) {
+        tokens.next();
+        name = match tokens.next() {
+            Some(Token::Word(name)) | Some(Token::QuotedName(name)) => name,
+            _ => return Err(Error::Parse(format!("Expected a name after '{}.'", name))),
+        };
+    }
+
+    Ok(name)
+}
+
+// Reads a keyword and returns it in uppercase.
+fn expect_word(tokens: &mut Tokens) -> Result<String> {
+    match tokens.next() {
+        Some(Token::Word(word)) => Ok(word.to_uppercase()),
+        Some(token) => Err(Error::Parse(format!(
+            "Expected a keyword but found {}",
+            token
+        ))),
+        None => Err(Error::Parse(
+            "Expected a keyword but the statement ended".to_string(),
+        )),
+    }
+}
+
+// Reads the passed keyword and fails if the next token is something else.
+fn expect_keyword(tokens: &mut Tokens, keyword: &str) -> Result<()> {
+    let word = expect_word(tokens)?;
+    if word != keyword {
+        return Err(Error::Parse(format!(
+            "Expected {} but found {}",
+            keyword, word
+        )));
+    }
+    Ok(())
+}
+
+// Skips IF NOT EXISTS.
+fn skip_if_not_exists(tokens: &mut Tokens) {
+    if matches!(tokens.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case("IF")) {
+        tokens.next();
+        tokens.next();
+        tokens.next();
+    }
+}
+
+// Skips the optional KEY or INDEX keyword and the name of an index before its column list.
+fn skip_index_name(tokens: &mut Tokens) {
+    while !matches!(tokens.peek(), Some(Token::Symbol('(')) | None) {
+        tokens.next();
+    }
+}
+
+// Returns if the word starts a key or constraint, so it isn't the name of a constraint.
+fn is_constraint_keyword(word: &str) -> bool {
+    matches!(
+        word.to_uppercase().as_str(),
+        "PRIMARY" | "UNIQUE" | "FOREIGN" | "CHECK"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db_mocker::ColumnType;
+
+    const SCHEMA: &str = r#"
+        -- Schema of the shop
+        /*!40101 SET NAMES utf8mb4 */;
+        CREATE DATABASE IF NOT EXISTS `shop`;
+        USE `shop`;
+
+        DROP TABLE IF EXISTS `orders`;
+        CREATE TABLE `orders` (
+            `id` INT(10) UNSIGNED NOT NULL AUTO_INCREMENT,
+            `customer_id` int NOT NULL,
+            `code` VARCHAR(20) DEFAULT 'it''s',
+            `status` ENUM('new', 'done') NOT NULL DEFAULT 'new',
+            `total` DECIMAL(10,2) NOT NULL,
+            `stops` MULTIPOINT,
+            `created_at` DATETIME DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+            PRIMARY KEY (`id`),
+            UNIQUE KEY `code_unique` (`code`),
+            KEY `customer_status` (`customer_id`, `status`),
+            CONSTRAINT `fk_customer` FOREIGN KEY (`customer_id`) REFERENCES `customers` (`id`) ON DELETE CASCADE
+        ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+
+        INSERT INTO `orders` VALUES (1, 1, 'a', 'new', 1.0, NULL, NOW());
+    "#;
+
+    #[test]
+    fn parses_create_table_statements() {
+        let import = parse_sql(SCHEMA).unwrap();
+        assert_eq!(import.vdb.database_name, "shop");
+
+        let table = &import.vdb.tables[0];
+        assert_eq!(table.table_name, "orders");
+        assert_eq!(table.primary_keys, vec!["id"]);
+        assert_eq!(table.uniques, vec!["code"]);
+        assert_eq!(table.indices[0].columns, vec!["customer_id", "status"]);
+        assert_eq!(table.foreign_keys[0].origin_table, "customers");
+
+        let columns: Vec<&str> = table
+            .columns
+            .iter()
+            .map(|column| column.column_name.as_str())
+            .collect();
+        assert_eq!(
+            columns,
+            vec!["id", "customer_id", "code", "status", "total", "created_at"]
+        );
+
+        let id = &table.columns[0];
+        assert!(matches!(id.column_type, ColumnType::Uint32));
+        assert!(id.not_null && id.auto_increment);
+        assert_eq!(table.columns[2].default.as_deref(), Some("it's"));
+        assert!(
+            matches!(&table.columns[3].column_type, ColumnType::Enum(values) if values.len() == 2)
+        );
+        assert_eq!(table.columns[4].column_type, ColumnType::Decimal(10, 2));
+        assert_eq!(
+            table.columns[5].default.as_deref(),
+            Some("CURRENT_TIMESTAMP")
+        );
+    }
+
+    #[test]
+    fn reports_unsupported_constructs() {
+        let import = parse_sql(SCHEMA).unwrap();
+        let unsupported = import.unsupported.join("\n");
+
+        assert!(unsupported.contains("orders.stops"));
+        assert!(unsupported.contains("ON UPDATE"));
+        assert!(unsupported.contains("ON DELETE CASCADE"));
+        assert!(unsupported.contains("ENGINE"));
+        assert!(unsupported.contains("INSERT statement"));
+    }
+
+    // Output of mysqldump 8.0 for a table with attributes that can't be stored
+    const MYSQLDUMP: &str = r#"
+        -- MySQL dump 10.13  Distrib 8.0.36, for Linux (x86_64)
+        /*!40101 SET @OLD_CHARACTER_SET_CLIENT=@@CHARACTER_SET_CLIENT */;
+        /*!50503 SET NAMES utf8mb4 */;
+        DROP TABLE IF EXISTS `shops`;
+        /*!40101 SET @saved_cs_client     = @@character_set_client */;
+        /*!50503 SET character_set_client = utf8mb4 */;
+        CREATE TABLE `shops` (
+          `id` int unsigned NOT NULL AUTO_INCREMENT,
+          `public_id` char(36) NOT NULL DEFAULT (uuid()),
+          `is_open` bit(1) NOT NULL DEFAULT b'0',
+          `flags` bit(8) DEFAULT 0x0F,
+          `name` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_0900_ai_ci NOT NULL,
+          `secret` varchar(20) /*!80023 INVISIBLE */ DEFAULT NULL,
+          `location` point NOT NULL /*!80003 SRID 4326 */,
+          `name_length` int GENERATED ALWAYS AS (char_length(`name`)) VIRTUAL NOT NULL,
+          `updated_at` datetime(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3) ON UPDATE CURRENT_TIMESTAMP(3),
+          PRIMARY KEY (`id`),
+          KEY `name_prefix` (`name`(10)),
+          KEY `lower_name` ((lower(`name`))),
+          SPATIAL KEY `location` (`location`)
+        ) ENGINE=InnoDB AUTO_INCREMENT=3 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
+        /*!40101 SET character_set_client = @saved_cs_client */;
+    "#;
+
+    #[test]
+    fn parses_mysqldump_output() {
+        let import = parse_sql(MYSQLDUMP).unwrap();
+        let table = &import.vdb.tables[0];
+
+        let column = |name: &str| {
+            table
+                .columns
+                .iter()
+                .find(|column| column.column_name == name)
+                .unwrap()
+        };
+        assert_eq!(table.columns.len(), 9);
+        assert!(column("public_id").not_null);
+        assert_eq!(column("public_id").default, None);
+        assert_eq!(column("is_open").default.as_deref(), Some("b'0'"));
+        assert_eq!(column("flags").default.as_deref(), Some("15"));
+        assert!(column("name").not_null);
+        assert!(column("location").not_null);
+        assert!(column("name_length").not_null);
+        assert!(column("updated_at").not_null);
+        assert_eq!(
+            column("updated_at").default.as_deref(),
+            Some("CURRENT_TIMESTAMP(3)")
+        );
+        assert_eq!(table.primary_keys, vec!["id"]);
+        assert_eq!(table.indices.len(), 1);
+        assert_eq!(table.indices[0].columns, vec!["name"]);
+
+        let unsupported = import.unsupported.join("\n");
+        assert!(unsupported.contains("DEFAULT ( uuid ( ) ) of column 'shops.public_id'"));
+        assert!(
+            unsupported.contains("ON UPDATE CURRENT_TIMESTAMP ( 3 ) of column 'shops.updated_at'")
+        );
+        assert!(unsupported.contains("GENERATED ALWAYS AS"));
+        assert!(unsupported.contains("KEY over an expression in table 'shops'"));
+    }
+
+    #[test]
+    fn keeps_parsing_after_unknown_attributes() {
+        let import = parse_sql(
+            "CREATE TABLE shops (
+                secret VARCHAR(20) INVISIBLE NOT NULL DEFAULT 'x',
+                location POINT SRID 4326 NOT NULL,
+                id INT STORAGE MEMORY AUTO_INCREMENT
+            )",
+        )
+        .unwrap();
+        let columns = &import.vdb.tables[0].columns;
+
+        assert!(columns[0].not_null);
+        assert_eq!(columns[0].default.as_deref(), Some("x"));
+        assert!(columns[1].not_null);
+        assert!(columns[2].auto_increment);
+        assert_eq!(
+            import.unsupported,
+            vec![
+                "INVISIBLE of column 'shops.secret'",
+                "SRID 4326 of column 'shops.location'",
+                "STORAGE MEMORY of column 'shops.id'",
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_bit_and_hex_literals() {
+        assert_eq!(
+            tokenize("b'101' X'fF' 0x1f bit").unwrap(),
+            vec![
+                Token::BitLiteral("101".to_string()),
+                Token::HexLiteral("fF".to_string()),
+                Token::Number("0x1f".to_string()),
+                Token::Word("bit".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_statements() {
+        assert!(matches!(
+            parse_sql("CREATE TABLE orders (id INT"),
+            Err(Error::Parse(_))
+        ));
+        assert!(parse_sql("CREATE TABLE orders (name VARCHAR(10) DEFAULT 'open)").is_err());
+    }
+}
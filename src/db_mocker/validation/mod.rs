@@ -0,0 +1,79 @@
+// Thoughts about this code
+
+// A Validator checks a VirtualDatabase against the rules of a DBMS before it gets written.
+// Everything the DBMS would reject is an error, everything that works but likely causes trouble is a warning.
+// Each message has a path to the part of the VirtualDatabase it is about, e.g. tables[orders].columns[total].
+// Validators fill the lists instead of returning on the first problem, so all problems can be shown at once.
+
+use std::fmt;
+
+use super::VirtualDatabase;
+
+pub mod my_sql;
+
+pub trait Validator {
+    fn validate(vdb: &VirtualDatabase, errors: &mut ErrorList, warnings: &mut WarningList);
+}
+
+// A problem found by a Validator and where it was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationMessage {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+// All errors the Validator has found.
+// Currently only a Vec but may become an Observer or a similar pattern the ui apps can listen to.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ErrorList {
+    pub errors: Vec<ValidationMessage>,
+}
+
+impl ErrorList {
+    pub fn push(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.errors.push(ValidationMessage {
+            path: path.into(),
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+// All warnings the Validator has found.
+// Currently only a Vec but may become an Observer or a similar pattern the ui apps can listen to.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WarningList {
+    pub warnings: Vec<ValidationMessage>,
+}
+
+impl WarningList {
+    pub fn push(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.warnings.push(ValidationMessage {
+            path: path.into(),
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+// Path of a table inside the VirtualDatabase.
+pub(crate) fn table_path(table_name: &str) -> String {
+    format!("tables[{}]", table_name)
+}
+
+// Path of a column inside the VirtualDatabase.
+pub(crate) fn column_path(table_name: &str, column_name: &str) -> String {
+    format!("{}.columns[{}]", table_path(table_name), column_name)
+}
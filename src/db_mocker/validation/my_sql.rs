@@ -0,0 +1,1140 @@
+// Thoughts about this code
+
+// Rules checked by the MySQLValidator
+// -----------------------------------
+// Errors:
+//     Names that are reserved words, empty or longer than 64 characters
+//     Duplicate table or column names
+//     Primary keys, uniques, indices and foreign keys on columns that don't exist
+//     Foreign keys referencing a table or column that doesn't exist, isn't a key or has another column type
+//     More than one auto_increment column per table, auto_increment on a column that isn't an integer or a key
+//     Defaults that don't parse as the column type of their column
+//     VARCHAR sizes over the limit of a column or the row size limit of a table
+//     DECIMAL precisions and scales over the limits of MySQL, BIT sizes outside of 1 to 64
+//     Keys on TEXT, BLOB, JSON and spatial columns, MySQL needs a prefix length for them
+// Warnings:
+//     Tables without a primary key
+//     Names starting with a digit or containing characters that need quoting
+
+// Row sizes are calculated for the utf8mb4 character set, which needs up to 4 bytes per character.
+
+use std::mem::discriminant;
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use uuid::Uuid;
+
+use super::{column_path, table_path, ErrorList, Validator, WarningList};
+use crate::db_mocker::{
+    decimal_fits, parse_bit_literal, Column, ColumnType, Table, VirtualDatabase,
+};
+
+pub struct MySQLValidator {}
+
+impl Validator for MySQLValidator {
+    fn validate(vdb: &VirtualDatabase, errors: &mut ErrorList, warnings: &mut WarningList) {
+        check_name("database_name", &vdb.database_name, errors, warnings);
+
+        for (i, table) in vdb.tables.iter().enumerate() {
+            let path = table_path(&table.table_name);
+
+            check_name(&path, &table.table_name, errors, warnings);
+            if vdb.tables[..i]
+                .iter()
+                .any(|other| other.table_name == table.table_name)
+            {
+                errors.push(&path, "Table name is used by another table");
+            }
+
+            check_columns(table, errors, warnings);
+            check_keys(table, errors, warnings);
+            check_foreign_keys(vdb, table, errors);
+        }
+    }
+}
+
+// Reserved words of MySQL 8.0 that can't be used as unquoted identifiers.
+const RESERVED_WORDS: &[&str] = &[
+    "ACCESSIBLE",
+    "ADD",
+    "ALL",
+    "ALTER",
+    "ANALYZE",
+    "AND",
+    "AS",
+    "ASC",
+    "ASENSITIVE",
+    "BEFORE",
+    "BETWEEN",
+    "BIGINT",
+    "BINARY",
+    "BLOB",
+    "BOTH",
+    "BY",
+    "CALL",
+    "CASCADE",
+    "CASE",
+    "CHANGE",
+    "CHAR",
+    "CHARACTER",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "CONDITION",
+    "CONSTRAINT",
+    "CONTINUE",
+    "CONVERT",
+    "CREATE",
+    "CROSS",
+    "CUBE",
+    "CUME_DIST",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "CURSOR",
+    "DATABASE",
+    "DATABASES",
+    "DAY_HOUR",
+    "DAY_MICROSECOND",
+    "DAY_MINUTE",
+    "DAY_SECOND",
+    "DEC",
+    "DECIMAL",
+    "DECLARE",
+    "DEFAULT",
+    "DELAYED",
+    "DELETE",
+    "DENSE_RANK",
+    "DESC",
+    "DESCRIBE",
+    "DETERMINISTIC",
+    "DISTINCT",
+    "DISTINCTROW",
+    "DIV",
+    "DOUBLE",
+    "DROP",
+    "DUAL",
+    "EACH",
+    "ELSE",
+    "ELSEIF",
+    "EMPTY",
+    "ENCLOSED",
+    "ESCAPED",
+    "EXCEPT",
+    "EXISTS",
+    "EXIT",
+    "EXPLAIN",
+    "FALSE",
+    "FETCH",
+    "FIRST_VALUE",
+    "FLOAT",
+    "FLOAT4",
+    "FLOAT8",
+    "FOR",
+    "FORCE",
+    "FOREIGN",
+    "FROM",
+    "FULLTEXT",
+    "FUNCTION",
+    "GENERATED",
+    "GET",
+    "GRANT",
+    "GROUP",
+    "GROUPING",
+    "GROUPS",
+    "HAVING",
+    "HIGH_PRIORITY",
+    "HOUR_MICROSECOND",
+    "HOUR_MINUTE",
+    "HOUR_SECOND",
+    "IF",
+    "IGNORE",
+    "IN",
+    "INDEX",
+    "INFILE",
+    "INNER",
+    "INOUT",
+    "INSENSITIVE",
+    "INSERT",
+    "INT",
+    "INT1",
+    "INT2",
+    "INT3",
+    "INT4",
+    "INT8",
+    "INTEGER",
+    "INTERSECT",
+    "INTERVAL",
+    "INTO",
+    "IO_AFTER_GTIDS",
+    "IO_BEFORE_GTIDS",
+    "IS",
+    "ITERATE",
+    "JOIN",
+    "JSON_TABLE",
+    "KEY",
+    "KEYS",
+    "KILL",
+    "LAG",
+    "LAST_VALUE",
+    "LATERAL",
+    "LEAD",
+    "LEADING",
+    "LEAVE",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LINEAR",
+    "LINES",
+    "LOAD",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "LOCK",
+    "LONG",
+    "LONGBLOB",
+    "LONGTEXT",
+    "LOOP",
+    "LOW_PRIORITY",
+    "MASTER_BIND",
+    "MASTER_SSL_VERIFY_SERVER_CERT",
+    "MATCH",
+    "MAXVALUE",
+    "MEDIUMBLOB",
+    "MEDIUMINT",
+    "MEDIUMTEXT",
+    "MIDDLEINT",
+    "MINUTE_MICROSECOND",
+    "MINUTE_SECOND",
+    "MOD",
+    "MODIFIES",
+    "NATURAL",
+    "NOT",
+    "NO_WRITE_TO_BINLOG",
+    "NTH_VALUE",
+    "NTILE",
+    "NULL",
+    "NUMERIC",
+    "OF",
+    "ON",
+    "OPTIMIZE",
+    "OPTIMIZER_COSTS",
+    "OPTION",
+    "OPTIONALLY",
+    "OR",
+    "ORDER",
+    "OUT",
+    "OUTER",
+    "OUTFILE",
+    "OVER",
+    "PARTITION",
+    "PERCENT_RANK",
+    "PRECISION",
+    "PRIMARY",
+    "PROCEDURE",
+    "PURGE",
+    "RANGE",
+    "RANK",
+    "READ",
+    "READS",
+    "READ_WRITE",
+    "REAL",
+    "RECURSIVE",
+    "REFERENCES",
+    "REGEXP",
+    "RELEASE",
+    "RENAME",
+    "REPEAT",
+    "REPLACE",
+    "REQUIRE",
+    "RESIGNAL",
+    "RESTRICT",
+    "RETURN",
+    "REVOKE",
+    "RIGHT",
+    "RLIKE",
+    "ROW",
+    "ROWS",
+    "ROW_NUMBER",
+    "SCHEMA",
+    "SCHEMAS",
+    "SECOND_MICROSECOND",
+    "SELECT",
+    "SENSITIVE",
+    "SEPARATOR",
+    "SET",
+    "SHOW",
+    "SIGNAL",
+    "SMALLINT",
+    "SPATIAL",
+    "SPECIFIC",
+    "SQL",
+    "SQLEXCEPTION",
+    "SQLSTATE",
+    "SQLWARNING",
+    "SQL_BIG_RESULT",
+    "SQL_CALC_FOUND_ROWS",
+    "SQL_SMALL_RESULT",
+    "SSL",
+    "STARTING",
+    "STORED",
+    "STRAIGHT_JOIN",
+    "SYSTEM",
+    "TABLE",
+    "TERMINATED",
+    "THEN",
+    "TINYBLOB",
+    "TINYINT",
+    "TINYTEXT",
+    "TO",
+    "TRAILING",
+    "TRIGGER",
+    "TRUE",
+    "UNDO",
+    "UNION",
+    "UNIQUE",
+    "UNLOCK",
+    "UNSIGNED",
+    "UPDATE",
+    "USAGE",
+    "USE",
+    "USING",
+    "UTC_DATE",
+    "UTC_TIME",
+    "UTC_TIMESTAMP",
+    "VALUES",
+    "VARBINARY",
+    "VARCHAR",
+    "VARCHARACTER",
+    "VARYING",
+    "VIRTUAL",
+    "WHEN",
+    "WHERE",
+    "WHILE",
+    "WINDOW",
+    "WITH",
+    "WRITE",
+    "XOR",
+    "YEAR_MONTH",
+    "ZEROFILL",
+];
+
+// Identifiers in MySQL can be at most 64 characters long.
+const MAX_NAME_LENGTH: usize = 64;
+
+// All columns of a row together can take at most 65535 bytes.
+const MAX_ROW_SIZE: usize = 65_535;
+
+// Checks a database, table or column name.
+fn check_name(path: &str, name: &str, errors: &mut ErrorList, warnings: &mut WarningList) {
+    if name.is_empty() {
+        errors.push(path, "Name is empty");
+        return;
+    }
+
+    if name.chars().count() > MAX_NAME_LENGTH {
+        errors.push(
+            path,
+            format!("Name is longer than {} characters", MAX_NAME_LENGTH),
+        );
+    }
+
+    if RESERVED_WORDS.contains(&name.to_uppercase().as_str()) {
+        errors.push(path, format!("'{}' is a reserved word", name));
+    }
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        warnings.push(path, "Name starts with a digit and always needs quoting");
+    } else if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    {
+        warnings.push(path, "Name contains characters that need quoting");
+    }
+}
+
+// Checks the columns of a table and the size of its rows.
+fn check_columns(table: &Table, errors: &mut ErrorList, warnings: &mut WarningList) {
+    let mut row_size = 0;
+
+    for (i, column) in table.columns.iter().enumerate() {
+        let path = column_path(&table.table_name, &column.column_name);
+
+        check_name(&path, &column.column_name, errors, warnings);
+        if table.columns[..i]
+            .iter()
+            .any(|other| other.column_name == column.column_name)
+        {
+            errors.push(&path, "Column name is used by another column of the table");
+        }
+
+        if let Some(default) = &column.default {
+            if let Some(message) = default_error(column, default) {
+                errors.push(format!("{}.default", path), message);
+            }
+        }
+
+        if column.auto_increment && !is_integer(&column.column_type) {
+            errors.push(&path, "Only integer columns can be auto_increment");
+        }
+
+        // utf8mb4 needs up to 4 bytes per character
+        if let ColumnType::Varchar(size) = column.column_type {
+            let max_size = MAX_ROW_SIZE / 4;
+            if size as usize > max_size {
+                errors.push(
+                    &path,
+                    format!(
+                        "VARCHAR({}) exceeds the maximum size of {} characters",
+                        size, max_size
+                    ),
+                );
+            }
+        }
+
+        if let ColumnType::Varbinary(size) = column.column_type {
+            if size as usize > MAX_ROW_SIZE {
+                errors.push(
+                    &path,
+                    format!(
+                        "VARBINARY({}) exceeds the maximum size of {} bytes",
+                        size, MAX_ROW_SIZE
+                    ),
+                );
+            }
+        }
+
+        if let ColumnType::Decimal(precision, scale) = column.column_type {
+            if !(1..=65).contains(&precision) {
+                errors.push(&path, "DECIMAL precision has to be between 1 and 65");
+            }
+            if scale > 30 || scale > precision {
+                errors.push(
+                    &path,
+                    "DECIMAL scale can't be greater than 30 or the precision",
+                );
+            }
+        }
+
+        if let ColumnType::Bit(size) = column.column_type {
+            if !(1..=64).contains(&size) {
+                errors.push(&path, "BIT size has to be between 1 and 64");
+            }
+        }
+
+        if is_long_data(&column.column_type) && is_part_of_key(table, &column.column_name) {
+            errors.push(
+                &path,
+                format!(
+                    "{} columns can't be part of a key without a prefix length",
+                    type_name(&column.column_type)
+                ),
+            );
+        }
+
+        row_size += column_size(&column.column_type);
+    }
+
+    if row_size > MAX_ROW_SIZE {
+        errors.push(
+            table_path(&table.table_name),
+            format!(
+                "Rows can take up to {} bytes which exceeds the row size limit of {} bytes",
+                row_size, MAX_ROW_SIZE
+            ),
+        );
+    }
+
+    let auto_increment_count = table
+        .columns
+        .iter()
+        .filter(|column| column.auto_increment)
+        .count();
+    if auto_increment_count > 1 {
+        errors.push(
+            table_path(&table.table_name),
+            format!(
+                "Table has {} auto_increment columns, only one is allowed",
+                auto_increment_count
+            ),
+        );
+    }
+}
+
+// Checks that primary keys, uniques and indices reference existing columns.
+fn check_keys(table: &Table, errors: &mut ErrorList, warnings: &mut WarningList) {
+    let path = table_path(&table.table_name);
+
+    if table.primary_keys.is_empty() {
+        warnings.push(&path, "Table has no primary key");
+    }
+
+    for primary_key in table.primary_keys.iter() {
+        if find_column(table, primary_key).is_none() {
+            errors.push(
+                format!("{}.primary_keys[{}]", path, primary_key),
+                format!("Primary key column '{}' doesn't exist", primary_key),
+            );
+        }
+    }
+
+    for unique in table.uniques.iter() {
+        if find_column(table, unique).is_none() {
+            errors.push(
+                format!("{}.uniques[{}]", path, unique),
+                format!("Unique column '{}' doesn't exist", unique),
+            );
+        }
+    }
+
+    for (i, index) in table.indices.iter().enumerate() {
+        if index.columns.is_empty() {
+            errors.push(format!("{}.indices[{}]", path, i), "Index has no columns");
+        }
+        for column_name in index.columns.iter() {
+            if find_column(table, column_name).is_none() {
+                errors.push(
+                    format!("{}.indices[{}]", path, i),
+                    format!("Index column '{}' doesn't exist", column_name),
+                );
+            }
+        }
+    }
+
+    // MySQL needs an index on the auto_increment column
+    for column in table.columns.iter().filter(|column| column.auto_increment) {
+        if !is_key(table, &column.column_name) {
+            errors.push(
+                column_path(&table.table_name, &column.column_name),
+                "An auto_increment column has to be a key",
+            );
+        }
+    }
+}
+
+// Checks that foreign keys reference existing keys with the same column type.
+fn check_foreign_keys(vdb: &VirtualDatabase, table: &Table, errors: &mut ErrorList) {
+    for fk in table.foreign_keys.iter() {
+        let path = format!(
+            "{}.foreign_keys[{}]",
+            table_path(&table.table_name),
+            fk.fk_column
+        );
+
+        let Some(fk_column) = find_column(table, &fk.fk_column) else {
+            errors.push(
+                &path,
+                format!("Foreign key column '{}' doesn't exist", fk.fk_column),
+            );
+            continue;
+        };
+
+        let Some(origin_table) = vdb
+            .tables
+            .iter()
+            .find(|origin| origin.table_name == fk.origin_table)
+        else {
+            errors.push(
+                &path,
+                format!("Referenced table '{}' doesn't exist", fk.origin_table),
+            );
+            continue;
+        };
+
+        let Some(origin_column) = find_column(origin_table, &fk.origin_column) else {
+            errors.push(
+                &path,
+                format!(
+                    "Referenced column '{}.{}' doesn't exist",
+                    fk.origin_table, fk.origin_column
+                ),
+            );
+            continue;
+        };
+
+        // Sizes of varchars may differ, everything else has to be the same column type
+        if discriminant(&fk_column.column_type) != discriminant(&origin_column.column_type) {
+            errors.push(
+                &path,
+                format!(
+                    "Datatype {} doesn't match column_type {} of the referenced column '{}.{}'",
+                    type_name(&fk_column.column_type),
+                    type_name(&origin_column.column_type),
+                    fk.origin_table,
+                    fk.origin_column
+                ),
+            );
+        }
+
+        // MySQL needs an index on the referenced column
+        if !is_key(origin_table, &fk.origin_column) {
+            errors.push(
+                &path,
+                format!(
+                    "Referenced column '{}.{}' isn't a key",
+                    fk.origin_table, fk.origin_column
+                ),
+            );
+        }
+    }
+}
+
+fn find_column<'a>(table: &'a Table, column_name: &str) -> Option<&'a Column> {
+    table
+        .columns
+        .iter()
+        .find(|column| column.column_name == column_name)
+}
+
+// Returns if the column is the first column of the primary key, a unique or the first column of an index.
+// MySQL can only use an index for a column if the column is the first one of the index.
+fn is_key(table: &Table, column_name: &str) -> bool {
+    table
+        .primary_keys
+        .first()
+        .is_some_and(|key| key == column_name)
+        || table.uniques.iter().any(|unique| unique == column_name)
+        || table
+            .indices
+            .iter()
+            .any(|index| index.columns.first().is_some_and(|key| key == column_name))
+}
+
+// Checks if the column is part of the primary key, a unique constraint or an index.
+fn is_part_of_key(table: &Table, column_name: &str) -> bool {
+    table.primary_keys.iter().any(|key| key == column_name)
+        || table.uniques.iter().any(|unique| unique == column_name)
+        || table
+            .indices
+            .iter()
+            .any(|index| index.columns.iter().any(|key| key == column_name))
+}
+
+// TEXT, BLOB, JSON and spatial values are stored outside of the row.
+fn is_long_data(column_type: &ColumnType) -> bool {
+    matches!(
+        column_type,
+        ColumnType::Text
+            | ColumnType::MediumText
+            | ColumnType::LongText
+            | ColumnType::Blob
+            | ColumnType::Json
+            | ColumnType::Geometry
+            | ColumnType::Point
+            | ColumnType::LineString
+            | ColumnType::Polygon
+    )
+}
+
+fn is_integer(column_type: &ColumnType) -> bool {
+    matches!(
+        column_type,
+        ColumnType::Int8
+            | ColumnType::Uint8
+            | ColumnType::Int16
+            | ColumnType::Uint16
+            | ColumnType::Int32
+            | ColumnType::Uint32
+            | ColumnType::Int64
+            | ColumnType::Uint64
+    )
+}
+
+// MySQL name of a column type for messages.
+fn type_name(column_type: &ColumnType) -> String {
+    match column_type {
+        ColumnType::Int8 => "TINYINT".to_string(),
+        ColumnType::Uint8 => "TINYINT UNSIGNED".to_string(),
+        ColumnType::Int16 => "SMALLINT".to_string(),
+        ColumnType::Uint16 => "SMALLINT UNSIGNED".to_string(),
+        ColumnType::Int32 => "INT".to_string(),
+        ColumnType::Uint32 => "INT UNSIGNED".to_string(),
+        ColumnType::Int64 => "BIGINT".to_string(),
+        ColumnType::Uint64 => "BIGINT UNSIGNED".to_string(),
+        ColumnType::Float => "FLOAT".to_string(),
+        ColumnType::Double => "DOUBLE".to_string(),
+        ColumnType::Decimal(precision, scale) => format!("DECIMAL({}, {})", precision, scale),
+        ColumnType::Boolean => "BOOLEAN".to_string(),
+        ColumnType::Bit(size) => format!("BIT({})", size),
+        ColumnType::Char(size) => format!("CHAR({})", size),
+        ColumnType::Varchar(size) => format!("VARCHAR({})", size),
+        ColumnType::Text => "TEXT".to_string(),
+        ColumnType::MediumText => "MEDIUMTEXT".to_string(),
+        ColumnType::LongText => "LONGTEXT".to_string(),
+        ColumnType::Binary(size) => format!("BINARY({})", size),
+        ColumnType::Varbinary(size) => format!("VARBINARY({})", size),
+        ColumnType::Blob => "BLOB".to_string(),
+        ColumnType::Json => "JSON".to_string(),
+        ColumnType::Date => "DATE".to_string(),
+        ColumnType::Time => "TIME".to_string(),
+        ColumnType::DateTime(_) => "DATETIME".to_string(),
+        ColumnType::Timestamp => "TIMESTAMP".to_string(),
+        ColumnType::Year => "YEAR".to_string(),
+        ColumnType::Uuid => "CHAR(36)".to_string(),
+        ColumnType::Enum(_) => "ENUM".to_string(),
+        ColumnType::Set(_) => "SET".to_string(),
+        ColumnType::Geometry => "GEOMETRY".to_string(),
+        ColumnType::Point => "POINT".to_string(),
+        ColumnType::LineString => "LINESTRING".to_string(),
+        ColumnType::Polygon => "POLYGON".to_string(),
+    }
+}
+
+// Maximum number of bytes a value of the column type takes in a row.
+fn column_size(column_type: &ColumnType) -> usize {
+    match column_type {
+        ColumnType::Int8 | ColumnType::Uint8 | ColumnType::Boolean | ColumnType::Year => 1,
+        ColumnType::Int16 | ColumnType::Uint16 | ColumnType::Enum(_) => 2,
+        ColumnType::Int32 | ColumnType::Uint32 | ColumnType::Float => 4,
+        ColumnType::Int64 | ColumnType::Uint64 | ColumnType::Double | ColumnType::Set(_) => 8,
+        ColumnType::Date | ColumnType::Time => 3,
+        ColumnType::DateTime(_) => 5,
+        ColumnType::Timestamp => 4,
+        ColumnType::Decimal(precision, scale) => {
+            decimal_digits_size(precision - scale.min(precision)) + decimal_digits_size(*scale)
+        }
+        ColumnType::Bit(size) => (*size as usize).div_ceil(8),
+        ColumnType::Char(size) => *size as usize * 4,
+        ColumnType::Uuid => 36 * 4,
+        ColumnType::Binary(size) => *size as usize,
+        // Values longer than 255 bytes need two bytes to store their length
+        ColumnType::Varchar(size) => {
+            let size = *size as usize * 4;
+            size + if size > 255 { 2 } else { 1 }
+        }
+        ColumnType::Varbinary(size) => {
+            let size = *size as usize;
+            size + if size > 255 { 2 } else { 1 }
+        }
+        // Only the length and a pointer to the value are stored in the row
+        ColumnType::Text | ColumnType::Blob => 10,
+        ColumnType::MediumText => 11,
+        ColumnType::LongText
+        | ColumnType::Json
+        | ColumnType::Geometry
+        | ColumnType::Point
+        | ColumnType::LineString
+        | ColumnType::Polygon => 12,
+    }
+}
+
+// Number of bytes MySQL needs for the digits of one side of the decimal point.
+// Every nine digits take four bytes, the remaining digits take up to four bytes more.
+fn decimal_digits_size(digits: u8) -> usize {
+    const REMAINDER_SIZES: [usize; 9] = [0, 1, 1, 2, 2, 3, 3, 4, 4];
+    let digits = digits as usize;
+    digits / 9 * 4 + REMAINDER_SIZES[digits % 9]
+}
+
+// Returns why the default doesn't fit the column, or None if it does.
+// The default can be quoted or unquoted, like it is accepted when the table is rendered.
+fn default_error(column: &Column, default: &str) -> Option<String> {
+    let default = default.trim();
+    let uppercase = default.to_uppercase();
+
+    if uppercase == "NULL" {
+        return column
+            .not_null
+            .then(|| "Default is NULL but the column is NOT NULL".to_string());
+    }
+
+    // Datetime functions are only allowed as default of DATETIME columns
+    let function = uppercase.split('(').next().unwrap_or_default().trim();
+    let is_function = matches!(
+        function,
+        "CURRENT_TIMESTAMP"
+            | "NOW"
+            | "LOCALTIME"
+            | "LOCALTIMESTAMP"
+            | "CURRENT_DATE"
+            | "CURDATE"
+            | "CURRENT_TIME"
+            | "CURTIME"
+    );
+    if is_function {
+        let allowed = matches!(
+            column.column_type,
+            ColumnType::DateTime(_) | ColumnType::Timestamp
+        ) && matches!(
+            function,
+            "CURRENT_TIMESTAMP" | "NOW" | "LOCALTIME" | "LOCALTIMESTAMP"
+        );
+        return (!allowed).then(|| {
+            format!(
+                "{} can't be the default of a {} column",
+                default,
+                type_name(&column.column_type)
+            )
+        });
+    }
+
+    let value = default
+        .strip_prefix('\'')
+        .and_then(|default| default.strip_suffix('\''))
+        .map(|default| default.replace("''", "'"))
+        .unwrap_or_else(|| default.to_string());
+
+    let fits = match &column.column_type {
+        ColumnType::Int8 => value.parse::<i8>().is_ok(),
+        ColumnType::Uint8 => value.parse::<u8>().is_ok(),
+        ColumnType::Int16 => value.parse::<i16>().is_ok(),
+        ColumnType::Uint16 => value.parse::<u16>().is_ok(),
+        ColumnType::Int32 => value.parse::<i32>().is_ok(),
+        ColumnType::Uint32 => value.parse::<u32>().is_ok(),
+        ColumnType::Int64 => value.parse::<i64>().is_ok(),
+        ColumnType::Uint64 => value.parse::<u64>().is_ok(),
+        ColumnType::Float | ColumnType::Double => value.parse::<f64>().is_ok(),
+        ColumnType::Decimal(precision, scale) => decimal_fits(&value, *precision, *scale),
+        ColumnType::Boolean => {
+            matches!(value.to_uppercase().as_str(), "TRUE" | "FALSE" | "0" | "1")
+        }
+        // Numbers or bit literals like b'101' with at most size bits
+        ColumnType::Bit(size) => {
+            parse_bit_literal(&value).is_some_and(|bits| *size >= 64 || bits < 1 << size)
+        }
+        ColumnType::Char(size) => {
+            let length = value.chars().count();
+            if length > *size as usize {
+                return Some(format!(
+                    "Default has {} characters but the column only fits {}",
+                    length, size
+                ));
+            }
+            true
+        }
+        ColumnType::Binary(size) if value.len() > *size as usize => {
+            return Some(format!(
+                "Default has {} bytes but the column only fits {}",
+                value.len(),
+                size
+            ))
+        }
+        ColumnType::Varbinary(size) if value.len() > *size as usize => {
+            return Some(format!(
+                "Default has {} bytes but the column only fits {}",
+                value.len(),
+                size
+            ))
+        }
+        ColumnType::Binary(_) | ColumnType::Varbinary(_) => true,
+        ColumnType::Text
+        | ColumnType::MediumText
+        | ColumnType::LongText
+        | ColumnType::Blob
+        | ColumnType::Json
+        | ColumnType::Geometry
+        | ColumnType::Point
+        | ColumnType::LineString
+        | ColumnType::Polygon => {
+            return Some(format!(
+                "{} columns can't have a literal default",
+                type_name(&column.column_type)
+            ))
+        }
+        ColumnType::Varchar(size) => {
+            let length = value.chars().count();
+            if length > *size as usize {
+                return Some(format!(
+                    "Default has {} characters but the column only fits {}",
+                    length, size
+                ));
+            }
+            true
+        }
+        ColumnType::Date => NaiveDate::parse_from_str(&value, "%Y-%m-%d").is_ok(),
+        ColumnType::Time => NaiveTime::parse_from_str(&value, "%H:%M:%S%.f").is_ok(),
+        ColumnType::DateTime(_) => {
+            NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S%.f").is_ok()
+        }
+        // TIMESTAMP only covers the seconds between 1970 and 2038 that fit into 32 bits
+        ColumnType::Timestamp => NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S%.f")
+            .is_ok_and(|timestamp| {
+                (1..=i32::MAX as i64).contains(&timestamp.and_utc().timestamp())
+            }),
+        ColumnType::Uuid => Uuid::parse_str(&value).is_ok(),
+        ColumnType::Year => value
+            .parse::<u16>()
+            .is_ok_and(|year| (1901..=2155).contains(&year)),
+        ColumnType::Enum(values) => values.contains(&value),
+        ColumnType::Set(values) => {
+            value.is_empty()
+                || value
+                    .split(',')
+                    .all(|member| values.iter().any(|v| v == member))
+        }
+    };
+
+    (!fits).then(|| {
+        format!(
+            "Default '{}' isn't a valid {} value",
+            value,
+            type_name(&column.column_type)
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Tz;
+
+    use super::*;
+    use crate::db_mocker::{ForeignKey, Index};
+
+    fn column(column_name: &str, column_type: ColumnType) -> Column {
+        Column {
+            column_name: column_name.to_string(),
+            column_type,
+            default: None,
+            auto_increment: false,
+            not_null: true,
+            generator: None,
+            constraints: None,
+        }
+    }
+
+    fn table(table_name: &str, columns: Vec<Column>) -> Table {
+        Table {
+            table_name: table_name.to_string(),
+            columns,
+            primary_keys: vec!["id".to_string()],
+            foreign_keys: Vec::new(),
+            uniques: Vec::new(),
+            indices: Vec::new(),
+            datasets: Vec::new(),
+        }
+    }
+
+    fn shop() -> VirtualDatabase {
+        let mut id = column("id", ColumnType::Uint32);
+        id.auto_increment = true;
+        let customers = table(
+            "customers",
+            vec![id, column("name", ColumnType::Varchar(100))],
+        );
+
+        let mut id = column("id", ColumnType::Uint32);
+        id.auto_increment = true;
+        let mut status = column(
+            "status",
+            ColumnType::Enum(vec!["new".to_string(), "done".to_string()]),
+        );
+        status.default = Some("'new'".to_string());
+        let mut orders = table(
+            "orders",
+            vec![id, column("customer_id", ColumnType::Uint32), status],
+        );
+        orders.foreign_keys.push(ForeignKey {
+            fk_column: "customer_id".to_string(),
+            origin_table: "customers".to_string(),
+            origin_column: "id".to_string(),
+        });
+
+        VirtualDatabase {
+            database_name: "shop".to_string(),
+            users: Vec::new(),
+            tables: vec![customers, orders],
+        }
+    }
+
+    fn error_paths(vdb: &VirtualDatabase) -> Vec<String> {
+        let (errors, _) = vdb.validate::<MySQLValidator>();
+        errors.errors.into_iter().map(|error| error.path).collect()
+    }
+
+    #[test]
+    fn accepts_valid_database() {
+        let (errors, warnings) = shop().validate::<MySQLValidator>();
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn reports_names() {
+        let mut vdb = shop();
+        vdb.tables[1].table_name = "order".to_string();
+        vdb.tables[0].columns[1].column_name = "id".to_string();
+        vdb.tables[0].columns.push(column("1st", ColumnType::Int8));
+
+        let (errors, warnings) = vdb.validate::<MySQLValidator>();
+        let paths: Vec<&str> = errors
+            .errors
+            .iter()
+            .map(|error| error.path.as_str())
+            .collect();
+        assert!(paths.contains(&"tables[order]"));
+        assert!(paths.contains(&"tables[customers].columns[id]"));
+        assert_eq!(warnings.warnings[0].path, "tables[customers].columns[1st]");
+    }
+
+    #[test]
+    fn reports_missing_key_columns() {
+        let mut vdb = shop();
+        let orders = &mut vdb.tables[1];
+        orders.primary_keys = vec!["order_id".to_string()];
+        orders.uniques = vec!["code".to_string()];
+        orders.indices = vec![Index {
+            columns: vec!["status".to_string(), "total".to_string()],
+        }];
+        orders.foreign_keys[0].fk_column = "client_id".to_string();
+
+        let paths = error_paths(&vdb);
+        assert!(paths.contains(&"tables[orders].primary_keys[order_id]".to_string()));
+        assert!(paths.contains(&"tables[orders].uniques[code]".to_string()));
+        assert!(paths.contains(&"tables[orders].indices[0]".to_string()));
+        assert!(paths.contains(&"tables[orders].foreign_keys[client_id]".to_string()));
+        // The id is no key anymore but still auto_increment
+        assert!(paths.contains(&"tables[orders].columns[id]".to_string()));
+    }
+
+    #[test]
+    fn reports_foreign_key_type_mismatch() {
+        let mut vdb = shop();
+        vdb.tables[1].columns[1].column_type = ColumnType::Int32;
+
+        let (errors, _) = vdb.validate::<MySQLValidator>();
+        assert_eq!(errors.errors.len(), 1);
+        assert_eq!(
+            errors.errors[0].path,
+            "tables[orders].foreign_keys[customer_id]"
+        );
+        assert!(errors.errors[0].message.contains("INT UNSIGNED"));
+    }
+
+    #[test]
+    fn reports_decimal_limits_and_keys_on_text_columns() {
+        let mut vdb = shop();
+        let orders = &mut vdb.tables[1];
+        orders
+            .columns
+            .push(column("total", ColumnType::Decimal(66, 2)));
+        orders
+            .columns
+            .push(column("rate", ColumnType::Decimal(5, 6)));
+        orders.columns.push(column("notes", ColumnType::Text));
+        orders.columns.push(column("receipt", ColumnType::Blob));
+        orders.uniques.push("notes".to_string());
+        orders.indices.push(Index {
+            columns: vec!["status".to_string(), "receipt".to_string()],
+        });
+
+        let paths = error_paths(&vdb);
+        assert_eq!(
+            paths,
+            vec![
+                "tables[orders].columns[total]",
+                "tables[orders].columns[rate]",
+                "tables[orders].columns[notes]",
+                "tables[orders].columns[receipt]",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_bit_sizes_and_keys_on_spatial_columns() {
+        let mut vdb = shop();
+        let orders = &mut vdb.tables[1];
+        orders.columns.push(column("flags", ColumnType::Bit(65)));
+        orders
+            .columns
+            .push(column("delivery_point", ColumnType::Point));
+        orders.indices.push(Index {
+            columns: vec!["delivery_point".to_string()],
+        });
+
+        let paths = error_paths(&vdb);
+        assert_eq!(
+            paths,
+            vec![
+                "tables[orders].columns[flags]",
+                "tables[orders].columns[delivery_point]",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_table_rules() {
+        let mut vdb = shop();
+        vdb.tables[0].primary_keys.clear();
+        vdb.tables[1].columns[1].auto_increment = true;
+        vdb.tables[1]
+            .columns
+            .push(column("notes", ColumnType::Varchar(16_000)));
+        vdb.tables[1]
+            .columns
+            .push(column("text", ColumnType::Varchar(20_000)));
+
+        let (errors, warnings) = vdb.validate::<MySQLValidator>();
+        assert_eq!(warnings.warnings[0].path, "tables[customers]");
+        let messages: Vec<String> = errors
+            .errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect();
+        assert!(messages.contains(
+            &"tables[orders]: Table has 2 auto_increment columns, only one is allowed".to_string()
+        ));
+        assert!(messages
+            .iter()
+            .any(|message| message.starts_with("tables[orders]: Rows can take up to")));
+        assert!(messages
+            .iter()
+            .any(|message| message.starts_with("tables[orders].columns[text]: VARCHAR(20000)")));
+    }
+
+    #[test]
+    fn reports_defaults_not_fitting_the_datatype() {
+        let cases = [
+            (ColumnType::Int8, "300", false),
+            (ColumnType::Int8, "-12", true),
+            (ColumnType::Uint32, "-1", false),
+            (ColumnType::Boolean, "TRUE", true),
+            (ColumnType::Varchar(3), "'abcd'", false),
+            (ColumnType::Date, "2023-02-30", false),
+            (ColumnType::Date, "CURRENT_DATE", false),
+            (ColumnType::Year, "1900", false),
+            (ColumnType::Decimal(5, 2), "123.45", true),
+            (ColumnType::Decimal(5, 2), "1234.5", false),
+            (ColumnType::Decimal(5, 2), "1.234", false),
+            (ColumnType::Char(2), "'abc'", false),
+            (ColumnType::Binary(4), "abcd", true),
+            (ColumnType::Text, "'notes'", false),
+            (ColumnType::Json, "'{}'", false),
+            (ColumnType::Point, "'POINT(0 0)'", false),
+            (ColumnType::Bit(3), "b'101'", true),
+            (ColumnType::Bit(3), "8", false),
+            (ColumnType::Timestamp, "1970-01-01 00:00:00", false),
+            (ColumnType::Timestamp, "CURRENT_TIMESTAMP", true),
+            (
+                ColumnType::Uuid,
+                "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                true,
+            ),
+            (ColumnType::Uuid, "not-a-uuid", false),
+            (
+                ColumnType::Set(vec!["a".to_string(), "b".to_string()]),
+                "a,b",
+                true,
+            ),
+            (
+                ColumnType::Set(vec!["a".to_string(), "b".to_string()]),
+                "a,c",
+                false,
+            ),
+        ];
+
+        for (column_type, default, fits) in cases {
+            let column = column("value", column_type);
+            assert_eq!(
+                default_error(&column, default).is_none(),
+                fits,
+                "Default {}",
+                default
+            );
+        }
+
+        let mut created_at = column("created_at", ColumnType::DateTime(Tz::UTC));
+        assert!(default_error(&created_at, "CURRENT_TIMESTAMP").is_none());
+        assert!(default_error(&created_at, "NULL").is_some());
+        created_at.not_null = false;
+        assert!(default_error(&created_at, "NULL").is_none());
+    }
+}